@@ -0,0 +1,321 @@
+//! Derives for the traits of [`safe-transmute`](https://crates.io/crates/safe-transmute).
+//!
+//! Use them through the `derive` feature of `safe-transmute`, which re-exports
+//! them next to the traits they implement.
+
+
+extern crate proc_macro2;
+extern crate proc_macro;
+extern crate quote;
+extern crate syn;
+
+use syn::{DeriveInput, Attribute, Fields, Member, LitInt, Index, Data, Type, parse_macro_input, parenthesized, parse_quote, token};
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+
+
+/// Implement `TransparentWrapper` for a `repr(transparent)` struct.
+///
+/// For a struct with a single field, the wrapped type is that field's type.
+/// Otherwise, it has to be named with a `#[transparent(Type)]` attribute, and
+/// all other fields must be zero-sized and constructible from nothing, like
+/// `PhantomData`.
+#[proc_macro_derive(TransparentWrapper, attributes(transparent))]
+pub fn derive_transparent_wrapper(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match transparent_wrapper(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn transparent_wrapper(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    if !has_repr(&input.attrs, "transparent")? {
+        return Err(syn::Error::new(Span::call_site(), "TransparentWrapper can only be derived for #[repr(transparent)] structs"));
+    }
+
+    let fields = match input.data {
+        Data::Struct(ref data) => &data.fields,
+        _ => return Err(syn::Error::new(Span::call_site(), "TransparentWrapper can only be derived for structs")),
+    };
+
+    let inner = match wrapped_type(&input.attrs)? {
+        Some(inner) => inner,
+        None => {
+            match *fields {
+                Fields::Named(ref named) if named.named.len() == 1 => named.named[0].ty.clone(),
+                Fields::Unnamed(ref unnamed) if unnamed.unnamed.len() == 1 => unnamed.unnamed[0].ty.clone(),
+                _ => {
+                    return Err(syn::Error::new(Span::call_site(),
+                                               "TransparentWrapper needs the wrapped type named with #[transparent(Type)] for structs without exactly one \
+                                                field"))
+                }
+            }
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        unsafe impl #impl_generics ::safe_transmute::TransparentWrapper<#inner> for #name #ty_generics #where_clause {}
+    })
+}
+
+/// Implement `TriviallyTransmutable` for a `repr(C)`, `repr(transparent)`, or
+/// `repr(packed)` struct whose fields are all trivially transmutable.
+///
+/// As trivially transmutable values can be viewed as bytes, the struct must
+/// not contain any padding, which is asserted at compile time for structs
+/// without generic parameters; the padding of generic structs depends on the
+/// parameters, so is not checked:
+///
+/// ```compile_fail,E0080
+/// # extern crate safe_transmute;
+/// # use safe_transmute::TriviallyTransmutable;
+/// #[derive(TriviallyTransmutable, Clone, Copy)]
+/// #[repr(C)]
+/// struct Padded {
+///     tag: u8,
+///     value: u32,
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ```
+/// # extern crate safe_transmute;
+/// # use safe_transmute::TriviallyTransmutable;
+/// #[derive(TriviallyTransmutable, Clone, Copy)]
+/// #[repr(C)]
+/// struct Unpadded {
+///     tag: u8,
+///     reserved: [u8; 3],
+///     value: u32,
+/// }
+/// # fn main() {}
+/// ```
+///
+/// With a `#[transmutable(layout)]` attribute, `Layout` is also implemented,
+/// describing every field; their types must then implement `Scalars`.
+///
+/// With a `#[transmutable(align = N)]` attribute, the struct is asserted at
+/// compile time to be aligned to at least `N` bytes, as for types which must
+/// sit on stricter boundaries than their fields need, like DMA descriptors:
+///
+/// ```
+/// # extern crate safe_transmute;
+/// # use safe_transmute::TriviallyTransmutable;
+/// #[derive(TriviallyTransmutable, Clone, Copy)]
+/// #[transmutable(align = 16)]
+/// #[repr(C, align(16))]
+/// struct Descriptor {
+///     address: u64,
+///     len: u32,
+///     flags: u32,
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ```compile_fail,E0080
+/// # extern crate safe_transmute;
+/// # use safe_transmute::TriviallyTransmutable;
+/// #[derive(TriviallyTransmutable, Clone, Copy)]
+/// #[transmutable(align = 16)]
+/// #[repr(C)]
+/// struct Descriptor {
+///     address: u64,
+///     len: u32,
+///     flags: u32,
+/// }
+/// # fn main() {}
+/// ```
+///
+/// With a `#[transmutable(fields)]` attribute, each named field `x` also gets
+/// associated functions `x_bytes()` and `x_ref()`, which, given the bytes of
+/// a whole struct, return the bytes of that field and a reference to it, with
+/// the field's visibility.
+#[proc_macro_derive(TriviallyTransmutable, attributes(transmutable))]
+pub fn derive_trivially_transmutable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match trivially_transmutable(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn trivially_transmutable(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    if !(has_repr(&input.attrs, "C")? || has_repr(&input.attrs, "transparent")? || has_repr(&input.attrs, "packed")?) {
+        return Err(syn::Error::new(Span::call_site(),
+                                   "TriviallyTransmutable can only be derived for #[repr(C)], #[repr(transparent)], or #[repr(packed)] structs"));
+    }
+
+    let fields = match input.data {
+        Data::Struct(ref data) => &data.fields,
+        _ => return Err(syn::Error::new(Span::call_site(), "TriviallyTransmutable can only be derived for structs")),
+    };
+    let options = transmutable_options(&input.attrs)?;
+
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    {
+        let where_clause = generics.make_where_clause();
+        for field in fields.iter() {
+            let ty = &field.ty;
+            where_clause.predicates.push(parse_quote!(#ty: ::safe_transmute::TriviallyTransmutable));
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut tokens = quote! {
+        unsafe impl #impl_generics ::safe_transmute::TriviallyTransmutable for #name #ty_generics #where_clause {}
+    };
+
+    if options.layout {
+        let layouts = fields.iter().enumerate().map(|(i, field)| {
+            let ty = &field.ty;
+            let (member, field_name) = match field.ident {
+                Some(ref ident) => (Member::Named(ident.clone()), ident.to_string()),
+                None => (Member::Unnamed(Index::from(i)), i.to_string()),
+            };
+            quote! {
+                ::safe_transmute::layout::FieldLayout {
+                    name: #field_name,
+                    offset: ::safe_transmute::layout::offset_of!(Self, #member),
+                    size: ::safe_transmute::layout::size_of::<#ty>(),
+                    kind: <#ty as ::safe_transmute::layout::Scalars>::KIND,
+                    count: <#ty as ::safe_transmute::layout::Scalars>::COUNT,
+                }
+            }
+        });
+        let mut generics = generics.clone();
+        {
+            let where_clause = generics.make_where_clause();
+            for field in fields.iter() {
+                let ty = &field.ty;
+                where_clause.predicates.push(parse_quote!(#ty: ::safe_transmute::layout::Scalars));
+            }
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        tokens.extend(quote! {
+            impl #impl_generics ::safe_transmute::layout::Layout for #name #ty_generics #where_clause {
+                const FIELDS: &'static [::safe_transmute::layout::FieldLayout] = &[#(#layouts),*];
+            }
+        });
+    }
+
+    // Padding bytes are uninitialised, and `TriviallyTransmutable` types can be viewed as bytes
+    if input.generics.params.is_empty() {
+        let tys = fields.iter().map(|field| &field.ty);
+        let message = format!("`{}` contains padding, which transmuting it into bytes would expose", name);
+        tokens.extend(quote! {
+            const _: () = assert!(::safe_transmute::layout::size_of::<#name>() == 0 #(+ ::safe_transmute::layout::size_of::<#tys>())*, #message);
+        });
+    }
+
+    if let Some((align, span)) = options.align {
+        if !input.generics.params.is_empty() {
+            return Err(syn::Error::new(span, "#[transmutable(align = N)] can only be used on non-generic structs"));
+        }
+        let message = format!("`{}` must be aligned to at least {} bytes; add #[repr(align({}))]", name, align, align);
+        tokens.extend(quote! {
+            const _: () = assert!(::safe_transmute::layout::align_of::<#name>() >= #align, #message);
+        });
+    }
+
+    if options.fields {
+        let mut accessors = Vec::new();
+        for field in fields.iter() {
+            let ident = match field.ident {
+                Some(ref ident) => ident,
+                None => return Err(syn::Error::new(Span::call_site(), "#[transmutable(fields)] can only be used on structs with named fields")),
+            };
+            let ty = &field.ty;
+            let vis = &field.vis;
+            let bytes_fn = format_ident!("{}_bytes", ident);
+            let ref_fn = format_ident!("{}_ref", ident);
+            let bytes_doc = format!("The bytes of the `{}` field, out of those of a whole `{}`.", ident, name);
+            let ref_doc = format!("A reference to the `{}` field, out of the bytes of a whole `{}`.", ident, name);
+            accessors.push(quote! {
+                #[doc = #bytes_doc]
+                #vis fn #bytes_fn(bytes: &[u8]) -> ::safe_transmute::layout::Result<&[u8], ::safe_transmute::GuardError> {
+                    ::safe_transmute::layout::field_bytes::<Self>(bytes,
+                                                                   ::safe_transmute::layout::offset_of!(Self, #ident),
+                                                                   ::safe_transmute::layout::size_of::<#ty>())
+                }
+
+                #[doc = #ref_doc]
+                #vis fn #ref_fn<'__bytes>(bytes: &'__bytes [u8])
+                    -> ::safe_transmute::layout::Result<&'__bytes #ty, ::safe_transmute::Error<'__bytes, u8, #ty>> {
+                    ::safe_transmute::transmute_one_ref::<#ty>(Self::#bytes_fn(bytes)?)
+                }
+            });
+        }
+        tokens.extend(quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                #(#accessors)*
+            }
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// Whether any `#[repr(...)]` attribute contains the given representation.
+fn has_repr(attrs: &[Attribute], repr: &str) -> syn::Result<bool> {
+    let mut found = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(repr) {
+                found = true;
+            }
+            if meta.input.peek(token::Paren) {
+                let args;
+                parenthesized!(args in meta.input);
+                args.parse::<proc_macro2::TokenStream>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(found)
+}
+
+/// The type named by a `#[transparent(Type)]` attribute, if any.
+fn wrapped_type(attrs: &[Attribute]) -> syn::Result<Option<Type>> {
+    match attrs.iter().find(|attr| attr.path().is_ident("transparent")) {
+        Some(attr) => attr.parse_args().map(Some),
+        None => Ok(None),
+    }
+}
+
+/// What the `#[transmutable(...)]` attributes ask for.
+#[derive(Default)]
+struct TransmutableOptions {
+    layout: bool,
+    fields: bool,
+    align: Option<(usize, Span)>,
+}
+
+fn transmutable_options(attrs: &[Attribute]) -> syn::Result<TransmutableOptions> {
+    let mut options = TransmutableOptions::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("transmutable")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("layout") {
+                options.layout = true;
+                Ok(())
+            } else if meta.path.is_ident("fields") {
+                options.fields = true;
+                Ok(())
+            } else if meta.path.is_ident("align") {
+                let lit: LitInt = meta.value()?.parse()?;
+                let align: usize = lit.base10_parse()?;
+                if !align.is_power_of_two() {
+                    return Err(syn::Error::new(lit.span(), "alignment must be a power of two"));
+                }
+                options.align = Some((align, lit.span()));
+                Ok(())
+            } else {
+                Err(meta.error("unknown transmutable attribute, expected `layout`, `fields`, or `align = N`"))
+            }
+        })?;
+    }
+    Ok(options)
+}
@@ -5,11 +5,20 @@ use core::mem::{align_of, size_of};
 use self::super::error::UnalignedError;
 
 
+fn validate_address(address: usize, align: usize) -> Result<(), usize> {
+    let offset = address % align;
+    if offset > 0 {
+        // reverse the offset (from "bytes to insert" to "bytes to remove")
+        Err(align - offset)
+    } else {
+        Ok(())
+    }
+}
+
 fn validate_alignment<S, T>(data: &[S]) -> Result<(), usize> {
     // TODO this could probably become more efficient once `ptr::align_offset`
     // is stabilized (#44488)
-    let ptr = data.as_ptr();
-    let offset = ptr as usize % align_of::<T>();
+    let offset = data.as_ptr() as usize % align_of::<T>();
     if offset > 0 {
         // reverse the offset (from "bytes to insert" to "bytes to remove")
         Err(size_of::<T>() - offset)
@@ -44,3 +53,98 @@ pub fn check_alignment_mut<S, T>(data: &mut [S]) -> Result<&mut [S], UnalignedEr
         Err(off) => Err(UnalignedError::new(off, data)),
     }
 }
+
+/// Check whether the given pointer is properly aligned for reading and
+/// writing values of type `T`.
+///
+/// The pointer is never dereferenced.
+///
+/// # Errors
+///
+/// The number of bytes to skip in order to reach a properly aligned address
+/// is returned.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::align::check_ptr_alignment;
+/// # use safe_transmute::transmute_to_bytes;
+/// let bytes = transmute_to_bytes(&[0u32; 2]);
+///
+/// assert_eq!(check_ptr_alignment::<u32>(bytes.as_ptr()), Ok(()));
+/// assert_eq!(check_ptr_alignment::<u32>(bytes[1..].as_ptr()), Err(3));
+/// ```
+pub fn check_ptr_alignment<T>(ptr: *const u8) -> Result<(), usize> {
+    validate_address(ptr as usize, align_of::<T>())
+}
+
+/// Check whether the given data slice of `S`s starts at a multiple of the
+/// given alignment, which does not need to be that of any type, like the
+/// width of a SIMD register.
+///
+/// `None` is returned if `align` is not a power of two, and so not a valid
+/// alignment.
+///
+/// # Errors
+///
+/// The number of bytes to discard from the front in order to reach a properly
+/// aligned address is returned.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::align::check_alignment_to;
+/// # use safe_transmute::aligned::{Aligned, A8};
+/// let bytes = Aligned::<A8, _>::new([0u8; 16]);
+///
+/// assert_eq!(check_alignment_to(&bytes[..], 8), Some(Ok(())));
+/// assert_eq!(check_alignment_to(&bytes[6..], 8), Some(Err(2)));
+/// assert_eq!(check_alignment_to(&bytes[..], 6), None);
+/// ```
+pub fn check_alignment_to<S>(data: &[S], align: usize) -> Option<Result<(), usize>> {
+    if align.is_power_of_two() {
+        Some(validate_address(data.as_ptr() as usize, align))
+    } else {
+        None
+    }
+}
+
+/// How many bytes past the previous address aligned for `T` the given byte
+/// slice starts at, or `0` if it is properly aligned.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::align::misalignment;
+/// # use safe_transmute::transmute_to_bytes;
+/// let bytes = transmute_to_bytes(&[0u32; 2]);
+///
+/// assert_eq!(misalignment::<u32>(bytes), 0);
+/// assert_eq!(misalignment::<u32>(&bytes[3..]), 3);
+/// ```
+pub fn misalignment<T>(bytes: &[u8]) -> usize {
+    bytes.as_ptr() as usize % align_of::<T>()
+}
+
+/// How many bytes need to be skipped from the front of the given byte slice
+/// for it to be properly aligned for `T`, or `0` if it already is.
+///
+/// Together with [`misalignment()`](fn.misalignment.html), this adds up to
+/// the alignment of `T` for misaligned data. The result may be larger than the
+/// length of the slice.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::align::offset_to_aligned;
+/// # use safe_transmute::{SingleManyGuard, transmute_many, transmute_to_bytes};
+/// let bytes = transmute_to_bytes(&[0u32; 2]);
+/// let header = &bytes[1..];
+///
+/// let skip = offset_to_aligned::<u32>(header);
+/// assert_eq!(skip, 3);
+/// assert_eq!(transmute_many::<u32, SingleManyGuard>(&header[skip..]), Ok(&[0u32][..]));
+/// ```
+pub fn offset_to_aligned<T>(bytes: &[u8]) -> usize {
+    validate_address(bytes.as_ptr() as usize, align_of::<T>()).err().unwrap_or(0)
+}
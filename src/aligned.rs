@@ -0,0 +1,166 @@
+//! Values aligned in memory as another type.
+//!
+//! An [`Aligned<A, T>`](struct.Aligned.html) holds a `T` at an address
+//! compatible with the alignment of `A`, which makes byte arrays, including
+//! `static` ones, transmutable into slices of wider types without ever
+//! failing due to memory alignment. The `A1` to `A64` types name the
+//! alignments of up to 64 bytes, but any type will do.
+//!
+//! # Examples
+//!
+//! ```
+//! # use safe_transmute::aligned::{Aligned, A8};
+//! # use safe_transmute::{PedanticGuard, transmute_aligned};
+//! static TABLE: Aligned<A8, [u8; 16]> = Aligned::new([1, 0, 0, 0, 0, 0, 0, 0,
+//!                                                     2, 0, 0, 0, 0, 0, 0, 0]);
+//!
+//! # fn main() {
+//! assert_eq!(transmute_aligned::<u64, _, PedanticGuard>(&TABLE), Ok(&[u64::from_le(1), u64::from_le(2)][..]));
+//! # }
+//! ```
+
+
+use self::super::trivial::TriviallyTransmutable;
+use self::super::error::GuardError;
+use core::ops::{DerefMut, Deref};
+use self::super::guard::Guard;
+use core::hash::{Hasher, Hash};
+use core::marker::PhantomData;
+use core::mem::align_of;
+use core::{slice, fmt};
+
+
+macro_rules! alignments {
+    ($($name:ident: $align:literal)*) => {
+        $(
+            #[doc = concat!("A zero-sized type aligned to ", stringify!($align), " bytes.")]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+            #[repr(align($align))]
+            pub struct $name;
+        )*
+    };
+}
+
+alignments!(A1: 1 A2: 2 A4: 4 A8: 8 A16: 16 A32: 32 A64: 64);
+
+
+/// A value aligned in memory at least as strictly as `A`.
+///
+/// The value is accessed through `Deref` and `DerefMut`. A reference to an
+/// aligned array, like `&Aligned<A8, [u8; N]>`, coerces into one to an
+/// aligned slice, `&Aligned<A8, [u8]>`.
+#[repr(C)]
+pub struct Aligned<A, T: ?Sized> {
+    _alignment: [A; 0],
+    value: T,
+}
+
+impl<A, T> Aligned<A, T> {
+    /// Align the given value.
+    pub const fn new(value: T) -> Aligned<A, T> {
+        Aligned {
+            _alignment: [],
+            value,
+        }
+    }
+
+    /// Unwrap the value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<A, T: ?Sized> Deref for Aligned<A, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<A, T: ?Sized> DerefMut for Aligned<A, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<A, T: Clone> Clone for Aligned<A, T> {
+    fn clone(&self) -> Self {
+        Aligned::new(self.value.clone())
+    }
+}
+
+impl<A: Copy, T: Copy> Copy for Aligned<A, T> {}
+
+impl<A, T: Default> Default for Aligned<A, T> {
+    fn default() -> Self {
+        Aligned::new(T::default())
+    }
+}
+
+impl<A, T: ?Sized + PartialEq> PartialEq for Aligned<A, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<A, T: ?Sized + Eq> Eq for Aligned<A, T> {}
+
+impl<A, T: ?Sized + Hash> Hash for Aligned<A, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<A, T: ?Sized + fmt::Debug> fmt::Debug for Aligned<A, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Aligned").field(&&self.value).finish()
+    }
+}
+
+
+/// Compile-time assertion that `A` is aligned at least as strictly as `T`.
+struct AssertAlignedFor<A, T>(PhantomData<(A, T)>);
+
+impl<A, T> AssertAlignedFor<A, T> {
+    const OK: () = assert!(align_of::<A>() >= align_of::<T>(), "alignment too loose for the target type");
+}
+
+/// View aligned bytes as a slice of values.
+///
+/// The bytes being aligned as `A` is checked at compile time to suffice for
+/// `T`, so only the guard can fail.
+///
+/// The required byte length of the slice depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::aligned::{Aligned, A4};
+/// # use safe_transmute::{SingleManyGuard, transmute_aligned};
+/// let bytes = Aligned::<A4, _>::new([0xFF; 6]);
+///
+/// assert_eq!(transmute_aligned::<u32, _, SingleManyGuard>(&bytes), Ok(&[0xFFFF_FFFF][..]));
+/// ```
+///
+/// Alignments too loose for the target type do not compile:
+///
+/// ```compile_fail
+/// # use safe_transmute::aligned::{Aligned, A2};
+/// # use safe_transmute::{SingleManyGuard, transmute_aligned};
+/// let bytes = Aligned::<A2, _>::new([0xFF; 4]);
+/// let _ = transmute_aligned::<u32, _, SingleManyGuard>(&bytes);
+/// ```
+pub fn transmute_aligned<T: TriviallyTransmutable, A, G: Guard>(bytes: &Aligned<A, [u8]>) -> Result<&[T], GuardError> {
+    let () = AssertAlignedFor::<A, T>::OK;
+
+    let len = G::check::<T>(&bytes.value)?;
+    // The bytes are aligned for `T`, and hold `len` values
+    Ok(unsafe { slice::from_raw_parts(bytes.value.as_ptr() as *const T, len) })
+}
@@ -0,0 +1,217 @@
+//! Asynchronous reading and writing of typed values.
+//!
+//! These are the non-blocking counterparts of
+//! [`TransmuteReader`](../stream/struct.TransmuteReader.html) and
+//! [`TransmuteWriter`](../stream/struct.TransmuteWriter.html), built on the
+//! runtime-agnostic `AsyncRead` and `AsyncWrite` traits of the `futures-io` crate.
+//! Values are produced as a `Stream`, and consumed as a `Sink`.
+//!
+//! Does not exist without the `async` feature.
+
+
+use self::super::to_bytes::transmute_one_to_bytes;
+use self::super::trivial::TriviallyTransmutable;
+use futures_io::{AsyncWrite, AsyncRead};
+use self::super::stream::ReadBuffer;
+use core::task::{Context, Poll};
+use std::io::{self, ErrorKind};
+use futures_core::Stream;
+use core::marker::PhantomData;
+use core::mem::size_of;
+use futures_sink::Sink;
+use alloc::vec::Vec;
+use core::pin::Pin;
+use core::fmt;
+
+
+/// A stream reading values of a trivially transmutable type out of an
+/// `AsyncRead` implementor.
+///
+/// The data is read in large blocks into an internal buffer, which is
+/// properly aligned for `T`, with values split across reads being put back
+/// together.
+///
+/// If the reader reaches its end in the middle of a value, an error of kind
+/// `UnexpectedEof` is yielded before the stream ends.
+pub struct AsyncTransmuteReader<R, T> {
+    reader: R,
+    buffer: ReadBuffer<T>,
+}
+
+impl<R: AsyncRead + Unpin, T: TriviallyTransmutable> AsyncTransmuteReader<R, T> {
+    /// Create a reader with a buffer of approximately 8 KiB.
+    pub fn new(reader: R) -> AsyncTransmuteReader<R, T> {
+        AsyncTransmuteReader::with_capacity(ReadBuffer::<T>::DEFAULT_CAPACITY, reader)
+    }
+
+    /// Create a reader with a buffer of (at least one) `capacity` values.
+    pub fn with_capacity(capacity: usize, reader: R) -> AsyncTransmuteReader<R, T> {
+        AsyncTransmuteReader {
+            reader: reader,
+            buffer: ReadBuffer::with_capacity(capacity),
+        }
+    }
+
+    /// Get a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Get a mutable reference to the underlying reader.
+    ///
+    /// Reading directly from it will skip over data not yet yielded by this stream.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Unwrap this stream, returning the underlying reader.
+    ///
+    /// Any buffered data not yet yielded by this stream is lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: AsyncRead + Unpin, T: TriviallyTransmutable> Stream for AsyncTransmuteReader<R, T> {
+    type Item = io::Result<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<io::Result<T>>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(value) = this.buffer.take() {
+                return Poll::Ready(value);
+            }
+
+            match Pin::new(&mut this.reader).poll_read(cx, this.buffer.spare()) {
+                Poll::Ready(Ok(read)) => this.buffer.filled(read),
+                Poll::Ready(Err(ref e)) if e.kind() == ErrorKind::Interrupted => {}
+                Poll::Ready(Err(e)) => return Poll::Ready(Some(Err(e))),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+// The values are never pinned
+impl<R: Unpin, T> Unpin for AsyncTransmuteReader<R, T> {}
+
+impl<R: fmt::Debug, T> fmt::Debug for AsyncTransmuteReader<R, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AsyncTransmuteReader")
+            .field("reader", &self.reader)
+            .field("buffered", &self.buffer.buffered())
+            .finish()
+    }
+}
+
+
+/// A sink writing values of a trivially transmutable type into an
+/// `AsyncWrite` implementor, as their raw in-memory byte representation.
+///
+/// Values are gathered in an internal buffer, which is written out once full,
+/// or when the sink is flushed or closed. Values only partially accepted by
+/// the writer are resumed on the next write.
+pub struct AsyncTransmuteWriter<W, T> {
+    writer: W,
+    buffer: Vec<u8>,
+    written: usize,
+    capacity: usize,
+    _phantom: PhantomData<fn(T)>,
+}
+
+impl<W: AsyncWrite + Unpin, T: TriviallyTransmutable> AsyncTransmuteWriter<W, T> {
+    /// Create a writer with a buffer of approximately 8 KiB.
+    pub fn new(writer: W) -> AsyncTransmuteWriter<W, T> {
+        AsyncTransmuteWriter::with_capacity(ReadBuffer::<T>::DEFAULT_CAPACITY, writer)
+    }
+
+    /// Create a writer with a buffer of (at least one) `capacity` values.
+    pub fn with_capacity(capacity: usize, writer: W) -> AsyncTransmuteWriter<W, T> {
+        let capacity = capacity.saturating_mul(size_of::<T>()).max(1);
+        AsyncTransmuteWriter {
+            writer: writer,
+            buffer: Vec::with_capacity(capacity),
+            written: 0,
+            capacity: capacity,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Get a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Get a mutable reference to the underlying writer.
+    ///
+    /// Writing directly to it will place data before the values not yet flushed.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Unwrap this sink, returning the underlying writer.
+    ///
+    /// Any buffered values not yet flushed are lost.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn poll_write_buffer(&mut self, cx: &mut Context) -> Poll<io::Result<()>> {
+        while self.written < self.buffer.len() {
+            match Pin::new(&mut self.writer).poll_write(cx, &self.buffer[self.written..]) {
+                Poll::Ready(Ok(0)) => return Poll::Ready(Err(io::Error::new(ErrorKind::WriteZero, "failed to write the buffered values"))),
+                Poll::Ready(Ok(written)) => self.written += written,
+                Poll::Ready(Err(ref e)) if e.kind() == ErrorKind::Interrupted => {}
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        self.buffer.clear();
+        self.written = 0;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W: AsyncWrite + Unpin, T: TriviallyTransmutable> Sink<T> for AsyncTransmuteWriter<W, T> {
+    type Error = io::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if this.buffer.len() >= this.capacity {
+            this.poll_write_buffer(cx)
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> io::Result<()> {
+        self.get_mut().buffer.extend_from_slice(transmute_one_to_bytes(&item));
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        match this.poll_write_buffer(cx) {
+            Poll::Ready(Ok(())) => Pin::new(&mut this.writer).poll_flush(cx),
+            other => other,
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        match this.poll_write_buffer(cx) {
+            Poll::Ready(Ok(())) => Pin::new(&mut this.writer).poll_close(cx),
+            other => other,
+        }
+    }
+}
+
+impl<W: fmt::Debug, T> fmt::Debug for AsyncTransmuteWriter<W, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AsyncTransmuteWriter")
+            .field("writer", &self.writer)
+            .field("buffered", &(self.buffer.len() - self.written))
+            .finish()
+    }
+}
@@ -0,0 +1,97 @@
+//! Views of byte slices as slices of atomic integers.
+//!
+//! Atomic types cannot be
+//! [`TriviallyTransmutable`](../trivial/trait.TriviallyTransmutable.html),
+//! as they are not `Copy`. More importantly, they may be modified through
+//! shared references, so they may only be viewed out of data that is not
+//! otherwise referenced; that is, out of mutable byte slices.
+//!
+//! The resulting shared slices can then be used concurrently, like with
+//! counters in a buffer of shared memory.
+
+
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicUsize, AtomicIsize};
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::{AtomicU8, AtomicI8};
+#[cfg(target_has_atomic = "16")]
+use core::sync::atomic::{AtomicU16, AtomicI16};
+#[cfg(target_has_atomic = "32")]
+use core::sync::atomic::{AtomicU32, AtomicI32};
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicU64, AtomicI64};
+use self::super::align::check_alignment_mut;
+use self::super::guard::Guard;
+use self::super::Error;
+use core::slice;
+
+
+/// Atomic type which has the same in-memory representation as a trivially
+/// transmutable type.
+///
+/// This is implemented for the atomic integer types available on the target.
+///
+/// # Safety
+///
+/// It is only safe to implement `AtomicTransmutable` for a type `A` if any
+/// combination of `size_of::<A>()` bytes, aligned for `A`, is a valid `A`.
+pub unsafe trait AtomicTransmutable: Sync {}
+
+#[cfg(target_has_atomic = "8")]
+unsafe impl AtomicTransmutable for AtomicU8 {}
+#[cfg(target_has_atomic = "8")]
+unsafe impl AtomicTransmutable for AtomicI8 {}
+#[cfg(target_has_atomic = "16")]
+unsafe impl AtomicTransmutable for AtomicU16 {}
+#[cfg(target_has_atomic = "16")]
+unsafe impl AtomicTransmutable for AtomicI16 {}
+#[cfg(target_has_atomic = "32")]
+unsafe impl AtomicTransmutable for AtomicU32 {}
+#[cfg(target_has_atomic = "32")]
+unsafe impl AtomicTransmutable for AtomicI32 {}
+#[cfg(target_has_atomic = "64")]
+unsafe impl AtomicTransmutable for AtomicU64 {}
+#[cfg(target_has_atomic = "64")]
+unsafe impl AtomicTransmutable for AtomicI64 {}
+#[cfg(target_has_atomic = "ptr")]
+unsafe impl AtomicTransmutable for AtomicUsize {}
+#[cfg(target_has_atomic = "ptr")]
+unsafe impl AtomicTransmutable for AtomicIsize {}
+
+
+/// View a mutable byte slice as a slice of atomic integers.
+///
+/// The required byte length of the slice depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `A`. Note that
+///   64-bit atomics may have a stricter alignment than the respective integers.
+/// - The data does not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::atomic::transmute_many_atomic;
+/// # use safe_transmute::{PedanticGuard, transmute_to_bytes_mut};
+/// # use std::sync::atomic::{AtomicU32, Ordering};
+/// let mut counters = [0u32; 4];
+/// {
+/// # /*
+///     let atomics = transmute_many_atomic::<AtomicU32, PedanticGuard>(transmute_to_bytes_mut(&mut counters))?;
+/// # */
+/// #   let atomics = transmute_many_atomic::<AtomicU32, PedanticGuard>(transmute_to_bytes_mut(&mut counters)).unwrap();
+///     atomics[2].fetch_add(3, Ordering::Relaxed);
+/// }
+///
+/// assert_eq!(counters, [0, 0, 3, 0]);
+/// ```
+pub fn transmute_many_atomic<A: AtomicTransmutable, G: Guard>(bytes: &mut [u8]) -> Result<&[A], Error<'_, u8, A>> {
+    let bytes = check_alignment_mut::<_, A>(bytes)?;
+    let len = G::check::<A>(bytes)?;
+    // The data is aligned for `A`, contains at least `len` values, and is borrowed exclusively
+    Ok(unsafe { slice::from_raw_parts(bytes.as_mut_ptr() as *const A, len) })
+}
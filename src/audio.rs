@@ -0,0 +1,192 @@
+//! Views of byte slices as PCM audio samples.
+//!
+//! Uncompressed audio, like the data chunk of a WAV file, is a sequence of
+//! frames, each holding one sample per channel, in a byte order fixed by the
+//! file format. The functions in this module convert such samples to the
+//! native byte order in place, and view them as a slice of frames, so that
+//! channels can be indexed as `frames[i][channel]`.
+//!
+//! # Examples
+//!
+//! ```
+//! # use safe_transmute::audio::{transmute_frames, transmute_samples_i16};
+//! # use safe_transmute::endian::LittleEndian;
+//! # use safe_transmute::transmute_to_bytes_mut;
+//! // Two stereo frames of 16-bit little-endian PCM
+//! let mut data = [0x0100u16.to_le(), 0xFF00u16.to_le(), 0x0200u16.to_le(), 0xFE00u16.to_le()];
+//! let bytes = transmute_to_bytes_mut(&mut data);
+//! transmute_samples_i16::<LittleEndian>(bytes).unwrap();
+//!
+//! let frames = transmute_frames::<i16, 2>(bytes).unwrap();
+//! assert_eq!(frames, &[[0x0100, -0x0100], [0x0200, -0x0200]]);
+//! ```
+
+
+use self::super::full::{transmute_many_mut, transmute_many};
+#[cfg(feature = "alloc")]
+use self::super::endian::transmute_many_endian;
+use self::super::endian::{swap_bytes_endian, ByteOrder, SwapBytes};
+use self::super::trivial::TriviallyTransmutable;
+use self::super::guard::AllOrNothingGuard;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use self::super::Error;
+
+
+/// View a mutable byte slice as a slice of samples, converting them from the
+/// byte order `E` to the native one in place.
+///
+/// The byte slice must hold a whole number of samples, possibly none.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`. Use
+///   [`decode_samples()`](fn.decode_samples.html) to copy it instead.
+/// - The data does not hold a whole number of samples.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::audio::transmute_samples;
+/// # use safe_transmute::endian::BigEndian;
+/// # use safe_transmute::transmute_to_bytes_mut;
+/// let mut data = [0x1234_5678u32.to_be()];
+///
+/// assert_eq!(transmute_samples::<i32, BigEndian>(transmute_to_bytes_mut(&mut data)), Ok(&mut [0x1234_5678][..]));
+/// ```
+pub fn transmute_samples<T: SwapBytes, E: ByteOrder>(bytes: &mut [u8]) -> Result<&mut [T], Error<'_, u8, T>> {
+    let samples = transmute_many_mut::<T, AllOrNothingGuard>(bytes)?;
+    swap_bytes_endian::<T, E>(samples);
+    Ok(samples)
+}
+
+/// View a mutable byte slice as a slice of 16-bit integer samples, converting
+/// them from the byte order `E` to the native one in place.
+///
+/// See [`transmute_samples()`](fn.transmute_samples.html) for details.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::audio::transmute_samples_i16;
+/// # use safe_transmute::endian::LittleEndian;
+/// # use safe_transmute::transmute_to_bytes_mut;
+/// let mut data = [0x8000u16.to_le(), 0x7FFFu16.to_le()];
+///
+/// assert_eq!(transmute_samples_i16::<LittleEndian>(transmute_to_bytes_mut(&mut data)), Ok(&mut [i16::MIN, i16::MAX][..]));
+/// ```
+pub fn transmute_samples_i16<E: ByteOrder>(bytes: &mut [u8]) -> Result<&mut [i16], Error<'_, u8, i16>> {
+    transmute_samples::<i16, E>(bytes)
+}
+
+/// View a mutable byte slice as a slice of floating-point samples, converting
+/// them from the byte order `E` to the native one in place.
+///
+/// See [`transmute_samples()`](fn.transmute_samples.html) for details.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::audio::transmute_samples_f32;
+/// # use safe_transmute::endian::BigEndian;
+/// # use safe_transmute::transmute_to_bytes_mut;
+/// let mut data = [0.5f32.to_bits().to_be(), (-1.0f32).to_bits().to_be()];
+///
+/// assert_eq!(transmute_samples_f32::<BigEndian>(transmute_to_bytes_mut(&mut data)), Ok(&mut [0.5, -1.0][..]));
+/// ```
+pub fn transmute_samples_f32<E: ByteOrder>(bytes: &mut [u8]) -> Result<&mut [f32], Error<'_, u8, f32>> {
+    transmute_samples::<f32, E>(bytes)
+}
+
+/// Copy the samples stored in the byte order `E` out of a byte slice into a
+/// vector.
+///
+/// The byte slice must hold a whole number of samples, possibly none. The data
+/// does not need to be aligned.
+///
+/// # Errors
+///
+/// An error is returned if the data does not hold a whole number of samples.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::audio::decode_samples;
+/// # use safe_transmute::endian::LittleEndian;
+/// assert_eq!(decode_samples::<i16, LittleEndian>(&[0x00, 0x80, 0xFF, 0x7F]), Ok(vec![i16::MIN, i16::MAX]));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_samples<T: SwapBytes, E: ByteOrder>(bytes: &[u8]) -> Result<Vec<T>, Error<'_, u8, T>> {
+    transmute_many_endian::<T, E, AllOrNothingGuard>(bytes)
+}
+
+/// View a byte slice as a slice of interleaved frames of `CH` samples each.
+///
+/// The byte slice must hold a whole number of frames, possibly none.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`.
+/// - The data does not hold a whole number of frames, or `CH` is `0`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::audio::transmute_frames;
+/// # use safe_transmute::transmute_to_bytes;
+/// let samples = [0.25f32, -0.25, 0.5, -0.5];
+///
+/// assert_eq!(transmute_frames::<f32, 2>(transmute_to_bytes(&samples)), Ok(&[[0.25, -0.25], [0.5, -0.5]][..]));
+/// ```
+pub fn transmute_frames<T: TriviallyTransmutable, const CH: usize>(bytes: &[u8]) -> Result<&[[T; CH]], Error<'_, u8, [T; CH]>> {
+    transmute_many::<[T; CH], AllOrNothingGuard>(bytes)
+}
+
+/// View a mutable byte slice as a mutable slice of interleaved frames of `CH`
+/// samples each.
+///
+/// See [`transmute_frames()`](fn.transmute_frames.html) for details.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::audio::transmute_frames_mut;
+/// # use safe_transmute::transmute_to_bytes_mut;
+/// let mut samples = [1i16, 2, 3, 4];
+/// for frame in transmute_frames_mut::<i16, 2>(transmute_to_bytes_mut(&mut samples)).unwrap() {
+///     frame.swap(0, 1);
+/// }
+///
+/// assert_eq!(samples, [2, 1, 4, 3]);
+/// ```
+pub fn transmute_frames_mut<T: TriviallyTransmutable, const CH: usize>(bytes: &mut [u8]) -> Result<&mut [[T; CH]], Error<'_, u8, [T; CH]>> {
+    transmute_many_mut::<[T; CH], AllOrNothingGuard>(bytes)
+}
+
+/// View a mutable byte slice as a mutable slice of interleaved frames of `CH`
+/// samples each, converting the samples from the byte order `E` to the native
+/// one in place.
+///
+/// See [`transmute_frames()`](fn.transmute_frames.html) for details.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::audio::transmute_frames_endian;
+/// # use safe_transmute::endian::BigEndian;
+/// # use safe_transmute::transmute_to_bytes_mut;
+/// let mut data = [1u16.to_be(), 2u16.to_be(), 3u16.to_be()];
+///
+/// assert_eq!(transmute_frames_endian::<u16, BigEndian, 3>(transmute_to_bytes_mut(&mut data)), Ok(&mut [[1, 2, 3]][..]));
+/// ```
+pub fn transmute_frames_endian<T: SwapBytes, E: ByteOrder, const CH: usize>(bytes: &mut [u8]) -> Result<&mut [[T; CH]], Error<'_, u8, [T; CH]>> {
+    let frames = transmute_many_mut::<[T; CH], AllOrNothingGuard>(bytes)?;
+    for frame in frames.iter_mut() {
+        swap_bytes_endian::<T, E>(frame);
+    }
+    Ok(frames)
+}
@@ -4,14 +4,14 @@
 //! recommended unless you *really* know what you are doing.
 
 
-use self::super::guard::{SingleValueGuard, PermissiveGuard, SingleManyGuard, Guard};
-use self::super::error::Error;
-use core::mem::size_of;
+use self::super::guard::{SingleValueGuard, PermissiveGuard, SingleManyGuard, RuntimeGuard, Guard};
+use self::super::error::{GuardError, Error};
 #[cfg(feature = "alloc")]
-use core::mem::forget;
+use core::mem::{size_of, forget};
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 use core::slice;
+use core::ptr;
 
 
 /// Convert a byte slice into a single instance of a `Copy`able type.
@@ -95,6 +95,83 @@ pub unsafe fn from_bytes_pedantic<T: Copy>(bytes: &[u8]) -> Result<T, Error<u8,
     Ok(slice::from_raw_parts(bytes.as_ptr() as *const T, 1)[0])
 }
 
+/// Convert a byte slice into a single instance of a `Copy`able type in a
+/// constant context.
+///
+/// The byte slice must have at least enough bytes to fill a single instance of
+/// a type, extraneous data is ignored.
+///
+/// This is equivalent to [`from_bytes()`](fn.from_bytes.html), except that
+/// the value is read without any memory alignment requirements, and that
+/// only a [`GuardError`](../error/struct.GuardError.html) can be returned,
+/// which can be disposed of in a constant context.
+///
+/// # Safety
+///
+/// The byte data needs to correspond to a valid `T` value.
+///
+/// Failure to fulfill this requirement may result in undefined behavior.
+///
+/// # Errors
+///
+/// An error is returned if the slice does not have enough bytes for a single
+/// value `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::base::from_bytes_const;
+/// const WORD: u32 = match unsafe { from_bytes_const::<u32>(&[0xFF, 0xFF, 0xFF, 0xFF, 0x00]) } {
+///     Ok(word) => word,
+///     Err(_) => panic!("not enough bytes"),
+/// };
+///
+/// assert_eq!(WORD, 0xFFFF_FFFF);
+/// ```
+pub const unsafe fn from_bytes_const<T: Copy>(bytes: &[u8]) -> Result<T, GuardError> {
+    match SingleManyGuard::check_const::<T>(bytes) {
+        Ok(_) => Ok(ptr::read_unaligned(bytes.as_ptr() as *const T)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Convert a byte slice into a single instance of a `Copy`able type in a
+/// constant context.
+///
+/// The byte slice must have exactly the expected number of bytes to fill a
+/// single instance of a type, without trailing space.
+///
+/// This is equivalent to [`from_bytes_pedantic()`](fn.from_bytes_pedantic.html),
+/// except that the value is read without any memory alignment requirements,
+/// and that only a [`GuardError`](../error/struct.GuardError.html) can be
+/// returned, which can be disposed of in a constant context.
+///
+/// # Safety
+///
+/// The byte data needs to correspond to a valid `T` value.
+///
+/// Failure to fulfill this requirement may result in undefined behavior.
+///
+/// # Errors
+///
+/// An error is returned if the slice's length is not equal to the size of a
+/// single value `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::base::from_bytes_pedantic_const;
+/// const WORD: Result<u32, safe_transmute::GuardError> = unsafe { from_bytes_pedantic_const::<u32>(&[0xFF, 0xFF, 0xFF, 0xFF, 0x00]) };
+///
+/// assert!(WORD.is_err());
+/// ```
+pub const unsafe fn from_bytes_pedantic_const<T: Copy>(bytes: &[u8]) -> Result<T, GuardError> {
+    match SingleValueGuard::check_const::<T>(bytes) {
+        Ok(_) => Ok(ptr::read_unaligned(bytes.as_ptr() as *const T)),
+        Err(e) => Err(e),
+    }
+}
+
 /// View a byte slice as a slice of an arbitrary type.
 ///
 /// The required byte length of the slice depends on the chosen boundary guard.
@@ -136,8 +213,9 @@ pub unsafe fn from_bytes_pedantic<T: Copy>(bytes: &[u8]) -> Result<T, Error<u8,
 /// # }
 /// ```
 pub unsafe fn transmute_many<T, G: Guard>(bytes: &[u8]) -> Result<&[T], Error<u8, T>> {
-    G::check::<T>(bytes)?;
-    Ok(slice::from_raw_parts(bytes.as_ptr() as *const T, bytes.len() / size_of::<T>()))
+    let len = G::check::<T>(bytes)?;
+    // The guard's contract ensures that `bytes` holds `len` values
+    Ok(slice::from_raw_parts(bytes.as_ptr() as *const T, len))
 }
 
 /// View a mutable byte slice as a slice of an arbitrary type.
@@ -181,15 +259,144 @@ pub unsafe fn transmute_many<T, G: Guard>(bytes: &[u8]) -> Result<&[T], Error<u8
 /// # }
 /// ```
 pub unsafe fn transmute_many_mut<T, G: Guard>(bytes: &mut [u8]) -> Result<&mut [T], Error<u8, T>> {
-    G::check::<T>(bytes)?;
-    Ok(slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut T, bytes.len() / size_of::<T>()))
+    let len = G::check::<T>(bytes)?;
+    // The guard's contract ensures that `bytes` holds `len` values
+    Ok(slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut T, len))
+}
+
+/// View a byte slice as a slice of an arbitrary type, checking it against a
+/// guard value.
+///
+/// This is equivalent to [`transmute_many()`](fn.transmute_many.html), but
+/// with a guard which may depend on runtime state.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Safety
+///
+/// - This function does not perform memory alignment checks. The beginning of
+///   the slice data must be properly aligned for accessing vlues of type `T`.
+/// - The byte data needs to correspond to a valid contiguous sequence of `T`
+///   values. Types `T` with a `Drop` implementation are unlikely to be safe
+///   in this regard.
+///
+/// Failure to fulfill any of the requirements above may result in undefined
+/// behavior.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::base::transmute_many_with;
+/// # use safe_transmute::guard::BoundedGuard;
+/// # include!("../tests/test_util/le_to_native.rs");
+/// # fn main() {
+/// // Little-endian
+/// unsafe {
+/// # /*
+///     assert_eq!(
+///         transmute_many_with::<u16, _>(&[0x00, 0x01, 0x00, 0x02], BoundedGuard::new(1, 2))?,
+/// # */
+/// #   assert_eq!(transmute_many_with::<u16, _>(&Le2NAl4([0x00, 0x01, 0x00, 0x02]).0.le_to_native::<u16>(), BoundedGuard::new(1, 2)).unwrap(),
+///         &[0x0100, 0x0200]
+///     );
+/// }
+/// # }
+/// ```
+pub unsafe fn transmute_many_with<T, G: RuntimeGuard>(bytes: &[u8], guard: G) -> Result<&[T], Error<'_, u8, T>> {
+    let len = guard.check_bytes::<T>(bytes)?;
+    // The guard's contract ensures that `bytes` holds `len` values
+    Ok(slice::from_raw_parts(bytes.as_ptr() as *const T, len))
+}
+
+/// View a mutable byte slice as a slice of an arbitrary type, checking it
+/// against a guard value.
+///
+/// This is equivalent to [`transmute_many_mut()`](fn.transmute_many_mut.html),
+/// but with a guard which may depend on runtime state.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Safety
+///
+/// - This function does not perform memory alignment checks. The beginning of
+///   the slice data must be properly aligned for accessing vlues of type `T`.
+/// - The byte data needs to correspond to a valid contiguous sequence of `T`
+///   values. Types `T` with a `Drop` implementation are unlikely to be safe
+///   in this regard.
+///
+/// Failure to fulfill any of the requirements above may result in undefined
+/// behavior.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard.
+pub unsafe fn transmute_many_mut_with<T, G: RuntimeGuard>(bytes: &mut [u8], guard: G) -> Result<&mut [T], Error<'_, u8, T>> {
+    let len = guard.check_bytes::<T>(bytes)?;
+    // The guard's contract ensures that `bytes` holds `len` values
+    Ok(slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut T, len))
+}
+
+/// Check a pointer to `len` bytes against a guard, and cast it to a pointer
+/// to values of an arbitrary type.
+///
+/// The required byte length depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// This is meant for callers which receive a pointer and a length, such as
+/// over FFI: unlike creating a `&[u8]` out of them, it makes no assumptions
+/// about the memory behind the pointer, which is never accessed. Reading the
+/// values through the resulting pointer is subject to the same requirements
+/// as [`transmute_many()`](fn.transmute_many.html), including alignment.
+///
+/// # Errors
+///
+/// An error is returned if `len` does not comply with the policies of the
+/// given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::base::transmute_ptr;
+/// # use safe_transmute::{PedanticGuard, Error, transmute_to_bytes};
+/// # fn run() -> Result<(), Error<'static, u8, u16>> {
+/// let words: &[u16] = &[0x0100, 0x0200];
+/// let bytes = transmute_to_bytes(words);
+///
+/// let values = transmute_ptr::<u16, PedanticGuard>(bytes.as_ptr(), bytes.len())?;
+/// assert_eq!(unsafe { *values.add(1) }, 0x0200);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+pub fn transmute_ptr<T, G: Guard>(ptr: *const u8, len: usize) -> Result<*const T, Error<'static, u8, T>> {
+    G::check_len::<T>(len)?;
+    Ok(ptr as *const T)
+}
+
+/// Check a mutable pointer to `len` bytes against a guard, and cast it to a
+/// pointer to values of an arbitrary type.
+///
+/// See [`transmute_ptr()`](fn.transmute_ptr.html) for details.
+///
+/// # Errors
+///
+/// An error is returned if `len` does not comply with the policies of the
+/// given guard `G`.
+pub fn transmute_ptr_mut<T, G: Guard>(ptr: *mut u8, len: usize) -> Result<*mut T, Error<'static, u8, T>> {
+    G::check_len::<T>(len)?;
+    Ok(ptr as *mut T)
 }
 
 /// View a byte slice as a slice of an arbitrary type.
 ///
 /// The resulting slice will have as many instances of a type as will fit,
-/// rounded down. The permissive guard is a no-op, which makes it possible for
-/// this function to return a slice directly. It is therefore equivalent to
+/// rounded down. The permissive guard only rejects zero-sized types, for
+/// which this function returns an empty slice instead, which makes it possible
+/// to return a slice directly. It is otherwise equivalent to
 /// `transmute_many::<_, PermissiveGuard>(bytes).unwrap()`.
 ///
 /// # Safety
@@ -222,7 +429,10 @@ pub unsafe fn transmute_many_mut<T, G: Guard>(bytes: &mut [u8]) -> Result<&mut [
 /// # }
 /// ```
 pub unsafe fn transmute_many_permissive<T>(bytes: &[u8]) -> &[T] {
-    transmute_many::<_, PermissiveGuard>(bytes).expect("permissive guard should never fail")
+    match PermissiveGuard::check::<T>(bytes) {
+        Ok(len) => slice::from_raw_parts(bytes.as_ptr() as *const T, len),
+        Err(_) => &[],
+    }
 }
 
 /// Transform a vector into a vector of another element type.
@@ -14,7 +14,7 @@
 
 
 use self::super::guard::{PermissiveGuard, PedanticGuard, Guard};
-use self::super::base::transmute_many;
+use self::super::base::{transmute_many_mut, transmute_many};
 #[cfg(feature = "alloc")]
 use self::super::base::transmute_vec;
 use core::mem::transmute;
@@ -45,10 +45,6 @@ fn byte_is_bool(b: u8) -> bool {
     unsafe { b == transmute::<_, u8>(false) || b == transmute::<_, u8>(true) }
 }
 
-fn transmute_bool<G: Guard>(bytes: &[u8]) -> Result<&[bool], Error<u8, bool>> {
-    check_bool(bytes)?;
-    unsafe { transmute_many::<_, G>(bytes) }
-}
 
 /// Helper function for returning an error if any of the bytes does not make a
 /// valid `bool`.
@@ -61,6 +57,58 @@ fn check_bool<'a, T>(bytes: &[u8]) -> Result<(), Error<'a, u8, T>> {
 }
 
 
+/// View a byte slice as a slice of boolean values.
+///
+/// The required byte length of the slice depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `G`, or if any of the bytes is neither 0 nor 1.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{SingleManyGuard, Error, transmute_bool};
+/// # fn run() -> Result<(), Error<'static, u8, bool>> {
+/// assert_eq!(transmute_bool::<SingleManyGuard>(&[0x01, 0x00])?, &[true, false]);
+/// assert_eq!(transmute_bool::<SingleManyGuard>(&[0x01, 0x02]), Err(Error::InvalidValue));
+/// # Ok(())
+/// # }
+/// # run().unwrap()
+/// ```
+pub fn transmute_bool<G: Guard>(bytes: &[u8]) -> Result<&[bool], Error<'_, u8, bool>> {
+    check_bool(bytes)?;
+    unsafe { transmute_many::<_, G>(bytes) }
+}
+
+/// View a mutable byte slice as a mutable slice of boolean values.
+///
+/// The bytes are validated in place, without copying them.
+///
+/// The required byte length of the slice depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `G`, or if any of the bytes is neither 0 nor 1.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PermissiveGuard, transmute_bool_mut};
+/// let mut flags = [0x00, 0x01, 0x00];
+/// transmute_bool_mut::<PermissiveGuard>(&mut flags).unwrap()[0] = true;
+///
+/// assert_eq!(flags, [0x01, 0x01, 0x00]);
+/// ```
+pub fn transmute_bool_mut<G: Guard>(bytes: &mut [u8]) -> Result<&mut [bool], Error<'_, u8, bool>> {
+    check_bool(bytes)?;
+    unsafe { transmute_many_mut::<_, G>(bytes) }
+}
+
 /// View a byte slice as a slice of boolean values.
 ///
 /// The resulting slice will have as many instances of `bool` as will fit, can be empty.
@@ -72,7 +120,7 @@ fn check_bool<'a, T>(bytes: &[u8]) -> Result<(), Error<'a, u8, T>> {
 /// # fn run() -> Result<(), Error<'static, u8, bool>> {
 /// assert_eq!(transmute_bool_permissive(&[0x00, 0x01, 0x00, 0x01])?,
 ///            &[false, true, false, true]);
-/// assert_eq!(transmute_bool_permissive(&[])?, &[]);
+/// assert_eq!(transmute_bool_permissive(&[])?, &[] as &[bool]);
 /// # Ok(())
 /// # }
 /// # run().unwrap()
@@ -101,6 +149,37 @@ pub fn transmute_bool_pedantic(bytes: &[u8]) -> Result<&[bool], Error<u8, bool>>
     transmute_bool::<PedanticGuard>(bytes)
 }
 
+/// Transform a byte vector into a vector of bool.
+///
+/// The vector's allocated byte buffer is always reused. The required byte
+/// length of the vector depends on the chosen boundary guard. Please see the
+/// [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `G`, or if any of the bytes is neither 0 nor 1.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{SingleManyGuard, Error, transmute_bool_vec};
+/// # fn run() -> Result<(), Error<'static, u8, bool>> {
+/// assert_eq!(transmute_bool_vec::<SingleManyGuard>(vec![0x00, 0x01])?, vec![false, true]);
+/// assert!(transmute_bool_vec::<SingleManyGuard>(vec![]).is_err());
+/// # Ok(())
+/// # }
+/// # run().unwrap()
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_bool_vec<G: Guard>(bytes: Vec<u8>) -> Result<Vec<bool>, Error<'static, u8, bool>> {
+    check_bool(&bytes)?;
+    G::check::<u8>(&bytes)?;
+    // Alignment guarantees are ensured, and all values have been checked,
+    // so the conversion is safe.
+    unsafe { Ok(transmute_vec::<u8, bool>(bytes)) }
+}
+
 /// Trasform a byte vector into a vector of bool.
 ///
 /// The vector's allocated byte buffer will be reused when possible.
@@ -0,0 +1,268 @@
+//! Transmutation into types which are valid for only some bit patterns.
+//!
+//! Types like `char` or the `NonZero` integers are not trivially
+//! transmutable, as only some of the values of the integer they are stored as
+//! are valid instances of them. The
+//! [`CheckedTransmutable`](trait.CheckedTransmutable.html) trait names that
+//! integer, and tells which of its values are valid, so that the data can be
+//! validated before it is handed out.
+//!
+//! Invalid data is reported as an
+//! [`Error::InvalidValueAt`](../error/enum.Error.html#variant.InvalidValueAt)
+//! error, with the index and the bytes of the first invalid value.
+//!
+//! Validating a whole slice up front takes time proportional to its length;
+//! a [`CheckedSlice`](struct.CheckedSlice.html) instead validates each value
+//! only when it is read, for large buffers of which only a few values are
+//! ever accessed.
+
+
+use self::super::trivial::TriviallyTransmutable;
+use self::super::align::check_alignment;
+use self::super::guard::{SingleManyGuard, Guard};
+use self::super::error::GuardError;
+use self::super::Error;
+use core::num::{NonZeroUsize, NonZeroIsize, NonZeroU128, NonZeroI128, NonZeroU16, NonZeroI16, NonZeroU32, NonZeroI32, NonZeroU64, NonZeroI64, NonZeroU8,
+                NonZeroI8};
+use core::marker::PhantomData;
+use core::mem::size_of;
+use core::char;
+use core::slice;
+use core::ptr;
+
+
+/// Type which can be constructed out of the bits of a trivially transmutable
+/// type, as long as they pass a validity check.
+///
+/// # Safety
+///
+/// `Bits` must have the same size and alignment as `Self`, and every value
+/// of `Bits` for which `is_valid_bits()` returns `true` must be a valid
+/// instance of `Self`. Values are read out of borrowed byte data without
+/// taking ownership of it, so `Self` must be `Copy`.
+pub unsafe trait CheckedTransmutable: Copy {
+    /// The trivially transmutable type with the same layout.
+    type Bits: TriviallyTransmutable;
+
+    /// Whether the given bits make up a valid instance of `Self`.
+    fn is_valid_bits(bits: &Self::Bits) -> bool;
+}
+
+unsafe impl CheckedTransmutable for char {
+    type Bits = u32;
+
+    #[inline]
+    fn is_valid_bits(bits: &u32) -> bool {
+        char::from_u32(*bits).is_some()
+    }
+}
+
+unsafe impl CheckedTransmutable for bool {
+    type Bits = u8;
+
+    #[inline]
+    fn is_valid_bits(bits: &u8) -> bool {
+        *bits <= 1
+    }
+}
+
+macro_rules! checked_non_zero {
+    ($($non_zero:ident: $int:ident)*) => {
+        $(
+            unsafe impl CheckedTransmutable for $non_zero {
+                type Bits = $int;
+
+                #[inline]
+                fn is_valid_bits(bits: &$int) -> bool {
+                    *bits != 0
+                }
+            }
+        )*
+    };
+}
+
+checked_non_zero!(NonZeroU8: u8 NonZeroI8: i8 NonZeroU16: u16 NonZeroI16: i16 NonZeroU32: u32 NonZeroI32: i32 NonZeroU64: u64 NonZeroI64: i64
+                  NonZeroU128: u128 NonZeroI128: i128 NonZeroUsize: usize NonZeroIsize: isize);
+
+
+/// Transmute a byte slice into a single value of a checked type.
+///
+/// The byte slice must have at least enough bytes to fill a single instance of
+/// a type, extraneous data is ignored. The data does not need to be aligned.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have enough bytes for a single value `T`.
+/// - The bits are not a valid `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{Error, transmute_one_checked, transmute_one_to_bytes};
+/// assert_eq!(transmute_one_checked::<char>(transmute_one_to_bytes(&0x1F980u32)), Ok('🦀'));
+/// assert_eq!(transmute_one_checked::<char>(transmute_one_to_bytes(&0xD800u32)),
+///            Err(Error::InvalidValueAt { index: 0, bytes: &0xD800u32.to_ne_bytes() }));
+/// ```
+pub fn transmute_one_checked<T: CheckedTransmutable>(bytes: &[u8]) -> Result<T, Error<'_, u8, T>> {
+    SingleManyGuard::check::<T>(bytes)?;
+    read_checked(0, &bytes[..size_of::<T>()])
+}
+
+/// View a byte slice as a slice of a checked type.
+///
+/// The required byte length of the slice depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`. You will
+///   have to make a copy anyway, or modify how the data was originally made.
+/// - The data does not comply with the policies of the given guard `G`.
+/// - Any of the values is not a valid `T`, reported with the index and the
+///   bytes of the first one.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{SingleManyGuard, Error, transmute_many_checked, transmute_to_bytes};
+/// let utf32: &[u32] = &[0x0052, 0x0075, 0x0073, 0x0074];
+/// assert_eq!(transmute_many_checked::<char, SingleManyGuard>(transmute_to_bytes(utf32)),
+///            Ok(&['R', 'u', 's', 't'][..]));
+///
+/// let utf32: &[u32] = &[0x0052, 0x0075, 0x11_0000, 0x0074];
+/// assert_eq!(transmute_many_checked::<char, SingleManyGuard>(transmute_to_bytes(utf32)),
+///            Err(Error::InvalidValueAt { index: 2, bytes: &0x11_0000u32.to_ne_bytes() }));
+/// ```
+pub fn transmute_many_checked<T: CheckedTransmutable, G: Guard>(bytes: &[u8]) -> Result<&[T], Error<'_, u8, T>> {
+    check_alignment::<_, T>(bytes)?;
+    let len = G::check::<T>(bytes)?;
+
+    // Alignment and length have been checked, and `Bits` has the same layout as `T`
+    let bits = unsafe { slice::from_raw_parts(bytes.as_ptr() as *const T::Bits, len) };
+    match bits.iter().position(|b| !T::is_valid_bits(b)) {
+        Some(index) => {
+            Err(Error::InvalidValueAt {
+                index,
+                bytes: &bytes[index * size_of::<T>()..(index + 1) * size_of::<T>()],
+            })
+        }
+        // All values have been checked
+        None => Ok(unsafe { slice::from_raw_parts(bits.as_ptr() as *const T, len) }),
+    }
+}
+
+
+/// A view of a byte slice as a slice of a checked type, which validates each
+/// value only when it is read.
+///
+/// Unlike [`transmute_many_checked()`](fn.transmute_many_checked.html), which
+/// validates every value before handing any out, constructing a view takes
+/// constant time, and an invalid value is only reported when it is read.
+/// Values are copied out with unaligned reads, so the data does not need to be
+/// aligned.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{SingleManyGuard, CheckedSlice, Error, transmute_to_bytes};
+/// let utf32: &[u32] = &[0x0052, 0x0075, 0x11_0000, 0x0074];
+/// let chars = CheckedSlice::<char>::new::<SingleManyGuard>(transmute_to_bytes(utf32)).unwrap();
+///
+/// assert_eq!(chars.len(), 4);
+/// assert_eq!(chars.get(1), Some(Ok('u')));
+/// assert_eq!(chars.get(2), Some(Err(Error::InvalidValueAt { index: 2, bytes: &0x11_0000u32.to_ne_bytes() })));
+/// assert_eq!(chars.get(4), None);
+/// ```
+#[derive(Debug)]
+pub struct CheckedSlice<'a, T> {
+    bytes: &'a [u8],
+    len: usize,
+    _phantom: PhantomData<&'a [T]>,
+}
+
+impl<'a, T> Clone for CheckedSlice<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for CheckedSlice<'a, T> {}
+
+impl<'a, T: CheckedTransmutable> CheckedSlice<'a, T> {
+    /// View the given bytes as a slice of `T`, without validating any of the
+    /// values.
+    ///
+    /// The required byte length of the slice depends on the chosen boundary
+    /// guard. Please see the [Guard API](../guard/index.html).
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the data does not comply with the policies of
+    /// the given guard `G`.
+    pub fn new<G: Guard>(bytes: &'a [u8]) -> Result<CheckedSlice<'a, T>, GuardError> {
+        let len = G::check::<T>(bytes)?;
+
+        Ok(CheckedSlice {
+            bytes: &bytes[..len * size_of::<T>()],
+            len,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// The number of values in the view, valid or not.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the view holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The underlying bytes, without any extraneous ones past the last value.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Read and validate the value at the given index, or `None` if it is out
+    /// of bounds.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the value is not a valid `T`.
+    pub fn get(&self, index: usize) -> Option<Result<T, Error<'a, u8, T>>> {
+        if index < self.len {
+            Some(read_checked(index, &self.bytes[index * size_of::<T>()..(index + 1) * size_of::<T>()]))
+        } else {
+            None
+        }
+    }
+
+    /// An iterator reading and validating the values in turn, from the first
+    /// one.
+    pub fn iter(&self) -> impl Iterator<Item = Result<T, Error<'a, u8, T>>> + 'a {
+        let view = *self;
+        (0..self.len).filter_map(move |i| view.get(i))
+    }
+}
+
+
+/// Read the value at the start of the given bytes, reporting it as the one at
+/// `index` if it is invalid.
+fn read_checked<T: CheckedTransmutable>(index: usize, bytes: &[u8]) -> Result<T, Error<'_, u8, T>> {
+    // There are enough bytes for a `Bits`, which has the same layout as `T`
+    let bits = unsafe { ptr::read_unaligned(bytes.as_ptr() as *const T::Bits) };
+    if T::is_valid_bits(&bits) {
+        // The value has been checked
+        Ok(unsafe { ptr::read(&bits as *const T::Bits as *const T) })
+    } else {
+        Err(Error::InvalidValueAt {
+            index,
+            bytes,
+        })
+    }
+}
@@ -0,0 +1,162 @@
+//! Interoperability with other transmutation crates.
+//!
+//! Types whose safety was already proven to another crate can be used with
+//! the APIs of this one through the adapters in this module, without
+//! duplicating the unsafe implementations. A blanket implementation of
+//! [`TriviallyTransmutable`](../trivial/trait.TriviallyTransmutable.html)
+//! would conflict with the ones for primitive types, so the adapters wrap
+//! the values instead. They are `repr(transparent)`, so slices thereof can be
+//! freely converted to slices of the wrapped type, including through
+//! [`TransparentWrapper`](../transparent/trait.TransparentWrapper.html).
+//!
+//! - [`Zerocopy`](struct.Zerocopy.html) adapts types implementing both
+//!   `zerocopy::FromBytes` and `zerocopy::AsBytes`, with the `zerocopy` feature.
+//! - [`Bytemuck`](struct.Bytemuck.html) adapts `bytemuck::Pod` types, with
+//!   the `bytemuck` feature. Errors of this crate can also be converted into
+//!   `bytemuck`'s `PodCastError` and `CheckedCastError`. The opposite is not
+//!   possible, as `bytemuck`'s errors do not hold the information required.
+
+
+use self::super::transparent::TransparentWrapper;
+use self::super::trivial::TriviallyTransmutable;
+#[cfg(feature = "bytemuck")]
+use bytemuck::checked::CheckedCastError;
+#[cfg(feature = "bytemuck")]
+use self::super::error::{UnalignedError, ErrorReason, GuardError};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{PodCastError, Pod};
+#[cfg(feature = "zerocopy")]
+use zerocopy::{FromBytes, AsBytes};
+#[cfg(feature = "bytemuck")]
+use self::super::Error;
+#[cfg(all(feature = "bytemuck", feature = "alloc"))]
+use core::mem::align_of;
+use core::slice;
+
+
+macro_rules! adapter_slices {
+    ($name:ident) => {
+        impl<T> $name<T> {
+            /// View a slice of adapted values as a slice of the wrapped type.
+            pub fn peel_slice(values: &[$name<T>]) -> &[T] {
+                unsafe { slice::from_raw_parts(values.as_ptr() as *const T, values.len()) }
+            }
+
+            /// View a mutable slice of adapted values as a mutable slice of the wrapped type.
+            pub fn peel_slice_mut(values: &mut [$name<T>]) -> &mut [T] {
+                unsafe { slice::from_raw_parts_mut(values.as_mut_ptr() as *mut T, values.len()) }
+            }
+
+            /// View a slice of values as a slice of adapted values.
+            pub fn wrap_slice(values: &[T]) -> &[$name<T>] {
+                unsafe { slice::from_raw_parts(values.as_ptr() as *const $name<T>, values.len()) }
+            }
+        }
+
+        unsafe impl<T> TransparentWrapper<T> for $name<T> {}
+    };
+}
+
+
+/// Adapter making a type implementing `zerocopy::FromBytes` and
+/// `zerocopy::AsBytes` trivially transmutable.
+///
+/// Requires the `zerocopy` feature.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate safe_transmute;
+/// # use safe_transmute::compat::Zerocopy;
+/// # use safe_transmute::{SingleManyGuard, transmute_many};
+/// # fn main() {
+/// let data = [0x01, 0x00, 0x02, 0x00];
+/// # if let Ok(pairs) = transmute_many::<Zerocopy<[u8; 2]>, SingleManyGuard>(&data) {
+/// # /*
+/// let pairs = transmute_many::<Zerocopy<[u8; 2]>, SingleManyGuard>(&data)?;
+/// # */
+///
+/// assert_eq!(Zerocopy::peel_slice(pairs), &[[0x01, 0x00], [0x02, 0x00]]);
+/// # }
+/// # }
+/// ```
+#[cfg(feature = "zerocopy")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Zerocopy<T>(pub T);
+
+#[cfg(feature = "zerocopy")]
+unsafe impl<T: FromBytes + AsBytes + Copy> TriviallyTransmutable for Zerocopy<T> {}
+
+#[cfg(feature = "zerocopy")]
+adapter_slices!(Zerocopy);
+
+
+/// Adapter making a `bytemuck::Pod` type trivially transmutable.
+///
+/// Requires the `bytemuck` feature.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate safe_transmute;
+/// # use safe_transmute::compat::Bytemuck;
+/// # use safe_transmute::{SingleManyGuard, transmute_many};
+/// # fn main() {
+/// let data = [0x01, 0x00, 0x02, 0x00];
+/// # if let Ok(pairs) = transmute_many::<Bytemuck<[u8; 2]>, SingleManyGuard>(&data) {
+/// # /*
+/// let pairs = transmute_many::<Bytemuck<[u8; 2]>, SingleManyGuard>(&data)?;
+/// # */
+///
+/// assert_eq!(Bytemuck::peel_slice(pairs), &[[0x01, 0x00], [0x02, 0x00]]);
+/// # }
+/// # }
+/// ```
+#[cfg(feature = "bytemuck")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Bytemuck<T>(pub T);
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: Pod> TriviallyTransmutable for Bytemuck<T> {}
+
+#[cfg(feature = "bytemuck")]
+adapter_slices!(Bytemuck);
+
+/// A `GuardError` is a size mismatch; an inexact byte count in a slice
+/// transmutation is reported as leftover bytes.
+#[cfg(feature = "bytemuck")]
+impl From<GuardError> for PodCastError {
+    fn from(err: GuardError) -> PodCastError {
+        match err.reason {
+            ErrorReason::InexactByteCount if err.actual > err.required => PodCastError::OutputSliceWouldHaveSlop,
+            _ => PodCastError::SizeMismatch,
+        }
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<'a, S, T> From<UnalignedError<'a, S, T>> for PodCastError {
+    fn from(_: UnalignedError<'a, S, T>) -> PodCastError {
+        PodCastError::TargetAlignmentGreaterAndInputNotAligned
+    }
+}
+
+/// An invalid value is reported as an invalid bit pattern.
+#[cfg(feature = "bytemuck")]
+impl<'a, S, T> From<Error<'a, S, T>> for CheckedCastError {
+    fn from(err: Error<'a, S, T>) -> CheckedCastError {
+        match err {
+            Error::Guard(e) => CheckedCastError::PodCastError(e.into()),
+            Error::Unaligned(e) => CheckedCastError::PodCastError(e.into()),
+            #[cfg(feature = "alloc")]
+            Error::IncompatibleVecTarget(_) if align_of::<S>() != align_of::<T>() => CheckedCastError::PodCastError(PodCastError::AlignmentMismatch),
+            #[cfg(feature = "alloc")]
+            Error::IncompatibleVecTarget(_) => CheckedCastError::PodCastError(PodCastError::SizeMismatch),
+            Error::InvalidStride { .. } => CheckedCastError::PodCastError(PodCastError::SizeMismatch),
+            Error::InvalidValue |
+            Error::InvalidValueAt { .. } => CheckedCastError::InvalidBitPattern,
+        }
+    }
+}
@@ -0,0 +1,387 @@
+//! Byte order handling.
+//!
+//! The [`transmute_many_le()`](fn.transmute_many_le.html) and
+//! [`transmute_many_be()`](fn.transmute_many_be.html) functions copy
+//! primitives stored in a given byte order into a vector, converting them to
+//! the byte order of the host. Their generic counterparts, like
+//! [`transmute_many_endian()`](fn.transmute_many_endian.html), take the byte
+//! order as a [`ByteOrder`](trait.ByteOrder.html) type parameter instead, so
+//! that the conversion compiles away entirely when it matches the host's.
+//!
+//! The types in this module hold the raw representation of a primitive in a
+//! specific byte order, regardless of the endianness of the host, and convert
+//! it to and from a native value with `get()` and `set()`. As any combination
+//! of bytes is valid for them, they are
+//! [`TriviallyTransmutable`](../trivial/trait.TriviallyTransmutable.html), and
+//! make cross-endian data formats expressible directly in `repr(C)` structs.
+//!
+//! They are backed by byte arrays, so have an alignment of 1, and can be
+//! transmuted to from any byte slice.
+//!
+//! # Examples
+//!
+//! ```
+//! # use safe_transmute::endian::{U32Be, U16Be};
+//! # use safe_transmute::{TriviallyTransmutable, SingleManyGuard, transmute_many};
+//! #[derive(Clone, Copy)]
+//! #[repr(C)]
+//! struct Record {
+//!     id: U16Be,
+//!     flags: U16Be,
+//!     offset: U32Be,
+//! }
+//! unsafe impl TriviallyTransmutable for Record {}
+//!
+//! # fn main() {
+//! let data = [0x00, 0x2A, 0x00, 0x01, 0x00, 0x00, 0x10, 0x00];
+//! let records = transmute_many::<Record, SingleManyGuard>(&data).unwrap();
+//!
+//! assert_eq!(records[0].id.get(), 42);
+//! assert_eq!(records[0].flags.get(), 1);
+//! assert_eq!(records[0].offset.get(), 0x1000);
+//! # }
+//! ```
+
+
+use self::super::full::transmute_one_unaligned;
+use self::super::trivial::TriviallyTransmutable;
+#[cfg(feature = "alloc")]
+use self::super::full::transmute_to_vec;
+#[cfg(feature = "alloc")]
+use self::super::guard::Guard;
+use core::hash::{Hasher, Hash};
+use self::super::Error;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+
+
+/// Type whose byte order can be reversed.
+///
+/// This is implemented for the primitive integer and floating-point types, as
+/// well as for the fixed byte order primitives in this module, for which it
+/// reinterprets the stored value in the opposite byte order.
+pub trait SwapBytes: TriviallyTransmutable {
+    /// Reverse the byte order of this value.
+    fn swap_bytes(self) -> Self;
+}
+
+macro_rules! swap_bytes_integer {
+    ($($native:ident)*) => {
+        $(
+            impl SwapBytes for $native {
+                fn swap_bytes(self) -> $native {
+                    $native::swap_bytes(self)
+                }
+            }
+        )*
+    };
+}
+
+swap_bytes_integer!(u8 i8 u16 i16 u32 i32 u64 i64 usize isize);
+
+impl SwapBytes for f32 {
+    fn swap_bytes(self) -> f32 {
+        f32::from_bits(self.to_bits().swap_bytes())
+    }
+}
+
+impl SwapBytes for f64 {
+    fn swap_bytes(self) -> f64 {
+        f64::from_bits(self.to_bits().swap_bytes())
+    }
+}
+
+
+/// A byte order, chosen at compile time.
+pub trait ByteOrder {
+    /// Whether this is the byte order of the target platform.
+    const NATIVE: bool;
+}
+
+/// The little-endian byte order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LittleEndian;
+
+/// The big-endian byte order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BigEndian;
+
+/// The byte order of the target platform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NativeEndian;
+
+impl ByteOrder for LittleEndian {
+    const NATIVE: bool = cfg!(target_endian = "little");
+}
+
+impl ByteOrder for BigEndian {
+    const NATIVE: bool = cfg!(target_endian = "big");
+}
+
+impl ByteOrder for NativeEndian {
+    const NATIVE: bool = true;
+}
+
+
+/// Reverse the byte order of every value in the given slice.
+///
+/// This converts data obtained through, e.g.,
+/// [`transmute_many_mut()`](../fn.transmute_many_mut.html) between its wire
+/// byte order and the native one without allocating a second buffer.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::endian::swap_bytes_in_place;
+/// let mut words = [0x0102u16, 0x0304];
+/// swap_bytes_in_place(&mut words);
+///
+/// assert_eq!(words, [0x0201, 0x0403]);
+/// ```
+pub fn swap_bytes_in_place<T: SwapBytes>(values: &mut [T]) {
+    for value in values {
+        *value = value.swap_bytes();
+    }
+}
+
+/// Convert every value in the given slice from the byte order `E` to the
+/// native one, or vice versa.
+///
+/// This does nothing if `E` is the native byte order.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::endian::{BigEndian, swap_bytes_endian};
+/// let mut words = [u16::from_be(0x0102)];
+/// swap_bytes_endian::<_, BigEndian>(&mut words);
+///
+/// assert_eq!(words, [0x0102]);
+/// ```
+pub fn swap_bytes_endian<T: SwapBytes, E: ByteOrder>(values: &mut [T]) {
+    if !E::NATIVE {
+        swap_bytes_in_place(values);
+    }
+}
+
+/// Copy a single value stored in the byte order `E` out of a byte slice,
+/// converting it to the native byte order.
+///
+/// The byte slice is not required to be aligned for `T`.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// [single value guard](../guard/struct.SingleValueGuard.html).
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::endian::{BigEndian, transmute_one_endian};
+/// # use safe_transmute::Error;
+/// # fn run() -> Result<(), Error<'static, u8, u32>> {
+/// let packet: &[u8] = &[0xFF, 0x00, 0x00, 0x01, 0x02];
+/// assert_eq!(transmute_one_endian::<u32, BigEndian>(&packet[1..])?, 0x0102);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+pub fn transmute_one_endian<T: SwapBytes, E: ByteOrder>(bytes: &[u8]) -> Result<T, Error<'_, u8, T>> {
+    let value = transmute_one_unaligned::<T>(bytes)?;
+    Ok(if E::NATIVE { value } else { value.swap_bytes() })
+}
+
+/// Copy a byte slice of values stored in the byte order `E` into a new vector
+/// of native values.
+///
+/// The required byte length of the slice depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::endian::{LittleEndian, transmute_many_endian};
+/// # use safe_transmute::{SingleManyGuard, Error};
+/// # fn run() -> Result<(), Error<'static, u8, u16>> {
+/// assert_eq!(transmute_many_endian::<u16, LittleEndian, SingleManyGuard>(&[0x01, 0x00, 0x02, 0x01])?,
+///            vec![0x0001, 0x0102]);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_many_endian<T: SwapBytes, E: ByteOrder, G: Guard>(bytes: &[u8]) -> Result<Vec<T>, Error<'_, u8, T>> {
+    let mut values = transmute_to_vec::<T, G>(bytes)?;
+    swap_bytes_endian::<T, E>(&mut values);
+    Ok(values)
+}
+
+/// Copy a byte slice of little-endian values into a new vector of native values.
+///
+/// The required byte length of the slice depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::endian::transmute_many_le;
+/// # use safe_transmute::{SingleManyGuard, Error};
+/// # fn run() -> Result<(), Error<'static, u8, u16>> {
+/// assert_eq!(transmute_many_le::<u16, SingleManyGuard>(&[0x01, 0x00, 0x02, 0x01])?,
+///            vec![0x0001, 0x0102]);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_many_le<T: SwapBytes, G: Guard>(bytes: &[u8]) -> Result<Vec<T>, Error<'_, u8, T>> {
+    transmute_many_endian::<T, LittleEndian, G>(bytes)
+}
+
+/// Copy a byte slice of big-endian values into a new vector of native values.
+///
+/// The required byte length of the slice depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::endian::transmute_many_be;
+/// # use safe_transmute::{SingleManyGuard, Error};
+/// # fn run() -> Result<(), Error<'static, u8, u16>> {
+/// assert_eq!(transmute_many_be::<u16, SingleManyGuard>(&[0x00, 0x01, 0x01, 0x02])?,
+///            vec![0x0001, 0x0102]);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_many_be<T: SwapBytes, G: Guard>(bytes: &[u8]) -> Result<Vec<T>, Error<'_, u8, T>> {
+    transmute_many_endian::<T, BigEndian, G>(bytes)
+}
+
+
+macro_rules! endian_primitive {
+    ($name:ident, $native:ident, $size:expr, $to_bytes:ident, $from_bytes:ident, $order:expr) => {
+        #[doc = concat!("A `", stringify!($native), "` stored in ", $order, " byte order.")]
+        #[derive(Clone, Copy, Default)]
+        #[repr(transparent)]
+        pub struct $name([u8; $size]);
+
+        impl $name {
+            #[doc = concat!("Store the given value in ", $order, " byte order.")]
+            pub const fn new(value: $native) -> $name {
+                $name(value.$to_bytes())
+            }
+
+            /// Read out the value in native byte order.
+            pub const fn get(self) -> $native {
+                $native::$from_bytes(self.0)
+            }
+
+            /// Replace the stored value.
+            pub fn set(&mut self, value: $native) {
+                self.0 = value.$to_bytes();
+            }
+
+            /// The raw stored bytes.
+            pub const fn to_bytes(self) -> [u8; $size] {
+                self.0
+            }
+
+            /// Wrap the given raw bytes.
+            pub const fn from_bytes(bytes: [u8; $size]) -> $name {
+                $name(bytes)
+            }
+        }
+
+        unsafe impl TriviallyTransmutable for $name {}
+
+        impl SwapBytes for $name {
+            fn swap_bytes(mut self) -> $name {
+                self.0.reverse();
+                self
+            }
+        }
+
+        impl From<$native> for $name {
+            fn from(value: $native) -> $name {
+                $name::new(value)
+            }
+        }
+
+        impl From<$name> for $native {
+            fn from(value: $name) -> $native {
+                value.get()
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &$name) -> bool {
+                self.get() == other.get()
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Debug::fmt(&self.get(), f)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.get(), f)
+            }
+        }
+    };
+}
+
+macro_rules! endian_integer {
+    ($le:ident, $be:ident, $native:ident, $size:expr) => {
+        endian_primitive!($le, $native, $size, to_le_bytes, from_le_bytes, "little-endian");
+        endian_primitive!($be, $native, $size, to_be_bytes, from_be_bytes, "big-endian");
+
+        impl Eq for $le {}
+        impl Eq for $be {}
+
+        impl Hash for $le {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.get().hash(state)
+            }
+        }
+
+        impl Hash for $be {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.get().hash(state)
+            }
+        }
+    };
+}
+
+endian_integer!(U16Le, U16Be, u16, 2);
+endian_integer!(I16Le, I16Be, i16, 2);
+endian_integer!(U32Le, U32Be, u32, 4);
+endian_integer!(I32Le, I32Be, i32, 4);
+endian_integer!(U64Le, U64Be, u64, 8);
+endian_integer!(I64Le, I64Be, i64, 8);
+
+endian_primitive!(F32Le, f32, 4, to_le_bytes, from_le_bytes, "little-endian");
+endian_primitive!(F32Be, f32, 4, to_be_bytes, from_be_bytes, "big-endian");
+endian_primitive!(F64Le, f64, 8, to_le_bytes, from_le_bytes, "little-endian");
+endian_primitive!(F64Be, f64, 8, to_be_bytes, from_be_bytes, "big-endian");
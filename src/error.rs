@@ -1,18 +1,37 @@
 //! Detectable and recoverable-from transmutation precondition errors.
+//!
+//! With the `serde` feature, all error types implement `serde::Serialize`,
+//! including the names of the source and target types where known.
+//!
+//! With the `defmt` feature, all error types implement `defmt::Format`. To
+//! keep log frames small, these leave out the type names.
 
 
 use core::fmt;
 #[cfg(feature = "alloc")]
 use core::ptr;
 #[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::str::Utf8Error;
 use core::marker::PhantomData;
+use core::any::type_name;
 #[cfg(feature = "std")]
-use std::error::Error as StdError;
-#[cfg(feature = "alloc")]
-use core::mem::{align_of, size_of};
+use std::io;
+use core::error::Error as CoreError;
+#[cfg(feature = "serde")]
+use serde::{Serializer, Serialize};
+#[cfg(feature = "serde")]
+use serde::ser::SerializeStruct;
+#[cfg(feature = "defmt")]
+use defmt::{Formatter, Format};
+use core::mem::{size_of_val, align_of, size_of};
 #[cfg(feature = "alloc")]
 use self::super::trivial::TriviallyTransmutable;
+#[cfg(feature = "alloc")]
+use self::super::to_bytes::transmute_to_bytes;
 
 
 /// A transmutation error. This type describes possible errors originating
@@ -39,6 +58,29 @@ pub enum Error<'a, S, T> {
     IncompatibleVecTarget(IncompatibleVecTargetError<S, T>),
     /// The data contains an invalid value for the target type.
     InvalidValue,
+    /// The data contains an invalid value for the target type, the first of
+    /// which starts at the given index.
+    ///
+    /// The index is in target elements, or in bytes for text.
+    InvalidValueAt {
+        /// Where the first invalid value starts.
+        index: usize,
+        /// The bytes of the first invalid value.
+        ///
+        /// Empty if the error outlives the source data, as after
+        /// [`without_src()`](#method.without_src).
+        bytes: &'a [u8],
+    },
+    /// The stride between rows is zero, or shorter than a row.
+    ///
+    /// Returned by [`StridedView::new()`](../rows/struct.StridedView.html#method.new).
+    InvalidStride {
+        /// The requested number of bytes from the start of one row to the
+        /// start of the next.
+        stride: usize,
+        /// The number of bytes in a row, saturated at `usize::MAX`.
+        row_size: usize,
+    },
 }
 
 impl<'a, S, T> Error<'a, S, T> {
@@ -89,10 +131,121 @@ impl<'a, S, T> Error<'a, S, T> {
             }
             Error::Guard(e) => Error::Guard(e),
             Error::InvalidValue => Error::InvalidValue,
+            Error::InvalidValueAt { index, bytes: _ } => {
+                Error::InvalidValueAt {
+                    index,
+                    bytes: &[],
+                }
+            }
+            Error::InvalidStride { stride, row_size } => Error::InvalidStride { stride, row_size },
             #[cfg(feature = "alloc")]
             Error::IncompatibleVecTarget(e) => Error::IncompatibleVecTarget(e),
         }
     }
+
+    /// What kind of error this is.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::Guard(e) => ErrorKind::Guard(e.reason),
+            Error::Unaligned(_) => ErrorKind::Unaligned,
+            Error::InvalidValue |
+            Error::InvalidValueAt { .. } => ErrorKind::InvalidValue,
+            Error::InvalidStride { .. } => ErrorKind::InvalidStride,
+            #[cfg(feature = "alloc")]
+            Error::IncompatibleVecTarget(_) => ErrorKind::IncompatibleVecTarget,
+        }
+    }
+
+    /// The amount of bytes required by a guard, if this is a guard error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::{ErrorReason, ErrorKind, transmute_one_unaligned};
+    /// let err = transmute_one_unaligned::<[u16; 4]>(&[0x00; 5]).unwrap_err();
+    ///
+    /// assert_eq!(err.kind(), ErrorKind::Guard(ErrorReason::NotEnoughBytes));
+    /// assert_eq!(err.required_bytes(), Some(8));
+    /// assert_eq!(err.actual_bytes(), Some(5));
+    /// assert_eq!(err.required_elements(), Some(1));
+    /// ```
+    pub fn required_bytes(&self) -> Option<usize> {
+        match self {
+            Error::Guard(e) => Some(e.required),
+            _ => None,
+        }
+    }
+
+    /// The amount of bytes in the source data, if known.
+    ///
+    /// This is unknown only for invalid values and strides.
+    pub fn actual_bytes(&self) -> Option<usize> {
+        match self {
+            Error::Guard(e) => Some(e.actual),
+            Error::Unaligned(e) => Some(size_of_val(e.source)),
+            Error::InvalidValue |
+            Error::InvalidValueAt { .. } |
+            Error::InvalidStride { .. } => None,
+            #[cfg(feature = "alloc")]
+            Error::IncompatibleVecTarget(e) => Some(size_of_val(&e.vec[..])),
+        }
+    }
+
+    /// The amount of `T` values required by a guard, if this is a guard error.
+    pub fn required_elements(&self) -> Option<usize> {
+        match self {
+            Error::Guard(e) => e.required.checked_div(size_of::<T>()),
+            _ => None,
+        }
+    }
+
+    /// A hint on how to recover from this error, if there is any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::{Suggestion, transmute_one_unaligned};
+    /// assert_eq!(transmute_one_unaligned::<[u16; 4]>(&[0x00; 5]).unwrap_err().suggestion(),
+    ///            Some(Suggestion::ProvideMoreBytes { missing: 3 }));
+    /// ```
+    pub fn suggestion(&self) -> Option<Suggestion> {
+        match self {
+            Error::Guard(e) => e.suggestion(),
+            Error::Unaligned(_) => Some(Suggestion::CopyToAlignedBuffer),
+            Error::InvalidValue |
+            Error::InvalidValueAt { .. } |
+            Error::InvalidStride { .. } => None,
+            #[cfg(feature = "alloc")]
+            Error::IncompatibleVecTarget(_) => Some(Suggestion::CopyToAlignedBuffer),
+        }
+    }
+
+    /// Convert this error into an owned error, without a lifetime or type
+    /// parameters, discarding the source data and vector, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::{TransmuteError, transmute_bool_pedantic};
+    /// fn first_flag(bytes: &[u8]) -> Result<bool, TransmuteError> {
+    ///     let flags = transmute_bool_pedantic(bytes).map_err(|e| e.into_owned())?;
+    ///     Ok(flags[0])
+    /// }
+    ///
+    /// assert_eq!(first_flag(&[0x01]), Ok(true));
+    /// assert_eq!(first_flag(&[0x05]), Err(TransmuteError::InvalidValue));
+    /// ```
+    pub fn into_owned(self) -> TransmuteError {
+        match self {
+            Error::Guard(e) => TransmuteError::Guard(e),
+            Error::Unaligned(e) => TransmuteError::Unaligned { offset: e.offset },
+            Error::InvalidValue => TransmuteError::InvalidValue,
+            Error::InvalidValueAt { index, .. } => TransmuteError::InvalidValueAt { index },
+            Error::InvalidStride { stride, row_size } => TransmuteError::InvalidStride { stride, row_size },
+            #[cfg(feature = "alloc")]
+            Error::IncompatibleVecTarget(_) => TransmuteError::IncompatibleVecTarget,
+        }
+    }
 }
 
 impl<'a, S, T> fmt::Debug for Error<'a, S, T> {
@@ -101,20 +254,24 @@ impl<'a, S, T> fmt::Debug for Error<'a, S, T> {
             Error::Guard(e) => write!(f, "Guard({:?})", e),
             Error::Unaligned(e) => write!(f, "Unaligned({:?})", e),
             Error::InvalidValue => f.write_str("InvalidValue"),
+            Error::InvalidValueAt { index, bytes } => write!(f, "InvalidValueAt {{ index: {}, bytes: {:?} }}", index, bytes),
+            Error::InvalidStride { stride, row_size } => write!(f, "InvalidStride {{ stride: {}, row_size: {} }}", stride, row_size),
             #[cfg(feature = "alloc")]
             Error::IncompatibleVecTarget(_) => f.write_str("IncompatibleVecTarget"),
         }
     }
 }
 
-#[cfg(feature = "std")]
 #[allow(deprecated)]
-impl<'a, S, T> StdError for Error<'a, S, T> {
+impl<'a, S, T> CoreError for Error<'a, S, T> {
     fn description(&self) -> &str {
         match self {
             Error::Guard(e) => e.description(),
             Error::Unaligned(e) => e.description(),
-            Error::InvalidValue => "invalid target value",
+            Error::InvalidValue |
+            Error::InvalidValueAt { .. } => "invalid target value",
+            Error::InvalidStride { .. } => "invalid stride",
+            #[cfg(feature = "alloc")]
             Error::IncompatibleVecTarget(e) => e.description(),
         }
     }
@@ -123,21 +280,72 @@ impl<'a, S, T> StdError for Error<'a, S, T> {
 impl<'a, S, T> fmt::Display for Error<'a, S, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Error::Guard(e) => e.fmt(f),
+            Error::Guard(e) => write!(f, "{} when transmuting into `{}`", e, type_name::<T>()),
             Error::Unaligned(e) => e.fmt(f),
-            Error::InvalidValue => f.write_str("Invalid target value"),
+            Error::InvalidValue => write!(f, "Invalid target value for `{}`", type_name::<T>()),
+            Error::InvalidValueAt { index, bytes } if bytes.is_empty() => write!(f, "Invalid target value for `{}` at index {}", type_name::<T>(), index),
+            Error::InvalidValueAt { index, bytes } => write!(f, "Invalid target value for `{}` at index {}: {:02X?}", type_name::<T>(), index, bytes),
+            Error::InvalidStride { stride, row_size } => write!(f, "Stride of {} bytes is zero or shorter than a row of {} bytes", stride, row_size),
             #[cfg(feature = "alloc")]
             Error::IncompatibleVecTarget(e) => e.fmt(f),
         }
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<'a, S, T> Format for Error<'a, S, T> {
+    fn format(&self, f: Formatter) {
+        match self {
+            Error::Guard(e) => e.format(f),
+            Error::Unaligned(e) => e.format(f),
+            Error::InvalidValue => defmt::write!(f, "Invalid target value"),
+            Error::InvalidValueAt { index, bytes } => defmt::write!(f, "Invalid target value at index {=usize}: {=[u8]:X}", index, bytes),
+            Error::InvalidStride { stride, row_size } => {
+                defmt::write!(f, "Stride of {=usize} bytes is zero or shorter than a row of {=usize} bytes", stride, row_size)
+            }
+            #[cfg(feature = "alloc")]
+            Error::IncompatibleVecTarget(e) => e.format(f),
+        }
+    }
+}
+
 impl<'a, S, T> From<GuardError> for Error<'a, S, T> {
     fn from(o: GuardError) -> Self {
         Error::Guard(o)
     }
 }
 
+impl<'a, S, T> From<Error<'a, S, T>> for TransmuteError {
+    fn from(err: Error<'a, S, T>) -> TransmuteError {
+        err.into_owned()
+    }
+}
+
+/// Guard errors are converted as for `GuardError`, and every other error is
+/// reported as invalid data.
+#[cfg(feature = "std")]
+impl<'a, S, T> From<Error<'a, S, T>> for io::Error {
+    fn from(err: Error<'a, S, T>) -> io::Error {
+        match err {
+            Error::Guard(e) => e.into(),
+            e => io::Error::new(io::ErrorKind::InvalidData, e.to_string()),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a, S, T> Serialize for Error<'a, S, T> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        let mut state = serializer.serialize_struct("Error", 5)?;
+        state.serialize_field("kind", &self.kind())?;
+        state.serialize_field("required_bytes", &self.required_bytes())?;
+        state.serialize_field("actual_bytes", &self.actual_bytes())?;
+        state.serialize_field("source_type", type_name::<S>())?;
+        state.serialize_field("target_type", type_name::<T>())?;
+        state.end()
+    }
+}
+
 impl<'a, S, T> From<UnalignedError<'a, S, T>> for Error<'a, S, T> {
     fn from(o: UnalignedError<'a, S, T>) -> Self {
         Error::Unaligned(o)
@@ -165,6 +373,7 @@ impl<'a, S, T> From<UnalignedError<'a, S, T>> for Error<'a, S, T> {
 /// # }
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct GuardError {
     /// The required amount of bytes for transmutation.
     pub required: usize,
@@ -177,19 +386,42 @@ pub struct GuardError {
 /// How the type's size compares to the received byte count and the
 /// transmutation function's characteristic.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "defmt", derive(Format))]
 pub enum ErrorReason {
     /// Too few bytes to fill even one instance of a type.
     NotEnoughBytes,
     /// Too many bytes to fill a type.
     ///
-    /// Currently unused.
+    /// Only used by guards with an upper bound, like
+    /// [`BoundedGuard`](../guard/struct.BoundedGuard.html).
     TooManyBytes,
     /// The byte amount received is not the same as the type's size.
     InexactByteCount,
+    /// The type is zero-sized, so any amount of bytes holds infinitely many
+    /// instances of it.
+    ///
+    /// Returned by all built-in guards, so by all guarded transmutation functions.
+    ZeroSizedTarget,
 }
 
-#[cfg(feature = "std")]
-impl StdError for GuardError {
+impl GuardError {
+    /// A hint on how to change the byte count to satisfy the guard, or `None`
+    /// for zero-sized targets, which no byte count can satisfy.
+    ///
+    /// Too many bytes, or an inexact amount of them, are to be truncated to
+    /// the `required` byte count. For guards accepting many values, that is
+    /// the size of a single value, so a larger multiple of it may also work.
+    pub fn suggestion(&self) -> Option<Suggestion> {
+        match self.reason {
+            ErrorReason::ZeroSizedTarget => None,
+            _ if self.actual < self.required => Some(Suggestion::ProvideMoreBytes { missing: self.required - self.actual }),
+            _ => Some(Suggestion::TruncateTo { bytes: self.required }),
+        }
+    }
+}
+
+impl CoreError for GuardError {
     fn description(&self) -> &str {
         self.reason.description()
     }
@@ -201,6 +433,26 @@ impl fmt::Display for GuardError {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl Format for GuardError {
+    fn format(&self, f: Formatter) {
+        defmt::write!(f, "{} (required: {=usize}, actual: {=usize})", self.reason, self.required, self.actual)
+    }
+}
+
+/// Running out of bytes is reported as an unexpected end of file, and any
+/// other size mismatch as invalid data.
+#[cfg(feature = "std")]
+impl From<GuardError> for io::Error {
+    fn from(err: GuardError) -> io::Error {
+        let kind = match err.reason {
+            ErrorReason::NotEnoughBytes => io::ErrorKind::UnexpectedEof,
+            _ => io::ErrorKind::InvalidData,
+        };
+        io::Error::new(kind, err.to_string())
+    }
+}
+
 impl ErrorReason {
     /// Retrieve a human readable description of the reason.
     pub fn description(self) -> &'static str {
@@ -208,6 +460,7 @@ impl ErrorReason {
             ErrorReason::NotEnoughBytes => "Not enough bytes to fill type",
             ErrorReason::TooManyBytes => "Too many bytes for type",
             ErrorReason::InexactByteCount => "Not exactly the amount of bytes for type",
+            ErrorReason::ZeroSizedTarget => "Cannot transmute into zero-sized type",
         }
     }
 }
@@ -219,8 +472,8 @@ impl ErrorReason {
 /// The byte data in the vector needs to correspond to a valid contiguous
 /// sequence of `T` values.
 #[cfg(feature = "alloc")]
-unsafe fn copy_to_vec_unchecked<S, T>(data: &[S]) -> Vec<T> {
-    let len = data.len() * size_of::<S>() / size_of::<T>();
+pub(crate) unsafe fn copy_to_vec_unchecked<S, T>(data: &[S]) -> Vec<T> {
+    let len = size_of_val(data).checked_div(size_of::<T>()).unwrap_or(0);
 
     let mut out = Vec::with_capacity(len);
     ptr::copy_nonoverlapping(data.as_ptr() as *const u8, out.as_mut_ptr() as *mut u8, len * size_of::<T>());
@@ -229,11 +482,183 @@ unsafe fn copy_to_vec_unchecked<S, T>(data: &[S]) -> Vec<T> {
     out
 }
 
+/// A machine-readable hint on how to recover from a transmutation error.
+///
+/// See [`Error::suggestion()`](enum.Error.html#method.suggestion).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "defmt", derive(Format))]
+pub enum Suggestion {
+    /// Copy the data into a buffer properly aligned for the target type, like
+    /// with [`Error::copy()`](enum.Error.html#method.copy).
+    CopyToAlignedBuffer,
+    /// Provide more bytes, at least `missing` of them.
+    ProvideMoreBytes {
+        /// The amount of bytes missing.
+        missing: usize,
+    },
+    /// Truncate the data to `bytes` bytes.
+    TruncateTo {
+        /// The amount of bytes to keep.
+        bytes: usize,
+    },
+}
+
+
+/// The kind of a transmutation error, without any of the data it holds.
+///
+/// See [`Error::kind()`](enum.Error.html#method.kind).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "defmt", derive(Format))]
+pub enum ErrorKind {
+    /// The data does not respect the target type's boundaries, for the given reason.
+    Guard(ErrorReason),
+    /// The given data slice is not properly aligned for the target type.
+    Unaligned,
+    /// The data vector's element type does not have the same size and minimum
+    /// alignment as the target type.
+    ///
+    /// Does not exist without the `alloc` feature.
+    #[cfg(feature = "alloc")]
+    IncompatibleVecTarget,
+    /// The data contains an invalid value for the target type.
+    InvalidValue,
+    /// The stride between rows is zero, or shorter than a row.
+    InvalidStride,
+}
+
+
+/// An owned transmutation error, without the lifetime and type parameters of
+/// [`Error`](enum.Error.html).
+///
+/// It can be propagated through generic error handling, like
+/// `Box<dyn core::error::Error + Send + Sync>`, where `Error` itself can not.
+/// See [`Error::into_owned()`](enum.Error.html#method.into_owned).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "defmt", derive(Format))]
+pub enum TransmuteError {
+    /// The data does not respect the target type's boundaries.
+    Guard(GuardError),
+    /// The given data slice is not properly aligned for the target type.
+    Unaligned {
+        /// The required amount of bytes to discard at the front for the
+        /// attempted transmutation to be successful.
+        offset: usize,
+    },
+    /// The data vector's element type does not have the same size and minimum
+    /// alignment as the target type.
+    ///
+    /// Does not exist without the `alloc` feature.
+    #[cfg(feature = "alloc")]
+    IncompatibleVecTarget,
+    /// The data contains an invalid value for the target type.
+    InvalidValue,
+    /// The data contains an invalid value for the target type, the first of
+    /// which starts at the given index.
+    InvalidValueAt {
+        /// Where the first invalid value starts.
+        index: usize,
+    },
+    /// The stride between rows is zero, or shorter than a row.
+    InvalidStride {
+        /// The requested number of bytes from the start of one row to the
+        /// start of the next.
+        stride: usize,
+        /// The number of bytes in a row, saturated at `usize::MAX`.
+        row_size: usize,
+    },
+}
+
+impl TransmuteError {
+    /// What kind of error this is.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            TransmuteError::Guard(e) => ErrorKind::Guard(e.reason),
+            TransmuteError::Unaligned { .. } => ErrorKind::Unaligned,
+            TransmuteError::InvalidValue |
+            TransmuteError::InvalidValueAt { .. } => ErrorKind::InvalidValue,
+            TransmuteError::InvalidStride { .. } => ErrorKind::InvalidStride,
+            #[cfg(feature = "alloc")]
+            TransmuteError::IncompatibleVecTarget => ErrorKind::IncompatibleVecTarget,
+        }
+    }
+
+    /// The amount of bytes required by a guard, if this is a guard error.
+    pub fn required_bytes(&self) -> Option<usize> {
+        match self {
+            TransmuteError::Guard(e) => Some(e.required),
+            _ => None,
+        }
+    }
+
+    /// The amount of bytes in the source data, if this is a guard error.
+    pub fn actual_bytes(&self) -> Option<usize> {
+        match self {
+            TransmuteError::Guard(e) => Some(e.actual),
+            _ => None,
+        }
+    }
+
+    /// A hint on how to recover from this error, if there is any.
+    pub fn suggestion(&self) -> Option<Suggestion> {
+        match self {
+            TransmuteError::Guard(e) => e.suggestion(),
+            TransmuteError::Unaligned { .. } => Some(Suggestion::CopyToAlignedBuffer),
+            TransmuteError::InvalidValue |
+            TransmuteError::InvalidValueAt { .. } |
+            TransmuteError::InvalidStride { .. } => None,
+            #[cfg(feature = "alloc")]
+            TransmuteError::IncompatibleVecTarget => Some(Suggestion::CopyToAlignedBuffer),
+        }
+    }
+}
+
+impl From<GuardError> for TransmuteError {
+    fn from(o: GuardError) -> Self {
+        TransmuteError::Guard(o)
+    }
+}
+
+#[allow(deprecated)]
+impl CoreError for TransmuteError {
+    fn description(&self) -> &str {
+        match self {
+            TransmuteError::Guard(e) => e.description(),
+            TransmuteError::Unaligned { .. } => "data is unaligned",
+            TransmuteError::InvalidValue |
+            TransmuteError::InvalidValueAt { .. } => "invalid target value",
+            TransmuteError::InvalidStride { .. } => "invalid stride",
+            #[cfg(feature = "alloc")]
+            TransmuteError::IncompatibleVecTarget => "incompatible target type",
+        }
+    }
+}
+
+impl fmt::Display for TransmuteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TransmuteError::Guard(e) => e.fmt(f),
+            TransmuteError::Unaligned { offset } => write!(f, "data is unaligned (off by {} bytes)", offset),
+            TransmuteError::InvalidValue => f.write_str("Invalid target value"),
+            TransmuteError::InvalidValueAt { index } => write!(f, "Invalid target value at index {}", index),
+            TransmuteError::InvalidStride { stride, row_size } => write!(f, "Stride of {} bytes is zero or shorter than a row of {} bytes", stride, row_size),
+            #[cfg(feature = "alloc")]
+            TransmuteError::IncompatibleVecTarget => f.write_str("incompatible target type"),
+        }
+    }
+}
+
 /// Unaligned memory access error.
 ///
 /// Returned when the given data slice is not properly aligned for the target
 /// type. It would have been properly aligned if `offset` bytes were shifted
 /// (discarded) from the front of the slice.
+///
+/// Use [`realigned()`](#method.realigned) to get at the aligned part of the
+/// source data, or [`copy()`](#method.copy) to get all of it in an aligned
+/// vector.
 #[derive(Clone, Eq, Hash, PartialEq)]
 pub struct UnalignedError<'a, S, T> {
     /// The required amount of bytes to discard at the front for the attempted
@@ -254,6 +679,51 @@ impl<'a, S, T> UnalignedError<'a, S, T> {
         }
     }
 
+    /// The alignment required by the target type, in bytes.
+    pub fn alignment(&self) -> usize {
+        align_of::<T>()
+    }
+
+    /// How many bytes past the previous aligned address the source data
+    /// starts at.
+    ///
+    /// Together with `offset`, this adds up to a multiple of the alignment of
+    /// `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::{transmute_to_bytes, transmute_many_permissive, Error};
+    /// let words: &[u32] = &[0x0100_0000, 0x0302_0100, 0x0706_0504];
+    /// let bytes = &transmute_to_bytes(words)[1..];
+    ///
+    /// match transmute_many_permissive::<u32>(bytes) {
+    ///     Err(Error::Unaligned(e)) => {
+    ///         assert_eq!(e.alignment(), 4);
+    ///         assert_eq!(e.misalignment(), 1);
+    ///         assert_eq!(e.offset, 3);
+    ///         assert_eq!(e.realigned(), Some(&bytes[3..]));
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn misalignment(&self) -> usize {
+        (align_of::<T>() - self.offset % align_of::<T>()) % align_of::<T>()
+    }
+
+    /// The source data, with the `offset` bytes at the front discarded, or
+    /// `None` if they do not make up a whole number of `S` values, or the
+    /// source data is not that long.
+    ///
+    /// The returned slice is properly aligned for `T`.
+    pub fn realigned(&self) -> Option<&'a [S]> {
+        let elements = self.offset.checked_div(size_of::<S>())?;
+        if elements * size_of::<S>() != self.offset {
+            return None;
+        }
+        self.source.get(elements..)
+    }
+
     /// Create a copy of the source data, transmuted into a vector. As the
     /// vector will be properly aligned for accessing values of type `T`, this
     /// operation will not fail due to memory alignment constraints.
@@ -300,13 +770,13 @@ impl<'a, S, T> fmt::Debug for UnalignedError<'a, S, T> {
 
         f.debug_struct("UnalignedError")
             .field("offset", &self.offset)
+            .field("align_of<T>", &align_of::<T>())
             .field("source", &Source { len: self.source.len() })
             .finish()
     }
 }
 
-#[cfg(feature = "std")]
-impl<'a, S, T> StdError for UnalignedError<'a, S, T> {
+impl<'a, S, T> CoreError for UnalignedError<'a, S, T> {
     fn description(&self) -> &str {
         "data is unaligned"
     }
@@ -314,7 +784,37 @@ impl<'a, S, T> StdError for UnalignedError<'a, S, T> {
 
 impl<'a, S, T> fmt::Display for UnalignedError<'a, S, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "data is unaligned (off by {} bytes)", self.offset)
+        write!(f,
+               "data is unaligned for `{}` ({} bytes past a {}-byte boundary, skip {} bytes)",
+               type_name::<T>(),
+               self.misalignment(),
+               align_of::<T>(),
+               self.offset)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<'a, S, T> Format for UnalignedError<'a, S, T> {
+    fn format(&self, f: Formatter) {
+        defmt::write!(f,
+                      "data is unaligned ({=usize} bytes past a {=usize}-byte boundary, skip {=usize} bytes)",
+                      self.misalignment(),
+                      align_of::<T>(),
+                      self.offset)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a, S, T> Serialize for UnalignedError<'a, S, T> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        let mut state = serializer.serialize_struct("UnalignedError", 6)?;
+        state.serialize_field("offset", &self.offset)?;
+        state.serialize_field("alignment", &self.alignment())?;
+        state.serialize_field("misalignment", &self.misalignment())?;
+        state.serialize_field("source_bytes", &size_of_val(self.source))?;
+        state.serialize_field("source_type", type_name::<S>())?;
+        state.serialize_field("target_type", type_name::<T>())?;
+        state.end()
     }
 }
 
@@ -361,6 +861,24 @@ impl<S, T> IncompatibleVecTargetError<S, T> {
     /// Create a copy of the data, transmuted into a new vector. As `T` is
     /// trivially transmutable, and the new vector will be properly allocated
     /// for accessing values of type `T`, this operation is safe and will never fail.
+    ///
+    /// Trailing bytes which do not make up a whole `T` are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::{Error, transmute_vec};
+    /// let target: Vec<u16> = match transmute_vec::<u8, u16>(vec![0x00, 0x01, 0x00, 0x02]) {
+    ///     Ok(target) => target,
+    ///     Err(Error::IncompatibleVecTarget(e)) => e.copy(),
+    ///     Err(_) => unreachable!(),
+    /// };
+    ///
+    /// # /*
+    /// assert_eq!(target, vec![0x0100, 0x0200]);  // Little-endian
+    /// # */
+    /// # assert_eq!(target, vec![u16::from_le(0x0100), u16::from_le(0x0200)]);
+    /// ```
     pub fn copy(&self) -> Vec<T>
         where T: TriviallyTransmutable
     {
@@ -369,6 +887,18 @@ impl<S, T> IncompatibleVecTargetError<S, T> {
             self.copy_unchecked()
         }
     }
+
+    /// Copy the bytes of the data into a boxed byte slice, consuming the
+    /// original vector.
+    ///
+    /// The result can be viewed as a slice of `T`s with
+    /// [`OwnedTransmuted::from_boxed()`](owned/struct.OwnedTransmuted.html#method.from_boxed),
+    /// or kept around as plain bytes.
+    pub fn into_boxed_bytes(self) -> Box<[u8]>
+        where S: TriviallyTransmutable
+    {
+        transmute_to_bytes(&self.vec).into()
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -390,8 +920,8 @@ impl<S, T> fmt::Debug for IncompatibleVecTargetError<S, T> {
     }
 }
 
-#[cfg(feature = "std")]
-impl<S, T> StdError for IncompatibleVecTargetError<S, T> {
+#[cfg(feature = "alloc")]
+impl<S, T> CoreError for IncompatibleVecTargetError<S, T> {
     fn description(&self) -> &str {
         "incompatible target type"
     }
@@ -401,10 +931,227 @@ impl<S, T> StdError for IncompatibleVecTargetError<S, T> {
 impl<S, T> fmt::Display for IncompatibleVecTargetError<S, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f,
-               "incompatible target type (size: {}, align: {}) for transmutation from source (size: {}, align: {})",
+               "incompatible target type `{}` (size: {}, align: {}) for transmutation from source `{}` (size: {}, align: {})",
+               type_name::<T>(),
                size_of::<T>(),
                align_of::<T>(),
+               type_name::<S>(),
                size_of::<S>(),
                align_of::<S>())
     }
 }
+
+#[cfg(all(feature = "alloc", feature = "defmt"))]
+impl<S, T> Format for IncompatibleVecTargetError<S, T> {
+    fn format(&self, f: Formatter) {
+        defmt::write!(f,
+                      "incompatible target type (size: {=usize}, align: {=usize}) for source (size: {=usize}, align: {=usize})",
+                      size_of::<T>(),
+                      align_of::<T>(),
+                      size_of::<S>(),
+                      align_of::<S>())
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "serde"))]
+impl<S, T> Serialize for IncompatibleVecTargetError<S, T> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        let mut state = serializer.serialize_struct("IncompatibleVecTargetError", 7)?;
+        state.serialize_field("len", &self.vec.len())?;
+        state.serialize_field("source_type", type_name::<S>())?;
+        state.serialize_field("source_size", &size_of::<S>())?;
+        state.serialize_field("source_align", &align_of::<S>())?;
+        state.serialize_field("target_type", type_name::<T>())?;
+        state.serialize_field("target_size", &size_of::<T>())?;
+        state.serialize_field("target_align", &align_of::<T>())?;
+        state.end()
+    }
+}
+
+
+/// Invalid UTF-8 in a byte vector.
+///
+/// Returned by
+/// [`transmute_vec_to_string()`](../text/fn.transmute_vec_to_string.html),
+/// giving back the vector it was called with.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{Error, transmute_vec_to_string};
+/// let err = transmute_vec_to_string(vec![0x2E, 0xC3, 0x28]).unwrap_err();
+/// assert_eq!(err.error(), Error::InvalidValueAt { index: 1, bytes: &[0xC3] });
+/// assert_eq!(err.vec, vec![0x2E, 0xC3, 0x28]);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidUtf8Error {
+    /// The original vector.
+    pub vec: Vec<u8>,
+    error: Utf8Error,
+}
+
+#[cfg(feature = "alloc")]
+impl InvalidUtf8Error {
+    /// Create an error with the given vector, and the error validating it.
+    pub fn new(vec: Vec<u8>, error: Utf8Error) -> Self {
+        InvalidUtf8Error {
+            vec,
+            error,
+        }
+    }
+
+    /// The byte index of the first invalid sequence.
+    pub fn index(&self) -> usize {
+        self.error.valid_up_to()
+    }
+
+    /// The bytes of the first invalid sequence, up to the end of the vector if
+    /// it ends in the middle of one.
+    pub fn invalid_bytes(&self) -> &[u8] {
+        let index = self.index();
+        &self.vec[index..index + self.error.error_len().unwrap_or(self.vec.len() - index)]
+    }
+
+    /// The error, as it would be reported for a byte slice by
+    /// [`transmute_bytes_to_str()`](../text/fn.transmute_bytes_to_str.html).
+    pub fn error(&self) -> Error<'_, u8, u8> {
+        Error::InvalidValueAt {
+            index: self.index(),
+            bytes: self.invalid_bytes(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl CoreError for InvalidUtf8Error {
+    fn source(&self) -> Option<&(dyn CoreError + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for InvalidUtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.error().fmt(f)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "defmt"))]
+impl Format for InvalidUtf8Error {
+    fn format(&self, f: Formatter) {
+        self.error().format(f)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "serde"))]
+impl Serialize for InvalidUtf8Error {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        let mut state = serializer.serialize_struct("InvalidUtf8Error", 3)?;
+        state.serialize_field("len", &self.vec.len())?;
+        state.serialize_field("index", &self.index())?;
+        state.serialize_field("invalid_bytes", self.invalid_bytes())?;
+        state.end()
+    }
+}
+
+/// Converted as an `InvalidData` error.
+#[cfg(feature = "std")]
+impl From<InvalidUtf8Error> for io::Error {
+    fn from(err: InvalidUtf8Error) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, err.to_string())
+    }
+}
+
+
+/// An error reading a field of a sequence of values.
+///
+/// Returned by [`transmute_sequence!()`](../macro.transmute_sequence.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "defmt", derive(Format))]
+pub struct SequenceError {
+    /// The name of the field that could not be read.
+    pub field: &'static str,
+    /// The byte offset the field starts at.
+    pub offset: usize,
+    /// Why the field could not be read.
+    pub error: GuardError,
+}
+
+impl CoreError for SequenceError {
+    fn source(&self) -> Option<&(dyn CoreError + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl fmt::Display for SequenceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "field `{}` at offset {}: {}", self.field, self.offset, self.error)
+    }
+}
+
+/// Converted as the `GuardError` it wraps.
+#[cfg(feature = "std")]
+impl From<SequenceError> for io::Error {
+    fn from(err: SequenceError) -> io::Error {
+        io::Error::new(io::Error::from(err.error).kind(), err.to_string())
+    }
+}
+
+/// An error loading values out of a file.
+///
+/// Returned by [`load_typed()`](../fs/fn.load_typed.html).
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum LoadError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// The size of the file does not respect the target type's boundaries.
+    Guard(GuardError),
+}
+
+#[cfg(feature = "std")]
+impl CoreError for LoadError {
+    fn source(&self) -> Option<&(dyn CoreError + 'static)> {
+        match self {
+            LoadError::Io(e) => Some(e),
+            LoadError::Guard(e) => Some(e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoadError::Io(e) => e.fmt(f),
+            LoadError::Guard(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for LoadError {
+    fn from(e: io::Error) -> LoadError {
+        LoadError::Io(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<GuardError> for LoadError {
+    fn from(e: GuardError) -> LoadError {
+        LoadError::Guard(e)
+    }
+}
+
+/// Guard errors are converted as for `GuardError`.
+#[cfg(feature = "std")]
+impl From<LoadError> for io::Error {
+    fn from(err: LoadError) -> io::Error {
+        match err {
+            LoadError::Io(e) => e,
+            LoadError::Guard(e) => e.into(),
+        }
+    }
+}
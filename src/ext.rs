@@ -0,0 +1,157 @@
+//! Extension traits providing method syntax for the transmutation functions.
+//!
+//! These are most conveniently imported through the
+//! [`prelude`](../prelude/index.html).
+
+
+use self::super::full::{transmute_many_mut, transmute_many, transmute_one};
+use self::super::to_bytes::{transmute_to_bytes_mut, transmute_to_bytes};
+use self::super::trivial::TriviallyTransmutable;
+#[cfg(feature = "alloc")]
+use self::super::full::{transmute_vec_or_copy, transmute_vec};
+use self::super::guard::Guard;
+use self::super::Error;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+
+/// Method syntax for viewing byte slices as values.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::prelude::*;
+/// # use safe_transmute::transmute_to_bytes;
+/// # use safe_transmute::Error;
+/// # fn run() -> Result<(), Error<'static, u8, u32>> {
+/// let bytes = transmute_to_bytes::<u32>(&[0x1234, 0x5678]);
+///
+/// assert_eq!(bytes.transmute_view::<u32, PedanticGuard>()?, &[0x1234, 0x5678]);
+/// assert_eq!(bytes.transmute_one::<u32>()?, 0x1234);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+pub trait TransmuteView {
+    /// View these bytes as a slice of values, checked against the given
+    /// guard.
+    ///
+    /// Equivalent to [`transmute_many()`](../fn.transmute_many.html).
+    fn transmute_view<T: TriviallyTransmutable, G: Guard>(&self) -> Result<&[T], Error<'_, u8, T>>;
+
+    /// View these bytes as a mutable slice of values, checked against the
+    /// given guard.
+    ///
+    /// Equivalent to [`transmute_many_mut()`](../fn.transmute_many_mut.html).
+    fn transmute_view_mut<T: TriviallyTransmutable, G: Guard>(&mut self) -> Result<&mut [T], Error<'_, u8, T>>;
+
+    /// Copy a single value out of these bytes.
+    ///
+    /// Equivalent to [`transmute_one()`](../fn.transmute_one.html).
+    fn transmute_one<T: TriviallyTransmutable>(&self) -> Result<T, Error<'_, u8, T>>;
+}
+
+impl TransmuteView for [u8] {
+    #[inline]
+    fn transmute_view<T: TriviallyTransmutable, G: Guard>(&self) -> Result<&[T], Error<'_, u8, T>> {
+        transmute_many::<T, G>(self)
+    }
+
+    #[inline]
+    fn transmute_view_mut<T: TriviallyTransmutable, G: Guard>(&mut self) -> Result<&mut [T], Error<'_, u8, T>> {
+        transmute_many_mut::<T, G>(self)
+    }
+
+    #[inline]
+    fn transmute_one<T: TriviallyTransmutable>(&self) -> Result<T, Error<'_, u8, T>> {
+        transmute_one::<T>(self)
+    }
+}
+
+
+/// Method syntax for viewing slices of values as bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::prelude::*;
+/// let mut pixels = [0xFF00_00FFu32.to_be(), 0x00FF_00FFu32.to_be()];
+///
+/// assert_eq!(pixels.as_byte_slice(), &[0xFF, 0x00, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0xFF]);
+///
+/// pixels.as_mut_byte_slice()[3] = 0x80;
+/// assert_eq!(u32::from_be(pixels[0]), 0xFF00_0080);
+/// ```
+pub trait AsByteSliceExt {
+    /// View these values as bytes.
+    ///
+    /// Equivalent to [`transmute_to_bytes()`](../fn.transmute_to_bytes.html).
+    fn as_byte_slice(&self) -> &[u8];
+
+    /// View these values as mutable bytes.
+    ///
+    /// Equivalent to [`transmute_to_bytes_mut()`](../fn.transmute_to_bytes_mut.html).
+    fn as_mut_byte_slice(&mut self) -> &mut [u8];
+}
+
+impl<T: TriviallyTransmutable> AsByteSliceExt for [T] {
+    #[inline]
+    fn as_byte_slice(&self) -> &[u8] {
+        transmute_to_bytes(self)
+    }
+
+    #[inline]
+    fn as_mut_byte_slice(&mut self) -> &mut [u8] {
+        transmute_to_bytes_mut(self)
+    }
+}
+
+
+/// Method syntax for turning byte vectors into vectors of values.
+///
+/// Does not exist without the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::prelude::*;
+/// # use safe_transmute::Error;
+/// # fn run() -> Result<(), Error<'static, u8, u16>> {
+/// let bytes = vec![0x01, 0x01, 0x02, 0x02];
+///
+/// assert_eq!(bytes.transmute_into_vec_or_copy::<u16, PedanticGuard>()?, vec![0x0101, 0x0202]);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[cfg(feature = "alloc")]
+pub trait VecTransmuteExt {
+    /// Transform this vector into a vector of values, reusing its allocation.
+    ///
+    /// Equivalent to [`transmute_vec()`](../fn.transmute_vec.html), so only
+    /// works for single-byte targets.
+    fn transmute_into_vec<T: TriviallyTransmutable>(self) -> Result<Vec<T>, Error<'static, u8, T>>;
+
+    /// Transform this vector into a vector of values, checked against the
+    /// given guard, reusing its allocation if possible, and copying the values
+    /// into a new one otherwise.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the data does not comply with the policies of
+    /// the given guard `G`.
+    fn transmute_into_vec_or_copy<T: TriviallyTransmutable, G: Guard>(self) -> Result<Vec<T>, Error<'static, u8, T>>;
+}
+
+#[cfg(feature = "alloc")]
+impl VecTransmuteExt for Vec<u8> {
+    #[inline]
+    fn transmute_into_vec<T: TriviallyTransmutable>(self) -> Result<Vec<T>, Error<'static, u8, T>> {
+        transmute_vec::<u8, T>(self)
+    }
+
+    #[inline]
+    fn transmute_into_vec_or_copy<T: TriviallyTransmutable, G: Guard>(self) -> Result<Vec<T>, Error<'static, u8, T>> {
+        transmute_vec_or_copy::<T, G>(self)
+    }
+}
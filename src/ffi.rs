@@ -0,0 +1,86 @@
+//! Views of data handed over from C as slices of values.
+//!
+//! C libraries hand out buffers as a pointer and a byte length, where the
+//! pointer may be null for empty buffers. The functions in this module check
+//! such a pair for nullness, memory alignment, and against a guard all at
+//! once, instead of combining `slice::from_raw_parts()` with a transmutation
+//! at every call site.
+
+
+use self::super::full::{transmute_many_mut, transmute_many};
+use self::super::trivial::TriviallyTransmutable;
+use self::super::guard::Guard;
+use self::super::Error;
+use core::ffi::c_void;
+use core::slice;
+
+
+/// View `byte_len` bytes at a pointer received from C as a slice of values.
+///
+/// A null pointer is taken to point to no data, regardless of `byte_len`, as
+/// in the `NULL, 0` pairs C libraries use for empty buffers. Whether that is
+/// an error is up to the guard.
+///
+/// The required byte length depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`.
+/// - The data does not comply with the policies of the given guard `G`.
+///
+/// # Safety
+///
+/// Unless it is null, `ptr` must be valid for reads of `byte_len` bytes,
+/// which must not be modified for the lifetime `'a`, as for
+/// [`slice::from_raw_parts()`](https://doc.rust-lang.org/core/slice/fn.from_raw_parts.html).
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::ffi::slice_from_c;
+/// # use safe_transmute::{PermissiveGuard, PedanticGuard};
+/// # use std::ffi::c_void;
+/// # use std::ptr;
+/// let samples = [1.0f32, 2.0];
+/// let data = samples.as_ptr() as *const c_void;
+///
+/// assert_eq!(unsafe { slice_from_c::<f32, PedanticGuard>(data, 8) }, Ok(&[1.0, 2.0][..]));
+/// assert_eq!(unsafe { slice_from_c::<f32, PermissiveGuard>(ptr::null(), 0) }, Ok(&[][..]));
+/// assert!(unsafe { slice_from_c::<f32, PedanticGuard>(ptr::null(), 8) }.is_err());
+/// ```
+pub unsafe fn slice_from_c<'a, T: TriviallyTransmutable, G: Guard>(ptr: *const c_void, byte_len: usize) -> Result<&'a [T], Error<'a, u8, T>> {
+    if ptr.is_null() {
+        G::check_len::<T>(0)?;
+        return Ok(&[]);
+    }
+    transmute_many::<T, G>(slice::from_raw_parts(ptr as *const u8, byte_len))
+}
+
+/// View `byte_len` bytes at a pointer received from C as a mutable slice of
+/// values.
+///
+/// See [`slice_from_c()`](fn.slice_from_c.html) for details.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`.
+/// - The data does not comply with the policies of the given guard `G`.
+///
+/// # Safety
+///
+/// Unless it is null, `ptr` must be valid for reads and writes of `byte_len`
+/// bytes, which must not be accessed through any other pointer for the
+/// lifetime `'a`, as for
+/// [`slice::from_raw_parts_mut()`](https://doc.rust-lang.org/core/slice/fn.from_raw_parts_mut.html).
+pub unsafe fn slice_from_c_mut<'a, T: TriviallyTransmutable, G: Guard>(ptr: *mut c_void, byte_len: usize) -> Result<&'a mut [T], Error<'a, u8, T>> {
+    if ptr.is_null() {
+        G::check_len::<T>(0)?;
+        return Ok(&mut []);
+    }
+    transmute_many_mut::<T, G>(slice::from_raw_parts_mut(ptr as *mut u8, byte_len))
+}
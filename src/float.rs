@@ -0,0 +1,89 @@
+//! Safe transmutation to floating-point values.
+//!
+//! Any bit pattern is a valid float, but some of them are signaling NaNs,
+//! which, while not illegal, can be unwieldy. The functions in this module
+//! view mutable byte slices as slices of floats, optionally making any
+//! signaling NaNs quiet in place, so that the result can be used without
+//! further care.
+
+
+use self::super::trivial::TriviallyTransmutable;
+use self::super::util::Designalise;
+use self::super::full::transmute_many_mut;
+use self::super::guard::Guard;
+use self::super::Error;
+
+
+/// View a mutable byte slice as a slice of floats, making any signaling NaNs
+/// quiet in place if `designalise` is `true`.
+///
+/// The required byte length of the slice depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `F`. You will
+///   have to make a copy anyway, or modify how the data was originally made.
+/// - The data does not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::float::transmute_many_float;
+/// # use safe_transmute::{SingleManyGuard, transmute_to_bytes_mut};
+/// let mut samples = [0x7F80_0001u32, 1.5f32.to_bits()];
+/// let floats = transmute_many_float::<f32, SingleManyGuard>(transmute_to_bytes_mut(&mut samples), true).unwrap();
+///
+/// assert!(floats[0].is_nan());
+/// assert_eq!(floats[0].to_bits(), 0x7FC0_0001);
+/// assert_eq!(floats[1], 1.5);
+/// ```
+pub fn transmute_many_float<F: Designalise + TriviallyTransmutable, G: Guard>(bytes: &mut [u8], designalise: bool) -> Result<&mut [F], Error<'_, u8, F>> {
+    let floats = transmute_many_mut::<F, G>(bytes)?;
+    if designalise {
+        for f in floats.iter_mut() {
+            *f = f.designalise();
+        }
+    }
+    Ok(floats)
+}
+
+/// View a mutable byte slice as a slice of `f32`s, making any signaling NaNs
+/// quiet in place if `designalise` is `true`.
+///
+/// See [`transmute_many_float()`](fn.transmute_many_float.html) for details.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PedanticGuard, transmute_many_f32, transmute_to_bytes_mut};
+/// let mut samples = [0.25f32.to_bits(), 0xFF80_0010];
+/// let floats = transmute_many_f32::<PedanticGuard>(transmute_to_bytes_mut(&mut samples), true).unwrap();
+///
+/// assert_eq!(floats[0], 0.25);
+/// assert_eq!(floats[1].to_bits(), 0xFFC0_0010);
+/// ```
+pub fn transmute_many_f32<G: Guard>(bytes: &mut [u8], designalise: bool) -> Result<&mut [f32], Error<'_, u8, f32>> {
+    transmute_many_float::<f32, G>(bytes, designalise)
+}
+
+/// View a mutable byte slice as a slice of `f64`s, making any signaling NaNs
+/// quiet in place if `designalise` is `true`.
+///
+/// See [`transmute_many_float()`](fn.transmute_many_float.html) for details.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PedanticGuard, transmute_many_f64, transmute_to_bytes_mut};
+/// let mut samples = [0.25f64.to_bits(), 0x7FF0_0000_0000_0100];
+/// let floats = transmute_many_f64::<PedanticGuard>(transmute_to_bytes_mut(&mut samples), true).unwrap();
+///
+/// assert_eq!(floats[0], 0.25);
+/// assert_eq!(floats[1].to_bits(), 0x7FF8_0000_0000_0100);
+/// ```
+pub fn transmute_many_f64<G: Guard>(bytes: &mut [u8], designalise: bool) -> Result<&mut [f64], Error<'_, u8, f64>> {
+    transmute_many_float::<f64, G>(bytes, designalise)
+}
@@ -0,0 +1,236 @@
+//! Iteration over length-prefixed records.
+//!
+//! Many packet streams and file formats are laid out as a series of records,
+//! each made of a fixed header followed by a payload whose length is stored
+//! in the header. A [`Frames`](struct.Frames.html) iterator walks such data,
+//! yielding every header along with a view of its payload.
+//!
+//! Database and file system images are instead split into pages of a fixed
+//! size, each starting with a header; a [`PageView`](struct.PageView.html)
+//! views every page's header in place, with the rest of it as raw payload.
+
+
+use self::super::full::{transmute_one_unaligned, transmute_many};
+use self::super::guard::{AllOrNothingGuard, SingleManyGuard, Guard};
+use self::super::error::{TransmuteError, ErrorReason, GuardError};
+use self::super::trivial::TriviallyTransmutable;
+use self::super::align::check_alignment;
+use self::super::Error;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::mem::size_of;
+use core::fmt;
+
+
+/// An iterator over the records of a byte slice, each made of a header `H`
+/// directly followed by a payload of `T` values.
+///
+/// The number of values in a payload is read out of its header with the
+/// closure `F` given on construction. Headers are copied out with unaligned
+/// reads, but payloads are viewed in place, so have to be aligned for `T`.
+///
+/// Iteration ends at the end of the data, or after the first error, which
+/// leaves the offending bytes in the [`remainder()`](#method.remainder).
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::framing::Frames;
+/// # use safe_transmute::endian::U16Le;
+/// # use safe_transmute::TransmuteError;
+/// # fn run() -> Result<(), TransmuteError> {
+/// // Two records, each a little-endian byte count followed by that many bytes
+/// let data = [0x02, 0x00, b'h', b'i', 0x03, 0x00, b'y', b'o', b'u'];
+/// let mut frames = Frames::<U16Le, u8, _>::new(&data, |header| header.get() as usize);
+///
+/// assert_eq!(frames.next().transpose()?.map(|(_, payload)| payload), Some(&b"hi"[..]));
+/// assert_eq!(frames.next().transpose()?.map(|(_, payload)| payload), Some(&b"you"[..]));
+/// assert_eq!(frames.next(), None);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+pub struct Frames<'a, H, T, F> {
+    bytes: &'a [u8],
+    payload_len: F,
+    failed: bool,
+    _phantom: PhantomData<(H, T)>,
+}
+
+impl<'a, H: TriviallyTransmutable, T: TriviallyTransmutable, F: Fn(&H) -> usize> Frames<'a, H, T, F> {
+    /// Iterate over the records in the given bytes, with `payload_len`
+    /// returning the number of `T` values following each header.
+    pub fn new(bytes: &'a [u8], payload_len: F) -> Frames<'a, H, T, F> {
+        Frames {
+            bytes,
+            payload_len,
+            failed: false,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// The bytes which have not been consumed yet, starting with the header
+    /// of the next record, or of the one which failed to parse.
+    pub fn remainder(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    fn next_record(&self) -> Result<(H, &'a [T], &'a [u8]), TransmuteError> {
+        SingleManyGuard::check::<H>(self.bytes)?;
+        let header = transmute_one_unaligned::<H>(self.bytes).map_err(|e| e.into_owned())?;
+
+        let rest = &self.bytes[size_of::<H>()..];
+        let payload_bytes = (self.payload_len)(&header).saturating_mul(size_of::<T>());
+        if rest.len() < payload_bytes {
+            return Err(TransmuteError::Guard(GuardError {
+                required: payload_bytes,
+                actual: rest.len(),
+                reason: ErrorReason::NotEnoughBytes,
+            }));
+        }
+
+        let (payload, rest) = rest.split_at(payload_bytes);
+        let payload = transmute_many::<T, AllOrNothingGuard>(payload).map_err(|e| e.into_owned())?;
+        Ok((header, payload, rest))
+    }
+}
+
+impl<'a, H: TriviallyTransmutable, T: TriviallyTransmutable + 'a, F: Fn(&H) -> usize> Iterator for Frames<'a, H, T, F> {
+    type Item = Result<(H, &'a [T]), TransmuteError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.bytes.is_empty() {
+            return None;
+        }
+
+        Some(match self.next_record() {
+            Ok((header, payload, rest)) => {
+                self.bytes = rest;
+                Ok((header, payload))
+            }
+            Err(e) => {
+                self.failed = true;
+                Err(e)
+            }
+        })
+    }
+}
+
+impl<'a, H: TriviallyTransmutable, T: TriviallyTransmutable + 'a, F: Fn(&H) -> usize> FusedIterator for Frames<'a, H, T, F> {}
+
+impl<'a, H, T, F: Clone> Clone for Frames<'a, H, T, F> {
+    fn clone(&self) -> Self {
+        Frames {
+            bytes: self.bytes,
+            payload_len: self.payload_len.clone(),
+            failed: self.failed,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, H, T, F> fmt::Debug for Frames<'a, H, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Frames").field("bytes", &self.bytes).field("failed", &self.failed).finish()
+    }
+}
+
+
+/// A view of a byte slice as pages of `PAGE` bytes, each starting with a
+/// header `T`, followed by raw payload.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PageView, transmute_to_bytes};
+/// // Two pages of 8 bytes, each starting with a page number
+/// let pages = [7u32, u32::from_ne_bytes(*b"data"), 8, u32::from_ne_bytes(*b"more")];
+/// let view = PageView::<u32, 8>::new(transmute_to_bytes(&pages)).unwrap();
+///
+/// assert_eq!(view.len(), 2);
+/// assert_eq!(view.page(1), (&8, &b"more"[..]));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PageView<'a, T, const PAGE: usize> {
+    bytes: &'a [u8],
+    _phantom: PhantomData<&'a T>,
+}
+
+impl<'a, T: TriviallyTransmutable, const PAGE: usize> PageView<'a, T, PAGE> {
+    /// View the given bytes as pages of `PAGE` bytes each.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in one of the following situations:
+    ///
+    /// - Any of the pages does not have a memory alignment compatible with
+    ///   `T`, reported for the first one which does not.
+    /// - The data does not hold a whole number of pages.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `PAGE` is zero, or shorter than a header.
+    pub fn new(bytes: &'a [u8]) -> Result<PageView<'a, T, PAGE>, Error<'a, u8, T>> {
+        assert!(PAGE != 0 && PAGE >= size_of::<T>(),
+                "page of {} bytes is zero or shorter than a header of {} bytes",
+                PAGE,
+                size_of::<T>());
+
+        if bytes.len() / PAGE * PAGE != bytes.len() {
+            return Err(Error::Guard(GuardError {
+                required: PAGE,
+                actual: bytes.len(),
+                reason: ErrorReason::InexactByteCount,
+            }));
+        }
+        check_alignment::<_, T>(bytes)?;
+        if bytes.len() > PAGE {
+            // Every header is aligned if the first two are
+            check_alignment::<_, T>(&bytes[PAGE..])?;
+        }
+
+        Ok(PageView {
+            bytes,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// The number of pages.
+    pub fn len(&self) -> usize {
+        self.bytes.len() / PAGE
+    }
+
+    /// Whether there are no pages.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// The header and payload of the page at the given index.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `i` is not less than the number of pages.
+    pub fn page(&self, i: usize) -> (&'a T, &'a [u8]) {
+        self.get(i).unwrap_or_else(|| panic!("page {} out of bounds for a view of {} pages", i, self.len()))
+    }
+
+    /// The header and payload of the page at the given index, or `None` if
+    /// it is out of bounds.
+    pub fn get(&self, i: usize) -> Option<(&'a T, &'a [u8])> {
+        if i < self.len() {
+            let page = &self.bytes[i * PAGE..(i + 1) * PAGE];
+            // Every header is aligned for `T`, and fits in its page
+            let header = unsafe { &*(page.as_ptr() as *const T) };
+            Some((header, &page[size_of::<T>()..]))
+        } else {
+            None
+        }
+    }
+
+    /// An iterator over the headers and payloads of the pages, from the first
+    /// one.
+    pub fn pages(&self) -> impl Iterator<Item = (&'a T, &'a [u8])> + 'a {
+        let view = *self;
+        (0..self.len()).map(move |i| view.page(i))
+    }
+}
@@ -0,0 +1,112 @@
+//! Reading values out of, and writing them into, whole files.
+//!
+//! Does not exist without the `std` feature.
+
+
+use self::super::to_bytes::transmute_to_bytes;
+use self::super::stream::read_exact_typed;
+use self::super::trivial::TriviallyTransmutable;
+use self::super::error::LoadError;
+use self::super::guard::Guard;
+use std::io::{self, ErrorKind, Write};
+use std::path::{PathBuf, Path};
+use std::fs::{self, File};
+use std::convert::TryFrom;
+use std::mem;
+
+
+/// Read a file into a vector of values, checked against the given guard.
+///
+/// The values are read straight into their vector, so are always aligned;
+/// bytes after the last value the guard allows for are not read.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The file could not be opened or read.
+/// - The file is too large to be addressed on this platform.
+/// - The size of the file does not comply with the policies of the given
+///   guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PedanticGuard, load_typed};
+/// # use std::fs;
+/// # let path = std::env::temp_dir().join("safe-transmute-load_typed-doc");
+/// fs::write(&path, [0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00])?;
+///
+/// assert_eq!(load_typed::<u32, PedanticGuard, _>(&path)?, [u32::from_le(1), u32::from_le(2)]);
+/// # fs::remove_file(&path)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn load_typed<T: TriviallyTransmutable, G: Guard, P: AsRef<Path>>(path: P) -> Result<Vec<T>, LoadError> {
+    let mut file = File::open(path)?;
+    let byte_len = usize::try_from(file.metadata()?.len()).map_err(|_| io::Error::new(ErrorKind::InvalidData, "file too large to address"))?;
+    let len = G::check_len::<T>(byte_len)?;
+
+    // All-zero bytes are a valid `T` thanks to `TriviallyTransmutable`
+    let mut values = vec![unsafe { mem::zeroed::<T>() }; len];
+    read_exact_typed(&mut file, &mut values)?;
+    Ok(values)
+}
+
+/// Write a slice of values into a file, replacing it if it exists.
+///
+/// The file holds the bytes of the values, as from
+/// [`transmute_to_bytes()`](../to_bytes/fn.transmute_to_bytes.html), so can
+/// be read back with [`load_typed()`](fn.load_typed.html) on the same
+/// platform. Use [`save_typed_atomic()`](fn.save_typed_atomic.html) if
+/// readers should never see a partially written file.
+///
+/// # Errors
+///
+/// An error is returned if the file could not be created or written.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PedanticGuard, load_typed, save_typed};
+/// # let path = std::env::temp_dir().join("safe-transmute-save_typed-doc");
+/// save_typed(&path, &[1.5f32, 2.5])?;
+///
+/// assert_eq!(load_typed::<f32, PedanticGuard, _>(&path)?, [1.5, 2.5]);
+/// # std::fs::remove_file(&path)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn save_typed<T: TriviallyTransmutable, P: AsRef<Path>>(path: P, values: &[T]) -> io::Result<()> {
+    fs::write(path, transmute_to_bytes(values))
+}
+
+/// Write a slice of values into a file, atomically replacing it if it
+/// exists.
+///
+/// The values are written into a temporary file next to the destination,
+/// named after it with `.tmp` appended, which is then synced to disk and
+/// renamed over the destination. Readers thus see either the old file or the
+/// whole new one.
+///
+/// See [`save_typed()`](fn.save_typed.html) for details.
+///
+/// # Errors
+///
+/// An error is returned if the temporary file could not be created, written,
+/// or renamed. The temporary file is removed in that case.
+pub fn save_typed_atomic<T: TriviallyTransmutable, P: AsRef<Path>>(path: P, values: &[T]) -> io::Result<()> {
+    let path = path.as_ref();
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    let temp = PathBuf::from(temp);
+
+    let result = File::create(&temp)
+        .and_then(|mut file| {
+            file.write_all(transmute_to_bytes(values))?;
+            file.sync_all()
+        })
+        .and_then(|_| fs::rename(&temp, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    result
+}
@@ -11,15 +11,30 @@
 //! target type.
 
 
-use self::super::trivial::{TriviallyTransmutable, transmute_trivial, transmute_trivial_many, transmute_trivial_many_mut};
-use self::super::guard::{SingleValueGuard, PermissiveGuard, PedanticGuard, Guard};
+use self::super::trivial::{TriviallyTransmutable, transmute_trivial_many_mut_with, transmute_trivial_many_with, transmute_trivial_many_mut,
+                           transmute_trivial_many, transmute_trivial};
+use self::super::guard::{SingleValueGuard, SingleManyGuard, PermissiveGuard, PedanticGuard, RuntimeGuard, Guard};
+#[cfg(feature = "alloc")]
+use self::super::guard::value_bytes;
 use self::super::align::{check_alignment, check_alignment_mut};
+use self::super::base::{from_bytes_pedantic_const, from_bytes_const};
+use self::super::error::{UnalignedError, GuardError};
+use core::marker::PhantomData;
+use core::mem;
+use core::ptr;
+use core::slice;
 #[cfg(feature = "alloc")]
-use self::super::error::IncompatibleVecTargetError;
+use self::super::error::{IncompatibleVecTargetError, copy_to_vec_unchecked};
 #[cfg(feature = "alloc")]
 use core::mem::{align_of, size_of, forget};
 use self::super::Error;
 #[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
 
@@ -86,6 +101,322 @@ pub fn transmute_one_pedantic<T: TriviallyTransmutable>(bytes: &[u8]) -> Result<
     unsafe { transmute_trivial(bytes) }
 }
 
+/// View a byte slice as a single instance of a trivially transmutable type.
+///
+/// The byte slice must have at least enough bytes to fill a single instance of a type,
+/// extraneous data is ignored.
+///
+/// Unlike [`transmute_one()`](fn.transmute_one.html), the value is not copied
+/// out, which avoids copying large values, like file headers, and allows
+/// borrowing their fields.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`. You will
+///   have to make a copy anyway, or modify how the data was originally made.
+/// - The data does not have enough bytes for a single value `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{transmute_to_bytes, transmute_one_ref};
+/// let words: &[u32] = &[1, 2, 3];
+/// let header: &[u32; 2] = transmute_one_ref(transmute_to_bytes(words)).unwrap();
+///
+/// assert_eq!(header, &[1, 2]);
+/// ```
+pub fn transmute_one_ref<T: TriviallyTransmutable>(bytes: &[u8]) -> Result<&T, Error<'_, u8, T>> {
+    check_alignment::<_, T>(bytes)?;
+    SingleManyGuard::check::<T>(bytes)?;
+    unsafe { Ok(&*(bytes.as_ptr() as *const T)) }
+}
+
+/// View a mutable byte slice as a single mutable instance of a trivially
+/// transmutable type.
+///
+/// The byte slice must have at least enough bytes to fill a single instance of a type,
+/// extraneous data is ignored.
+///
+/// This is the mutable counterpart of
+/// [`transmute_one_ref()`](fn.transmute_one_ref.html), for editing values,
+/// like the fields of a file header, in place.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`. You will
+///   have to make a copy anyway, or modify how the data was originally made.
+/// - The data does not have enough bytes for a single value `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{transmute_to_bytes_mut, transmute_one_ref_mut};
+/// let mut words = [0u32, 0xFF, 0xFF];
+/// let len: &mut u32 = transmute_one_ref_mut(transmute_to_bytes_mut(&mut words)).unwrap();
+/// *len = 2;
+///
+/// assert_eq!(words, [2, 0xFF, 0xFF]);
+/// ```
+pub fn transmute_one_ref_mut<T: TriviallyTransmutable>(bytes: &mut [u8]) -> Result<&mut T, Error<'_, u8, T>> {
+    let bytes = check_alignment_mut::<_, T>(bytes)?;
+    SingleManyGuard::check::<T>(bytes)?;
+    unsafe { Ok(&mut *(bytes.as_mut_ptr() as *mut T)) }
+}
+
+/// Transmute a byte slice into a single instance of a trivially transmutable
+/// type, regardless of memory alignment.
+///
+/// The byte slice must have at least enough bytes to fill a single instance of a type,
+/// extraneous data is ignored.
+///
+/// Unlike [`transmute_one()`](fn.transmute_one.html), the value is copied out
+/// with an unaligned read, so this function never fails due to memory alignment.
+///
+/// # Errors
+///
+/// An error is returned if the data does not have enough bytes for a single value `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{Error, transmute_one_unaligned};
+/// # fn run() -> Result<(), Error<'static, u8, u32>> {
+/// let packet: &[u8] = &[0xFF, 0x01, 0x00, 0x00, 0x00];
+/// assert_eq!(transmute_one_unaligned::<u32>(&packet[1..])?, u32::from_le(1));
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+pub fn transmute_one_unaligned<T: TriviallyTransmutable>(bytes: &[u8]) -> Result<T, Error<'_, u8, T>> {
+    transmute_one_const::<T>(bytes).map_err(Error::from)
+}
+
+/// Transmute a byte slice into a single instance of a trivially transmutable
+/// type in a constant context.
+///
+/// The byte slice must have at least enough bytes to fill a single instance of a type,
+/// extraneous data is ignored.
+///
+/// Unlike [`transmute_one()`](fn.transmute_one.html), this function has no
+/// memory alignment requirements, as the value is copied out with an
+/// unaligned read. This makes it possible to build constants and lookup tables
+/// out of embedded byte blobs at compile time.
+///
+/// # Errors
+///
+/// An error is returned if the data does not have enough bytes for a single value `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_one_const;
+/// static TABLE: &[u8] = &[0x00, 0x00, 0x80, 0x3F, 0xFF];
+/// const ONE: f32 = match transmute_one_const::<[u8; 4]>(TABLE) {
+///     Ok(bytes) => f32::from_le_bytes(bytes),
+///     Err(_) => panic!("table too short"),
+/// };
+///
+/// assert_eq!(ONE, 1.0);
+/// ```
+pub const fn transmute_one_const<T: TriviallyTransmutable>(bytes: &[u8]) -> Result<T, GuardError> {
+    unsafe { from_bytes_const::<T>(bytes) }
+}
+
+/// Transmute a byte slice into a single instance of a trivially transmutable
+/// type in a constant context.
+///
+/// The byte slice must have exactly enough bytes to fill a single instance of a type.
+///
+/// Unlike [`transmute_one_pedantic()`](fn.transmute_one_pedantic.html), this
+/// function has no memory alignment requirements, as the value is copied out
+/// with an unaligned read.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have enough bytes for a single value `T`.
+/// - The data has more bytes than those required to produce a single value `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_one_pedantic_const;
+/// const MAGIC: u32 = match transmute_one_pedantic_const::<u32>(b"\x7FELF") {
+///     Ok(magic) => magic,
+///     Err(_) => panic!("bad magic"),
+/// };
+///
+/// assert_eq!(MAGIC, u32::from_be(0x7F454C46));
+/// ```
+pub const fn transmute_one_pedantic_const<T: TriviallyTransmutable>(bytes: &[u8]) -> Result<T, GuardError> {
+    unsafe { from_bytes_pedantic_const::<T>(bytes) }
+}
+
+/// Compile-time assertion that `T` is exactly `N` bytes long, and not zero-sized.
+struct AssertArrayFitsOne<T, const N: usize>(PhantomData<T>);
+
+impl<T, const N: usize> AssertArrayFitsOne<T, N> {
+    const OK: () = {
+        assert!(mem::size_of::<T>() != 0, "the target type is zero-sized");
+        assert!(mem::size_of::<T>() == N, "the array length differs from the size of the target type");
+    };
+}
+
+/// Transmute a byte array into a single instance of a trivially transmutable type.
+///
+/// The array length must be equal to the size of `T`, which must not be
+/// zero, and both are checked at compile time. As the value is copied out
+/// with an unaligned read, this function cannot fail.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_one_from_array;
+/// assert_eq!(transmute_one_from_array::<u32, 4>(&[0x01, 0x00, 0x00, 0x00]), u32::from_le(1));
+/// ```
+///
+/// Arrays of the wrong size are rejected at compile time:
+///
+/// ```compile_fail
+/// # use safe_transmute::transmute_one_from_array;
+/// transmute_one_from_array::<u32, 3>(&[0x01, 0x00, 0x00]);
+/// ```
+pub fn transmute_one_from_array<T: TriviallyTransmutable, const N: usize>(bytes: &[u8; N]) -> T {
+    let () = AssertArrayFitsOne::<T, N>::OK;
+    unsafe { ptr::read_unaligned(bytes.as_ptr() as *const T) }
+}
+
+/// View a byte array as a single instance of a trivially transmutable type.
+///
+/// The array length must be equal to the size of `T`, which must not be
+/// zero, and both are checked at compile time.
+///
+/// # Errors
+///
+/// An error is returned if the data does not have a memory alignment
+/// compatible with `T`. In that case,
+/// [`transmute_one_from_array()`](fn.transmute_one_from_array.html) can be
+/// used to copy the value out instead.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_one_ref_from_array;
+/// #[repr(align(4))]
+/// struct Aligned([u8; 4]);
+///
+/// let bytes = Aligned([0x01, 0x00, 0x00, 0x00]);
+/// assert_eq!(transmute_one_ref_from_array::<u32, 4>(&bytes.0), Ok(&u32::from_le(1)));
+/// ```
+pub fn transmute_one_ref_from_array<T: TriviallyTransmutable, const N: usize>(bytes: &[u8; N]) -> Result<&T, UnalignedError<'_, u8, T>> {
+    let () = AssertArrayFitsOne::<T, N>::OK;
+    check_alignment::<_, T>(bytes)?;
+    unsafe { Ok(&*(bytes.as_ptr() as *const T)) }
+}
+
+/// Compile-time assertion that `S` and `T` have the same size.
+struct AssertSameSize<S, T>(PhantomData<(S, T)>);
+
+impl<S, T> AssertSameSize<S, T> {
+    const OK: () = assert!(mem::size_of::<S>() == mem::size_of::<T>(), "the source and target types differ in size");
+}
+
+/// Transmute a value into one of another trivially transmutable type of the
+/// same size.
+///
+/// The sizes of `S` and `T` must be equal, which is checked at compile time,
+/// so this is a safe alternative to `mem::transmute()` which cannot fail.
+/// Their memory alignments may differ.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_value;
+/// assert_eq!(transmute_value::<f32, u32>(1.0), 0x3F80_0000);
+/// assert_eq!(transmute_value::<[u16; 2], u32>([0x0001, 0x0002]), u32::from_le(0x0002_0001));
+/// ```
+///
+/// Types of different sizes do not compile:
+///
+/// ```compile_fail
+/// # use safe_transmute::transmute_value;
+/// transmute_value::<u32, u64>(1);
+/// ```
+pub fn transmute_value<S: TriviallyTransmutable, T: TriviallyTransmutable>(value: S) -> T {
+    let () = AssertSameSize::<S, T>::OK;
+    unsafe { mem::transmute_copy(&value) }
+}
+
+/// View a reference to a value as one to another trivially transmutable type.
+///
+/// This covers reinterpretations of single values, like viewing a wrapper as
+/// its inner value, or a struct of equally typed fields as an array.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The value does not have a memory alignment compatible with `T`. Use
+///   [`transmute_value()`](fn.transmute_value.html) to copy it instead.
+/// - The sizes of `S` and `T` differ, as a guard error.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{TriviallyTransmutable, transmute_ref};
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Point {
+///     x: f32,
+///     y: f32,
+/// }
+/// unsafe impl TriviallyTransmutable for Point {}
+///
+/// let point = Point { x: 1.0, y: 2.0 };
+/// assert_eq!(transmute_ref::<Point, [f32; 2]>(&point).unwrap(), &[1.0, 2.0]);
+/// assert!(transmute_ref::<Point, [f32; 3]>(&point).is_err());
+/// ```
+pub fn transmute_ref<S: TriviallyTransmutable, T: TriviallyTransmutable>(value: &S) -> Result<&T, Error<'_, S, T>> {
+    let source = slice::from_ref(value);
+    check_alignment::<_, T>(source)?;
+    SingleValueGuard::check_len::<T>(mem::size_of::<S>())?;
+    unsafe { Ok(&*(value as *const S as *const T)) }
+}
+
+/// View a mutable reference to a value as one to another trivially
+/// transmutable type.
+///
+/// This is the mutable counterpart of
+/// [`transmute_ref()`](fn.transmute_ref.html), for editing values in place
+/// as another type, like a byte array as the header it holds.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The value does not have a memory alignment compatible with `T`.
+/// - The sizes of `S` and `T` differ, as a guard error.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_ref_mut;
+/// let mut words = [0u16; 4];
+/// transmute_ref_mut::<[u16; 4], [[u16; 2]; 2]>(&mut words).unwrap()[1] = [3, 4];
+///
+/// assert_eq!(words, [0, 0, 3, 4]);
+/// ```
+pub fn transmute_ref_mut<S: TriviallyTransmutable, T: TriviallyTransmutable>(value: &mut S) -> Result<&mut T, Error<'_, S, T>> {
+    let source = check_alignment_mut::<_, T>(slice::from_mut(value))?;
+    SingleValueGuard::check_len::<T>(mem::size_of::<S>())?;
+    unsafe { Ok(&mut *(source.as_mut_ptr() as *mut T)) }
+}
+
 /// Transmute a byte slice into a sequence of values of the given type.
 ///
 /// # Errors
@@ -115,6 +446,41 @@ pub fn transmute_many<T: TriviallyTransmutable, G: Guard>(bytes: &[u8]) -> Resul
     unsafe { transmute_trivial_many::<_, G>(bytes) }
 }
 
+/// Transmute a byte slice into a sequence of values of the given type,
+/// checking it against a guard value.
+///
+/// This is equivalent to [`transmute_many()`](fn.transmute_many.html), but
+/// with a guard which may depend on runtime state, like an element count read
+/// from a header. Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`. You will
+///   have to make a copy anyway, or modify how the data was originally made.
+/// - The data does not comply with the policies of the given guard.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::guard::BoundedGuard;
+/// # use safe_transmute::transmute_many_with;
+/// # include!("../tests/test_util/le_to_native.rs");
+/// # fn main() {
+/// // Little-endian
+/// # /*
+/// assert_eq!(transmute_many_with::<u16, _>(&[0x00, 0x01, 0x00, 0x02], BoundedGuard::new(1, 2))?,
+/// # */
+/// # assert_eq!(transmute_many_with::<u16, _>(&Le2NAl4([0x00, 0x01, 0x00, 0x02]).0.le_to_native::<u16>(), BoundedGuard::new(1, 2)).unwrap(),
+///            &[0x0100, 0x0200]);
+/// # }
+/// ```
+pub fn transmute_many_with<T: TriviallyTransmutable, G: RuntimeGuard>(bytes: &[u8], guard: G) -> Result<&[T], Error<'_, u8, T>> {
+    check_alignment::<_, T>(bytes)?;
+    unsafe { transmute_trivial_many_with::<_, G>(bytes, guard) }
+}
+
 /// Transmute a byte slice into a sequence of values of the given type.
 ///
 /// # Errors
@@ -132,7 +498,7 @@ pub fn transmute_many<T: TriviallyTransmutable, G: Guard>(bytes: &[u8]) -> Resul
 /// assert_eq!(transmute_many_permissive::<u16>(&[0x00])?, [].as_ref());
 /// # */
 /// # match transmute_many_permissive::<u16>(&[0x00]) {
-/// #   Ok(sl) => assert_eq!(sl, [].as_ref()),
+/// #   Ok(sl) => assert_eq!(sl, [0u16; 0].as_ref()),
 /// #   Err(Error::Unaligned(_)) => {}
 /// #   Err(e) => panic!("{}", e),
 /// # }
@@ -199,6 +565,42 @@ pub fn transmute_many_mut<T: TriviallyTransmutable, G: Guard>(bytes: &mut [u8])
         .and_then(|bytes| unsafe { transmute_trivial_many_mut::<_, G>(bytes) })
 }
 
+/// Transmute a mutable byte slice into a mutable sequence of values of the
+/// given type, checking it against a guard value.
+///
+/// This is equivalent to [`transmute_many_mut()`](fn.transmute_many_mut.html),
+/// but with a guard which may depend on runtime state.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`. You will
+///   have to make a copy anyway, or modify how the data was originally made.
+/// - The data does not comply with the policies of the given guard.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::guard::BoundedGuard;
+/// # use safe_transmute::transmute_many_mut_with;
+/// # include!("../tests/test_util/le_to_native.rs");
+/// # fn main() {
+/// // Little-endian
+/// # /*
+/// assert_eq!(transmute_many_mut_with::<u16, _>(&mut [0x00, 0x01, 0x00, 0x02], BoundedGuard::at_most(2))?,
+/// # */
+/// # assert_eq!(transmute_many_mut_with::<u16, _>(&mut Le2NAl4([0x00, 0x01, 0x00, 0x02]).0.le_to_native::<u16>(), BoundedGuard::at_most(2)).unwrap(),
+///            &mut [0x0100, 0x0200]);
+/// # }
+/// ```
+pub fn transmute_many_mut_with<T: TriviallyTransmutable, G: RuntimeGuard>(bytes: &mut [u8], guard: G) -> Result<&mut [T], Error<'_, u8, T>> {
+    check_alignment_mut::<_, T>(bytes)
+        .map_err(Error::from)
+        .and_then(|bytes| unsafe { transmute_trivial_many_mut_with::<_, G>(bytes, guard) })
+}
+
 /// Transmute a byte slice into a sequence of values of the given type.
 ///
 /// # Errors
@@ -216,7 +618,7 @@ pub fn transmute_many_mut<T: TriviallyTransmutable, G: Guard>(bytes: &mut [u8])
 /// assert_eq!(transmute_many_permissive_mut::<u16>(&mut [0x00])?, [].as_mut());
 /// # */
 /// # match transmute_many_permissive_mut::<u16>(&mut [0x00]) {
-/// #   Ok(sl) => assert_eq!(sl, [].as_mut()),
+/// #   Ok(sl) => assert_eq!(sl, [0u16; 0].as_mut()),
 /// #   Err(Error::Unaligned(_)) => {}
 /// #   Err(e) => panic!("{}", e),
 /// # }
@@ -253,6 +655,40 @@ pub fn transmute_many_pedantic_mut<T: TriviallyTransmutable>(bytes: &mut [u8]) -
     transmute_many_mut::<T, PedanticGuard>(bytes)
 }
 
+/// Copy a byte slice into a new vector of values of the given type.
+///
+/// As the vector will be properly aligned for accessing values of type `T`,
+/// this operation will never fail due to memory alignment constraints, at the
+/// cost of always copying the data. Extraneous bytes allowed by the guard are
+/// not copied.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{SingleManyGuard, Error, transmute_to_vec};
+/// # fn run() -> Result<(), Error<'static, u8, u16>> {
+/// let bytes = &[0xFF, 0x01, 0x00, 0x02, 0x00, 0xFF];
+/// assert_eq!(transmute_to_vec::<u16, SingleManyGuard>(&bytes[1..])?,
+///            vec![u16::from_le(0x0001), u16::from_le(0x0002)]);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_to_vec<T: TriviallyTransmutable, G: Guard>(bytes: &[u8]) -> Result<Vec<T>, Error<'_, u8, T>> {
+    let len = G::check::<T>(bytes)?;
+
+    unsafe {
+        // no value checks needed thanks to `TriviallyTransmutable`
+        Ok(copy_to_vec_unchecked::<u8, T>(value_bytes::<T>(bytes, len)))
+    }
+}
+
 /// Transform a vector into a vector of values with the given target type.
 ///
 /// The resulting vector will reuse the allocated byte buffer when successful.
@@ -298,3 +734,190 @@ pub fn transmute_vec<S: TriviallyTransmutable, T: TriviallyTransmutable>(mut vec
         Ok(Vec::from_raw_parts(ptr as *mut T, len, capacity))
     }
 }
+
+/// Transform a boxed value into one of the given target type.
+///
+/// The heap allocation is reused, so large values are re-typed without being
+/// moved through the stack.
+///
+/// # Errors
+///
+/// The original box is returned if *either* the size or the minimum memory
+/// requirements are not the same between `S` and `T`:
+///
+/// - `std::mem::size_of::<S>() != std::mem::size_of::<T>()`
+/// - `std::mem::align_of::<S>() != std::mem::align_of::<T>()`
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_box;
+/// let words = Box::new([0x0102_0304u32; 1024]);
+/// let floats = transmute_box::<_, [f32; 1024]>(words).unwrap();
+///
+/// assert_eq!(floats[1023].to_bits(), 0x0102_0304);
+/// assert!(transmute_box::<_, [u64; 512]>(floats).is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_box<S: TriviallyTransmutable, T: TriviallyTransmutable>(boxed: Box<S>) -> Result<Box<T>, Box<S>> {
+    if align_of::<S>() != align_of::<T>() || size_of::<S>() != size_of::<T>() {
+        return Err(boxed);
+    }
+
+    // Same layout, so the allocation is freed correctly as a `T`
+    unsafe { Ok(Box::from_raw(Box::into_raw(boxed) as *mut T)) }
+}
+
+/// Transform a vector into a vector of values with the given target type,
+/// grouping or splitting elements as needed.
+///
+/// Unlike [`transmute_vec()`](fn.transmute_vec.html), the element sizes do not
+/// need to be equal, as long as one is a multiple of the other. This allows
+/// conversions such as `Vec<u16> -> Vec<[u16; 2]>` or `Vec<[u8; 4]> -> Vec<u8>`.
+///
+/// The resulting vector will reuse the allocated byte buffer when successful.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The minimum memory alignment requirements are not the same between `S` and `T`.
+/// - Neither of `std::mem::size_of::<S>()` and `std::mem::size_of::<T>()` is a multiple of the other.
+/// - The byte length or the byte capacity of the vector is not a multiple of
+///   `std::mem::size_of::<T>()`.
+///
+/// As with [`transmute_vec()`](fn.transmute_vec.html), the
+/// [`IncompatibleVecTargetError`](../error/struct.IncompatibleVecTargetError.html) error
+/// type provides a means of making a copy to the intended target type instead.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_vec_grouped;
+/// # use safe_transmute::error::Error;
+/// # fn run() -> Result<(), Error<'static, u16, [u16; 2]>> {
+/// let mut words = Vec::with_capacity(4);
+/// words.extend_from_slice(&[0x0001u16, 0x0002, 0x0003, 0x0004]);
+///
+/// assert_eq!(transmute_vec_grouped::<u16, [u16; 2]>(words)?,
+///            vec![[0x0001, 0x0002], [0x0003, 0x0004]]);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_vec_grouped<S: TriviallyTransmutable, T: TriviallyTransmutable>(mut vec: Vec<S>) -> Result<Vec<T>, Error<'static, S, T>> {
+    let (size_s, size_t) = (size_of::<S>(), size_of::<T>());
+    if align_of::<S>() != align_of::<T>() || size_s == 0 || size_t == 0 || (size_s % size_t != 0 && size_t % size_s != 0) ||
+       !(vec.len() * size_s).is_multiple_of(size_t) || !(vec.capacity() * size_s).is_multiple_of(size_t) {
+        return Err(IncompatibleVecTargetError::new(vec).into());
+    }
+
+    unsafe {
+        let capacity = vec.capacity() * size_s / size_t;
+        let len = vec.len() * size_s / size_t;
+        let ptr = vec.as_mut_ptr();
+        forget(vec);
+        Ok(Vec::from_raw_parts(ptr as *mut T, len, capacity))
+    }
+}
+
+/// Transform a byte vector into a vector of values, checked against the guard
+/// `G`, reusing its allocation if possible, and copying the values into a new
+/// one otherwise.
+#[cfg(feature = "alloc")]
+pub(crate) fn transmute_vec_or_copy<T: TriviallyTransmutable, G: Guard>(bytes: Vec<u8>) -> Result<Vec<T>, Error<'static, u8, T>> {
+    let len = G::check::<T>(&bytes)?;
+    match transmute_vec_grouped::<u8, T>(bytes) {
+        Ok(mut values) => {
+            values.truncate(len);
+            Ok(values)
+        }
+        Err(Error::IncompatibleVecTarget(e)) => transmute_to_vec::<T, G>(&e.vec).map_err(Error::without_src),
+        Err(e) => Err(e),
+    }
+}
+
+/// Transform a byte `Cow` into a `Cow` of values with the given target type.
+///
+/// Borrowed bytes stay borrowed if they are properly aligned for `T`, and
+/// are copied otherwise. Owned bytes are transformed like with
+/// [`transmute_vec_grouped()`](fn.transmute_vec_grouped.html), reusing their
+/// allocation if possible, and are copied otherwise.
+///
+/// The required byte length depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PedanticGuard, Error, transmute_to_bytes, transmute_cow};
+/// # use std::borrow::Cow;
+/// # fn run() -> Result<(), Error<'static, u8, u16>> {
+/// let words: &[u16] = &[0x0100, 0x0200];
+///
+/// let borrowed = transmute_cow::<u16, PedanticGuard>(Cow::Borrowed(transmute_to_bytes(words)))?;
+/// assert!(match borrowed {
+///     Cow::Borrowed(values) => values == words,
+///     Cow::Owned(_) => false,
+/// });
+///
+/// let owned = transmute_cow::<u16, PedanticGuard>(Cow::Owned(transmute_to_bytes(words).to_vec()))?;
+/// assert_eq!(owned, words);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_cow<T: TriviallyTransmutable, G: Guard>(bytes: Cow<[u8]>) -> Result<Cow<[T]>, Error<u8, T>> {
+    match bytes {
+        Cow::Borrowed(bytes) => {
+            match transmute_many::<T, G>(bytes) {
+                Ok(values) => Ok(Cow::Borrowed(values)),
+                Err(Error::Unaligned(_)) => transmute_to_vec::<T, G>(bytes).map(Cow::Owned),
+                Err(e) => Err(e),
+            }
+        }
+        Cow::Owned(bytes) => transmute_vec_or_copy::<T, G>(bytes).map(Cow::Owned),
+    }
+}
+
+/// View the contents of a byte ring buffer as a slice of values, making them
+/// contiguous first.
+///
+/// This moves the bytes around within the buffer if they wrap around its end.
+/// As with any byte buffer, the result may not be aligned for `T`.
+///
+/// The required byte length depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The contiguous data does not have a memory alignment compatible with
+///   `T`. You will have to make a copy anyway, with
+///   [`transmute_to_vec()`](fn.transmute_to_vec.html).
+/// - The data does not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{SingleManyGuard, transmute_vecdeque};
+/// # use std::collections::VecDeque;
+/// let mut input = VecDeque::new();
+/// input.extend(&[0x01, 0x02, 0x03, 0x04]);
+/// input.drain(..2);
+/// input.extend(&[0x05, 0x06]);
+///
+/// assert_eq!(transmute_vecdeque::<i8, SingleManyGuard>(&mut input), Ok(&[0x03, 0x04, 0x05, 0x06][..]));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_vecdeque<T: TriviallyTransmutable, G: Guard>(deque: &mut VecDeque<u8>) -> Result<&[T], Error<'_, u8, T>> {
+    transmute_many::<T, G>(deque.make_contiguous())
+}
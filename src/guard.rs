@@ -42,6 +42,21 @@
 //! # run().unwrap();
 //! ```
 //!
+//! Guards which need runtime state, such as element counts read from a file
+//! header, are values implementing [`RuntimeGuard`](trait.RuntimeGuard.html)
+//! instead. Every `Guard` type is also a `RuntimeGuard`.
+//!
+//! ```
+//! # use safe_transmute::error::GuardError;
+//! # use safe_transmute::guard::{BoundedGuard, RuntimeGuard};
+//! # fn run() -> Result<(), GuardError> {
+//! let max_records = 4;
+//! BoundedGuard::new(1, max_records).check_bytes::<u16>(&[0xAA, 0xAA, 0xBB, 0xBB])?;
+//! # Ok(())
+//! # }
+//! # run().unwrap();
+//! ```
+//!
 //! If the check fails, the resulting [`GuardError`](../type.GuardError.html) value describes why.
 //!
 //! ```
@@ -55,6 +70,10 @@
 //!            }));
 //! ```
 //!
+//! Zero-sized types are rejected by all guards, with
+//! [`ErrorReason::ZeroSizedTarget`](../error/enum.ErrorReason.html#variant.ZeroSizedTarget),
+//! as there is no meaningful amount of them in a byte slice.
+//!
 //! # Note
 //!
 //! Regardless of the chosen strategy, guarded transmutation functions will
@@ -69,14 +88,116 @@ use core::mem::size_of;
 
 /// The trait describes types which define boundary checking strategies.
 /// See the [module-level documentation](index.html) for more details.
-pub trait Guard {
-    /// Check the size of the given byte slice against a particular type.
+///
+/// # Safety
+///
+/// Whenever `check_len::<T>(len)` returns `Ok(count)`, `count * size_of::<T>()`
+/// must not overflow, and must be at most `len`; the same goes for `check()`,
+/// if overridden, and the length of the slice. Functions such as
+/// [`base::transmute_many()`](../base/fn.transmute_many.html) build a slice of
+/// `count` values out of the checked bytes without checking the count again,
+/// so a guard breaking this contract would let them read out of bounds.
+pub unsafe trait Guard {
+    /// Check the given byte length against a particular type, returning the
+    /// number of `T` values it holds.
+    ///
+    /// The returned count may be lower than the number of values which would
+    /// fit in the bytes, in which case the extraneous values are ignored.
+    /// The number of bytes consumed is thus `count * size_of::<T>()`.
+    ///
+    /// # Errors
+    ///
+    /// If the length does not comply with this guard, an error which
+    /// specifies the incompatibility is returned.
+    fn check_len<T>(len: usize) -> Result<usize, GuardError>;
+
+    /// Check the size of the given byte slice against a particular type,
+    /// returning the number of `T` values it holds.
+    ///
+    /// See [`check_len()`](#tymethod.check_len) for details.
     ///
     /// # Errors
     ///
     /// If the slice's size does not comply with this guard, an error
     /// which specifies the incompatibility is returned.
-    fn check<T>(v: &[u8]) -> Result<(), GuardError>;
+    #[inline]
+    fn check<T>(v: &[u8]) -> Result<usize, GuardError> {
+        Self::check_len::<T>(v.len())
+    }
+}
+
+/// The trait describes values which define boundary checking strategies,
+/// possibly depending on runtime state.
+///
+/// All [`Guard`](trait.Guard.html) types implement this trait as well, ignoring the value.
+/// See the [module-level documentation](index.html) for more details.
+///
+/// # Safety
+///
+/// The same contract as for [`Guard`](trait.Guard.html#safety) applies to
+/// the count returned by `check_bytes()`.
+pub unsafe trait RuntimeGuard {
+    /// Check the size of the given byte slice against a particular type,
+    /// returning the number of `T` values it holds.
+    ///
+    /// See [`Guard::check_len()`](trait.Guard.html#tymethod.check_len) for details.
+    ///
+    /// # Errors
+    ///
+    /// If the slice's size does not comply with this guard, an error
+    /// which specifies the incompatibility is returned.
+    fn check_bytes<T>(&self, v: &[u8]) -> Result<usize, GuardError>;
+}
+
+unsafe impl<G: Guard> RuntimeGuard for G {
+    #[inline]
+    fn check_bytes<T>(&self, bytes: &[u8]) -> Result<usize, GuardError> {
+        G::check::<T>(bytes)
+    }
+}
+
+
+/// The error for a zero-sized `T`, of which any amount of bytes would hold
+/// infinitely many values.
+const fn zero_sized_target(len: usize) -> GuardError {
+    GuardError {
+        required: 0,
+        actual: len,
+        reason: ErrorReason::ZeroSizedTarget,
+    }
+}
+
+/// The number of bytes taken up by `count` values of `T`.
+///
+/// This saturates at `usize::MAX` instead of overflowing: as no slice is that
+/// long, checks against the result still fail.
+pub(crate) const fn bytes_for<T>(count: usize) -> usize {
+    count.saturating_mul(size_of::<T>())
+}
+
+/// The bytes of the first `count` values of `T` in the given slice.
+///
+/// # Safety
+///
+/// `count` must have been returned by a guard checking these bytes for `T`.
+#[cfg(feature = "alloc")]
+pub(crate) unsafe fn value_bytes<T>(bytes: &[u8], count: usize) -> &[u8] {
+    bytes.get_unchecked(..count * size_of::<T>())
+}
+
+/// Check the given bytes against the guard `G`, and the resulting number of
+/// `T`s against the given capacity, returning that number.
+pub(crate) fn check_capacity<T, G: Guard>(bytes: &[u8], capacity: usize) -> Result<usize, GuardError> {
+    let len = G::check::<T>(bytes)?;
+    if len > capacity {
+        Err(GuardError {
+            required: bytes_for::<T>(capacity),
+            actual: bytes.len(),
+            reason: ErrorReason::TooManyBytes,
+        })
+    } else {
+        Ok(len)
+    }
 }
 
 
@@ -84,91 +205,347 @@ pub trait Guard {
 /// instance of a type.
 pub struct SingleValueGuard;
 
-impl Guard for SingleValueGuard {
-    fn check<T>(bytes: &[u8]) -> Result<(), GuardError> {
-        if bytes.len() != size_of::<T>() {
+impl SingleValueGuard {
+    /// Equivalent to [`Guard::check()`](trait.Guard.html#method.check), but
+    /// usable in constant contexts.
+    pub const fn check_const<T>(bytes: &[u8]) -> Result<usize, GuardError> {
+        SingleValueGuard::check_len_const::<T>(bytes.len())
+    }
+
+    /// Equivalent to [`Guard::check_len()`](trait.Guard.html#tymethod.check_len),
+    /// but usable in constant contexts.
+    pub const fn check_len_const<T>(len: usize) -> Result<usize, GuardError> {
+        if size_of::<T>() == 0 {
+            Err(zero_sized_target(len))
+        } else if len != size_of::<T>() {
             Err(GuardError {
                 required: size_of::<T>(),
-                actual: bytes.len(),
+                actual: len,
                 reason: ErrorReason::InexactByteCount,
             })
         } else {
-            Ok(())
+            Ok(1)
         }
     }
 }
 
+unsafe impl Guard for SingleValueGuard {
+    fn check_len<T>(len: usize) -> Result<usize, GuardError> {
+        SingleValueGuard::check_len_const::<T>(len)
+    }
+}
+
 
 /// Pedantic guard: The byte slice must have at least enough bytes to fill a single
 /// instance of a type, and should not have extraneous data.
 pub struct PedanticGuard;
 
-impl Guard for PedanticGuard {
-    fn check<T>(bytes: &[u8]) -> Result<(), GuardError> {
-        if bytes.len() < size_of::<T>() {
+impl PedanticGuard {
+    /// Equivalent to [`Guard::check()`](trait.Guard.html#method.check), but
+    /// usable in constant contexts.
+    pub const fn check_const<T>(bytes: &[u8]) -> Result<usize, GuardError> {
+        PedanticGuard::check_len_const::<T>(bytes.len())
+    }
+
+    /// Equivalent to [`Guard::check_len()`](trait.Guard.html#tymethod.check_len),
+    /// but usable in constant contexts.
+    pub const fn check_len_const<T>(len: usize) -> Result<usize, GuardError> {
+        if size_of::<T>() == 0 {
+            Err(zero_sized_target(len))
+        } else if len < size_of::<T>() {
             Err(GuardError {
                 required: size_of::<T>(),
-                actual: bytes.len(),
+                actual: len,
                 reason: ErrorReason::NotEnoughBytes,
             })
-        } else if (size_of::<T>() == 0 && bytes.len() != 0) || (size_of::<T>() != 0 && bytes.len() % size_of::<T>() != 0) {
+        } else if !len.is_multiple_of(size_of::<T>()) {
             Err(GuardError {
                 required: size_of::<T>(),
-                actual: bytes.len(),
+                actual: len,
                 reason: ErrorReason::InexactByteCount,
             })
         } else {
-            Ok(())
+            Ok(len / size_of::<T>())
         }
     }
 }
 
+unsafe impl Guard for PedanticGuard {
+    fn check_len<T>(len: usize) -> Result<usize, GuardError> {
+        PedanticGuard::check_len_const::<T>(len)
+    }
+}
+
 
 /// An all-or-nothing guard: The byte slice should not have extraneous data, but can be
 /// empty, unlike `PedanticGuard`.
 pub struct AllOrNothingGuard;
 
-impl Guard for AllOrNothingGuard {
-    fn check<T>(bytes: &[u8]) -> Result<(), GuardError> {
-        if (size_of::<T>() == 0 && bytes.len() != 0) || (size_of::<T>() != 0 && bytes.len() % size_of::<T>() != 0) {
+impl AllOrNothingGuard {
+    /// Equivalent to [`Guard::check()`](trait.Guard.html#method.check), but
+    /// usable in constant contexts.
+    pub const fn check_const<T>(bytes: &[u8]) -> Result<usize, GuardError> {
+        AllOrNothingGuard::check_len_const::<T>(bytes.len())
+    }
+
+    /// Equivalent to [`Guard::check_len()`](trait.Guard.html#tymethod.check_len),
+    /// but usable in constant contexts.
+    pub const fn check_len_const<T>(len: usize) -> Result<usize, GuardError> {
+        if size_of::<T>() == 0 {
+            Err(zero_sized_target(len))
+        } else if !len.is_multiple_of(size_of::<T>()) {
             Err(GuardError {
                 required: size_of::<T>(),
-                actual: bytes.len(),
+                actual: len,
+                reason: ErrorReason::InexactByteCount,
+            })
+        } else {
+            Ok(len / size_of::<T>())
+        }
+    }
+}
+
+unsafe impl Guard for AllOrNothingGuard {
+    fn check_len<T>(len: usize) -> Result<usize, GuardError> {
+        AllOrNothingGuard::check_len_const::<T>(len)
+    }
+}
+
+
+/// Exact count guard: The byte slice must have exactly enough bytes to fill `N`
+/// instances of a type.
+///
+/// `SingleValueGuard` is equivalent to `ExactCountGuard<1>`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, GuardError};
+/// # use safe_transmute::guard::{ExactCountGuard, Guard};
+/// assert_eq!(ExactCountGuard::<3>::check::<u16>(&[0x00; 6]), Ok(3));
+/// assert_eq!(ExactCountGuard::<3>::check::<u16>(&[0x00; 4]),
+///            Err(GuardError {
+///                required: 6,
+///                actual: 4,
+///                reason: ErrorReason::InexactByteCount,
+///            }));
+/// ```
+pub struct ExactCountGuard<const N: usize>;
+
+impl<const N: usize> ExactCountGuard<N> {
+    /// Equivalent to [`Guard::check()`](trait.Guard.html#method.check), but
+    /// usable in constant contexts.
+    pub const fn check_const<T>(bytes: &[u8]) -> Result<usize, GuardError> {
+        ExactCountGuard::<N>::check_len_const::<T>(bytes.len())
+    }
+
+    /// Equivalent to [`Guard::check_len()`](trait.Guard.html#tymethod.check_len),
+    /// but usable in constant contexts.
+    pub const fn check_len_const<T>(len: usize) -> Result<usize, GuardError> {
+        if size_of::<T>() == 0 {
+            Err(zero_sized_target(len))
+        } else if len != bytes_for::<T>(N) {
+            Err(GuardError {
+                required: bytes_for::<T>(N),
+                actual: len,
                 reason: ErrorReason::InexactByteCount,
             })
         } else {
-            Ok(())
+            Ok(N)
         }
     }
 }
 
+unsafe impl<const N: usize> Guard for ExactCountGuard<N> {
+    fn check_len<T>(len: usize) -> Result<usize, GuardError> {
+        ExactCountGuard::<N>::check_len_const::<T>(len)
+    }
+}
+
+
+/// At-least guard: The byte slice must have at least enough bytes to fill `N`
+/// instances of a type, and extraneous data is ignored.
+///
+/// `SingleManyGuard` is equivalent to `AtLeastGuard<1>`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, GuardError};
+/// # use safe_transmute::guard::{AtLeastGuard, Guard};
+/// assert_eq!(AtLeastGuard::<2>::check::<u16>(&[0x00; 7]), Ok(3));
+/// assert_eq!(AtLeastGuard::<2>::check::<u16>(&[0x00; 3]),
+///            Err(GuardError {
+///                required: 4,
+///                actual: 3,
+///                reason: ErrorReason::NotEnoughBytes,
+///            }));
+/// ```
+pub struct AtLeastGuard<const N: usize>;
+
+impl<const N: usize> AtLeastGuard<N> {
+    /// Equivalent to [`Guard::check()`](trait.Guard.html#method.check), but
+    /// usable in constant contexts.
+    pub const fn check_const<T>(bytes: &[u8]) -> Result<usize, GuardError> {
+        AtLeastGuard::<N>::check_len_const::<T>(bytes.len())
+    }
+
+    /// Equivalent to [`Guard::check_len()`](trait.Guard.html#tymethod.check_len),
+    /// but usable in constant contexts.
+    pub const fn check_len_const<T>(len: usize) -> Result<usize, GuardError> {
+        if size_of::<T>() == 0 {
+            Err(zero_sized_target(len))
+        } else if len < bytes_for::<T>(N) {
+            Err(GuardError {
+                required: bytes_for::<T>(N),
+                actual: len,
+                reason: ErrorReason::NotEnoughBytes,
+            })
+        } else {
+            Ok(len / size_of::<T>())
+        }
+    }
+}
+
+unsafe impl<const N: usize> Guard for AtLeastGuard<N> {
+    fn check_len<T>(len: usize) -> Result<usize, GuardError> {
+        AtLeastGuard::<N>::check_len_const::<T>(len)
+    }
+}
+
 
 /// A single-or-many guard: The byte slice must have at least enough bytes to fill a single
 /// instance of a type, and extraneous data is ignored.
 pub struct SingleManyGuard;
 
-impl Guard for SingleManyGuard {
-    fn check<T>(bytes: &[u8]) -> Result<(), GuardError> {
-        if bytes.len() < size_of::<T>() {
+impl SingleManyGuard {
+    /// Equivalent to [`Guard::check()`](trait.Guard.html#method.check), but
+    /// usable in constant contexts.
+    pub const fn check_const<T>(bytes: &[u8]) -> Result<usize, GuardError> {
+        SingleManyGuard::check_len_const::<T>(bytes.len())
+    }
+
+    /// Equivalent to [`Guard::check_len()`](trait.Guard.html#tymethod.check_len),
+    /// but usable in constant contexts.
+    pub const fn check_len_const<T>(len: usize) -> Result<usize, GuardError> {
+        if size_of::<T>() == 0 {
+            Err(zero_sized_target(len))
+        } else if len < size_of::<T>() {
             Err(GuardError {
                 required: size_of::<T>(),
-                actual: bytes.len(),
+                actual: len,
                 reason: ErrorReason::NotEnoughBytes,
             })
         } else {
-            Ok(())
+            Ok(len / size_of::<T>())
         }
     }
 }
 
+unsafe impl Guard for SingleManyGuard {
+    fn check_len<T>(len: usize) -> Result<usize, GuardError> {
+        SingleManyGuard::check_len_const::<T>(len)
+    }
+}
+
 
 /// Permissive guard: The resulting slice would have as many instances of a type as will
-/// fit, rounded down. Therefore, this guard will never yield an error.
+/// fit, rounded down. Therefore, this guard will never yield an error, unless the type
+/// is zero-sized.
 pub struct PermissiveGuard;
 
-impl Guard for PermissiveGuard {
+impl PermissiveGuard {
+    /// Equivalent to [`Guard::check()`](trait.Guard.html#method.check), but
+    /// usable in constant contexts.
     #[inline]
-    fn check<T>(_: &[u8]) -> Result<(), GuardError> {
-        Ok(())
+    pub const fn check_const<T>(bytes: &[u8]) -> Result<usize, GuardError> {
+        PermissiveGuard::check_len_const::<T>(bytes.len())
+    }
+
+    /// Equivalent to [`Guard::check_len()`](trait.Guard.html#tymethod.check_len),
+    /// but usable in constant contexts.
+    #[inline]
+    pub const fn check_len_const<T>(len: usize) -> Result<usize, GuardError> {
+        if size_of::<T>() == 0 {
+            Err(zero_sized_target(len))
+        } else {
+            Ok(len / size_of::<T>())
+        }
+    }
+}
+
+unsafe impl Guard for PermissiveGuard {
+    #[inline]
+    fn check_len<T>(len: usize) -> Result<usize, GuardError> {
+        PermissiveGuard::check_len_const::<T>(len)
+    }
+}
+
+
+/// Bounded guard: The byte slice must have enough bytes to fill at least `min` instances
+/// of a type, and at most `max` instances. Extraneous bytes after the last full instance
+/// are ignored.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, GuardError};
+/// # use safe_transmute::guard::{BoundedGuard, RuntimeGuard};
+/// let guard = BoundedGuard::new(2, 3);
+///
+/// assert_eq!(guard.check_bytes::<u16>(&[0x00; 5]), Ok(2));
+/// assert_eq!(guard.check_bytes::<u16>(&[0x00; 8]),
+///            Err(GuardError {
+///                required: 6,
+///                actual: 8,
+///                reason: ErrorReason::TooManyBytes,
+///            }));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BoundedGuard {
+    /// The minimum amount of instances.
+    pub min: usize,
+    /// The maximum amount of instances.
+    pub max: usize,
+}
+
+impl BoundedGuard {
+    /// Create a guard accepting between `min` and `max` instances, inclusive.
+    pub fn new(min: usize, max: usize) -> BoundedGuard {
+        BoundedGuard {
+            min,
+            max,
+        }
+    }
+
+    /// Create a guard accepting at most `max` instances, possibly none.
+    pub fn at_most(max: usize) -> BoundedGuard {
+        BoundedGuard::new(0, max)
+    }
+}
+
+unsafe impl RuntimeGuard for BoundedGuard {
+    fn check_bytes<T>(&self, bytes: &[u8]) -> Result<usize, GuardError> {
+        if size_of::<T>() == 0 {
+            return Err(zero_sized_target(bytes.len()));
+        }
+
+        let count = bytes.len() / size_of::<T>();
+
+        if count < self.min {
+            Err(GuardError {
+                required: bytes_for::<T>(self.min),
+                actual: bytes.len(),
+                reason: ErrorReason::NotEnoughBytes,
+            })
+        } else if count > self.max {
+            Err(GuardError {
+                required: bytes_for::<T>(self.max),
+                actual: bytes.len(),
+                reason: ErrorReason::TooManyBytes,
+            })
+        } else {
+            Ok(count)
+        }
     }
 }
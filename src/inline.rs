@@ -0,0 +1,148 @@
+//! Copying transmutation into collections with inline storage.
+//!
+//! The functions in this module copy the values out of a byte slice, without
+//! any memory alignment requirements, into fixed-capacity or small-size
+//! optimised collections, rather than into a heap-allocated `Vec`. This makes
+//! copying conversions available in no-`std` environments without `alloc`,
+//! and keeps small decode results on the stack.
+//!
+//! If the values do not fit in the collection's fixed capacity, a
+//! [`GuardError`](../error/struct.GuardError.html) with the
+//! [`TooManyBytes`](../error/enum.ErrorReason.html#variant.TooManyBytes)
+//! reason is returned.
+//!
+//! Each function requires the feature named after its collection's crate.
+
+
+use self::super::trivial::TriviallyTransmutable;
+use self::super::stream::unaligned_iter;
+#[cfg(any(feature = "heapless", feature = "arrayvec"))]
+use self::super::guard::check_capacity;
+use self::super::guard::Guard;
+use self::super::Error;
+
+
+/// Copy the values of a trivially transmutable type in a byte slice into a
+/// `heapless::Vec` of capacity `N`.
+///
+/// The required byte length of the slice depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// Requires the `heapless` feature.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not comply with the policies of the given guard `G`.
+/// - The data holds more than `N` values.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate heapless;
+/// # extern crate safe_transmute;
+/// # use safe_transmute::inline::transmute_to_heapless;
+/// # use safe_transmute::{SingleManyGuard, Error};
+/// # fn run() -> Result<(), Error<'static, u8, u16>> {
+/// let words: heapless::Vec<u16, 4> = transmute_to_heapless::<_, SingleManyGuard, 4>(&[0x01, 0x00, 0x02, 0x00])?;
+///
+/// assert_eq!(&words[..], &[u16::from_le(0x0001), u16::from_le(0x0002)]);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # run().unwrap();
+/// # }
+/// ```
+#[cfg(feature = "heapless")]
+pub fn transmute_to_heapless<T: TriviallyTransmutable, G: Guard, const N: usize>(bytes: &[u8]) -> Result<::heapless::Vec<T, N>, Error<u8, T>> {
+    let len = check_capacity::<T, G>(bytes, N)?;
+    let mut values = ::heapless::Vec::new();
+    values.extend(unaligned_iter(bytes).take(len));
+    Ok(values)
+}
+
+/// Copy the values of a trivially transmutable type in a byte slice into a
+/// `SmallVec` backed by the array type `A`.
+///
+/// The values are stored inline if they fit in `A`, and on the heap otherwise.
+///
+/// The required byte length of the slice depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// Requires the `smallvec` feature.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate smallvec;
+/// # extern crate safe_transmute;
+/// # use safe_transmute::inline::transmute_to_smallvec;
+/// # use safe_transmute::{SingleManyGuard, Error};
+/// # fn run() -> Result<(), Error<'static, u8, u16>> {
+/// let words = transmute_to_smallvec::<u16, [u16; 4], SingleManyGuard>(&[0x01, 0x00, 0x02, 0x00])?;
+///
+/// assert!(!words.spilled());
+/// assert_eq!(&words[..], &[u16::from_le(0x0001), u16::from_le(0x0002)]);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # run().unwrap();
+/// # }
+/// ```
+#[cfg(feature = "smallvec")]
+pub fn transmute_to_smallvec<T, A, G>(bytes: &[u8]) -> Result<::smallvec::SmallVec<A>, Error<u8, T>>
+    where T: TriviallyTransmutable,
+          A: ::smallvec::Array<Item = T>,
+          G: Guard
+{
+    let len = G::check::<T>(bytes)?;
+    let mut values = ::smallvec::SmallVec::with_capacity(len);
+    values.extend(unaligned_iter(bytes).take(len));
+    Ok(values)
+}
+
+/// Copy the values of a trivially transmutable type in a byte slice into an
+/// `ArrayVec` of capacity `CAP`.
+///
+/// The required byte length of the slice depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// Requires the `arrayvec` feature.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not comply with the policies of the given guard `G`.
+/// - The data holds more than `CAP` values.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate arrayvec;
+/// # extern crate safe_transmute;
+/// # use safe_transmute::inline::transmute_to_arrayvec;
+/// # use safe_transmute::{SingleManyGuard, Error};
+/// # fn run() -> Result<(), Error<'static, u8, u16>> {
+/// let words = transmute_to_arrayvec::<u16, SingleManyGuard, 4>(&[0x01, 0x00, 0x02, 0x00])?;
+///
+/// assert_eq!(&words[..], &[u16::from_le(0x0001), u16::from_le(0x0002)]);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # run().unwrap();
+/// # }
+/// ```
+#[cfg(feature = "arrayvec")]
+pub fn transmute_to_arrayvec<T: TriviallyTransmutable, G: Guard, const CAP: usize>(bytes: &[u8]) -> Result<::arrayvec::ArrayVec<T, CAP>, Error<u8, T>> {
+    let len = check_capacity::<T, G>(bytes, CAP)?;
+    let mut values = ::arrayvec::ArrayVec::new();
+    values.extend(unaligned_iter(bytes).take(len));
+    Ok(values)
+}
@@ -0,0 +1,193 @@
+//! Descriptions of the memory layout of trivially transmutable structs.
+//!
+//! A type implementing [`Layout`](trait.Layout.html) lists the offset, size,
+//! and scalar makeup of each of its fields, which is all a graphics API needs
+//! to know to set up vertex attributes for a buffer uploaded with
+//! [`transmute_to_bytes()`](../to_bytes/fn.transmute_to_bytes.html).
+//!
+//! With the `derive` feature, `#[transmutable(layout)]` makes the
+//! `TriviallyTransmutable` derive implement it too, so that the offset tables
+//! never need to be maintained by hand:
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # mod derived {
+//! # use safe_transmute::layout::{ScalarKind, Layout};
+//! # use safe_transmute::TriviallyTransmutable;
+//! #[derive(TriviallyTransmutable, Clone, Copy)]
+//! #[transmutable(layout)]
+//! #[repr(C)]
+//! struct Vertex {
+//!     position: [f32; 3],
+//!     colour: [u8; 4],
+//! }
+//!
+//! # pub fn main() {
+//! let colour = &Vertex::FIELDS[1];
+//!
+//! assert_eq!(colour.name, "colour");
+//! assert_eq!(colour.offset, 12);
+//! assert_eq!((colour.kind, colour.count), (ScalarKind::U8, 4));
+//! # }
+//! # }
+//! # fn main() {
+//! # #[cfg(feature = "derive")]
+//! # derived::main();
+//! # }
+//! ```
+//!
+//! Types which must sit on stricter boundaries than their fields need, like
+//! DMA descriptors, can have that asserted at compile time with
+//! `#[transmutable(align = N)]`, so that losing their `repr(align)` does not go
+//! unnoticed:
+//!
+#![cfg_attr(feature = "derive",
+            doc = r#"```compile_fail,E0080
+# use safe_transmute::TriviallyTransmutable;
+#[derive(TriviallyTransmutable, Clone, Copy)]
+#[transmutable(align = 16)]
+#[repr(C)]
+struct Descriptor {
+    address: u64,
+    len: u32,
+    flags: u32,
+}
+# fn main() {}
+```"#)]
+//!
+//! Likewise, `#[transmutable(fields)]` generates accessors of single fields
+//! out of the bytes of a whole struct, so that only the fields needed out of
+//! a large record are read:
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # mod derived {
+//! # use safe_transmute::{TriviallyTransmutable, transmute_to_bytes};
+//! #[derive(TriviallyTransmutable, Clone, Copy)]
+//! #[transmutable(fields)]
+//! #[repr(C)]
+//! struct Record {
+//!     id: u64,
+//!     payload: [u8; 4096],
+//! }
+//!
+//! # pub fn main() {
+//! # let record = Record { id: 7, payload: [0; 4096] };
+//! let bytes = transmute_to_bytes(std::slice::from_ref(&record));
+//!
+//! assert_eq!(Record::id_bytes(bytes), Ok(&7u64.to_ne_bytes()[..]));
+//! assert_eq!(Record::id_ref(bytes), Ok(&7));
+//! # }
+//! # }
+//! # fn main() {
+//! # #[cfg(feature = "derive")]
+//! # derived::main();
+//! # }
+//! ```
+
+
+use self::super::guard::{SingleManyGuard, Guard};
+use self::super::trivial::TriviallyTransmutable;
+use self::super::error::GuardError;
+use core::num::Wrapping;
+
+#[doc(hidden)]
+pub use core::mem::{offset_of, align_of, size_of};
+#[doc(hidden)]
+pub use core::result::Result;
+
+
+/// The kind of scalar a field is made of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScalarKind {
+    /// `u8`
+    U8,
+    /// `i8`
+    I8,
+    /// `u16`
+    U16,
+    /// `i16`
+    I16,
+    /// `u32`
+    U32,
+    /// `i32`
+    I32,
+    /// `u64`
+    U64,
+    /// `i64`
+    I64,
+    /// `f32`
+    F32,
+    /// `f64`
+    F64,
+}
+
+/// Type made of a number of scalars of the same kind, laid out one after
+/// another.
+///
+/// This is implemented for the primitive integers and floats of fixed width,
+/// and for arrays of those, so `[[f32; 4]; 4]` is sixteen `f32`s. Implement it
+/// for other field types, like the vectors of a maths library, to use them in
+/// a derived [`Layout`](trait.Layout.html).
+pub trait Scalars {
+    /// The kind of every scalar.
+    const KIND: ScalarKind;
+    /// How many scalars there are.
+    const COUNT: usize;
+}
+
+macro_rules! scalars {
+    ($($ty:ident: $kind:ident)*) => {
+        $(
+            impl Scalars for $ty {
+                const KIND: ScalarKind = ScalarKind::$kind;
+                const COUNT: usize = 1;
+            }
+        )*
+    };
+}
+
+scalars!(u8: U8 i8: I8 u16: U16 i16: I16 u32: U32 i32: I32 u64: U64 i64: I64 f32: F32 f64: F64);
+
+impl<T: Scalars, const N: usize> Scalars for [T; N] {
+    const KIND: ScalarKind = T::KIND;
+    const COUNT: usize = T::COUNT * N;
+}
+
+impl<T: Scalars> Scalars for Wrapping<T> {
+    const KIND: ScalarKind = T::KIND;
+    const COUNT: usize = T::COUNT;
+}
+
+
+/// Where a single field of a struct is, and what it is made of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldLayout {
+    /// The name of the field, or its index for tuple structs.
+    pub name: &'static str,
+    /// The offset of the field from the start of the struct, in bytes.
+    pub offset: usize,
+    /// The size of the field, in bytes.
+    pub size: usize,
+    /// The kind of scalars the field is made of.
+    pub kind: ScalarKind,
+    /// How many scalars the field is made of.
+    pub count: usize,
+}
+
+/// Trivially transmutable type with a known field layout.
+///
+/// The stride of a buffer of these is `size_of::<Self>()`.
+pub trait Layout: TriviallyTransmutable {
+    /// The layout of every field, in declaration order.
+    const FIELDS: &'static [FieldLayout];
+}
+
+
+/// The `size` bytes at `offset` in the bytes of a whole `S`, for the field
+/// accessors generated by `#[transmutable(fields)]`.
+#[doc(hidden)]
+pub fn field_bytes<S>(bytes: &[u8], offset: usize, size: usize) -> Result<&[u8], GuardError> {
+    SingleManyGuard::check::<S>(bytes)?;
+    Ok(&bytes[offset..offset + size])
+}
@@ -21,8 +21,76 @@
 //!   [`base`](base/index.html), but still do not prevent unaligned memory access.
 //! - [`to_bytes`](to_bytes/index.html) enables the opposite operation of
 //!   reintepreting values as bytes.
+//! - The [`endian`](endian/index.html) module converts values between byte
+//!   orders, and provides primitives stored in a fixed byte order, for
+//!   describing cross-endian data formats.
+//! - The [`checked`](checked/index.html) module transmutes into types which
+//!   are valid for only some bit patterns, like `char`, validating every value
+//!   up front or as it is read.
+//! - The [`float`](float/index.html) module views bytes as floats, optionally
+//!   making signaling NaNs quiet.
 //! - The [`bool`](bool/index.html) module ensures safe transmutation of bytes
 //!   to boolean values.
+//! - The [`text`](text/index.html) module ensures safe transmutation of bytes
+//!   to strings.
+//! - The [`rows`](rows/index.html) module views bytes as two-dimensional data,
+//!   like images or matrices.
+//! - The [`audio`](audio/index.html) module views bytes as PCM audio samples
+//!   in a given byte order, and as interleaved frames of channels.
+//! - The [`framing`](framing/index.html) module iterates over records made of
+//!   a fixed header followed by a payload of the length it names, and views
+//!   fixed-size pages, each starting with a header.
+//! - The [`aligned`](aligned/index.html) module holds values, like byte
+//!   arrays, aligned in memory as another type, for transmuting into it.
+//! - The [`owned`](owned/index.html) module provides containers which take
+//!   ownership of byte buffers and view them as slices of another type, and
+//!   byte buffers allocated with a chosen memory alignment.
+//! - The [`shared`](shared/index.html) module transmutes reference-counted
+//!   byte slices (`Rc<[u8]>` and `Arc<[u8]>`), reusing their allocation
+//!   whenever possible.
+//! - The [`stream`](stream/index.html) module copies values out of byte data
+//!   one at a time, without memory alignment requirements, including from
+//!   streams of arbitrarily split byte chunks.
+//! - The [`fs`](fs/index.html) module reads whole files into vectors of
+//!   values, and writes slices of values into files, with the `std` feature.
+//! - The [`async_io`](async_io/index.html) module reads and writes values
+//!   through asynchronous IO, with the `async` feature.
+//! - The [`mmap`](mmap/index.html) module views memory-mapped files as slices
+//!   of values, with the `mmap` feature.
+//! - The [`typed_bytes`](typed_bytes/index.html) module views buffers of the
+//!   `bytes` crate as slices of values, with the `bytes` feature.
+//! - The [`typed_ndarray`](typed_ndarray/index.html) module views byte slices
+//!   as n-dimensional arrays of the `ndarray` crate, with the `ndarray`
+//!   feature.
+//! - The [`pixels`](pixels/index.html) module views byte slices as pixels of
+//!   the `image` crate, with the `image` feature.
+//! - The [`py_buffer`](py_buffer/index.html) module views and copies Python
+//!   buffers, like numpy arrays, with the `pyo3` feature.
+//! - The [`wasm`](wasm/index.html) module copies JavaScript typed arrays and
+//!   array buffers into vectors of values, with the `wasm` feature.
+//! - The [`inline`](inline/index.html) module copies values into collections
+//!   with inline storage, like `heapless::Vec`, `SmallVec`, or `ArrayVec`,
+//!   with the respective features.
+//! - The [`uninit`](uninit/index.html) module transmutes into possibly
+//!   uninitialized values and storage.
+//! - The [`atomic`](atomic/index.html) module views mutable byte slices as
+//!   slices of atomic integers.
+//! - The [`volatile`](volatile/index.html) module reads and writes single
+//!   values with volatile operations, like for memory-mapped registers.
+//! - The [`layout`](layout/index.html) module describes the field offsets and
+//!   scalar kinds of structs, like for setting up vertex attributes, with a
+//!   derive in the `derive` feature.
+//! - The [`ffi`](ffi/index.html) module views buffers handed over from C, as
+//!   a pointer and a byte length, as slices of values.
+//! - The [`transparent`](transparent/index.html) module converts between
+//!   `repr(transparent)` newtypes and the types they wrap, with a derive in
+//!   the `derive` feature.
+//! - The [`compat`](compat/index.html) module adapts types implementing the
+//!   traits of other transmutation crates, like `zerocopy` or `bytemuck`,
+//!   with the respective features.
+//! - The [`ext`](ext/index.html) module provides extension traits for calling
+//!   the transmutation functions with method syntax, and the
+//!   [`prelude`](prelude/index.html) imports them all at once.
 //! - At the root of this crate, there are transmutation functions with enough
 //!   checks to be considered safe to use in any circumstance. The operation may
 //!   still arbitrarily return (recoverable) errors due to unaligned data or
@@ -32,7 +100,8 @@
 //!
 //! This crate can be used in a no-`std` environment by disabling the `std`
 //! feature through specifying `default-features = false` on import.
-//! However, `std` is only used for integration with `std::error::Error`.
+//! However, `std` is only used for integration with `std::io`: the error
+//! types implement `core::error::Error` either way.
 //!
 //! Note, though, that functions operating on items from `alloc` will also be disabled by this.
 //! If your no-`std` environment has an `alloc` implementation, you will have to reenable them by using `features = ["alloc"]`.
@@ -154,6 +223,7 @@
 
 
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
 
 
 #[cfg(feature = "std")]
@@ -161,35 +231,187 @@ extern crate core;
 #[cfg(feature = "alloc")]
 #[doc(hidden)]
 pub extern crate alloc;
+#[doc(hidden)]
+pub use core::mem as __mem;
+#[cfg(feature = "async")]
+extern crate futures_core;
+#[cfg(feature = "async")]
+extern crate futures_sink;
+#[cfg(feature = "async")]
+extern crate futures_io;
+#[cfg(feature = "mmap")]
+extern crate memmap2;
+#[cfg(feature = "bytes")]
+extern crate bytes;
+#[cfg(feature = "heapless")]
+extern crate heapless;
+#[cfg(feature = "smallvec")]
+extern crate smallvec;
+#[cfg(feature = "arrayvec")]
+extern crate arrayvec;
+#[cfg(feature = "zerocopy")]
+extern crate zerocopy;
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
+#[cfg(feature = "glam")]
+extern crate glam;
+#[cfg(feature = "mint")]
+extern crate mint;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+#[cfg(feature = "fixed")]
+extern crate fixed;
+#[cfg(feature = "uuid")]
+extern crate uuid;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "defmt")]
+extern crate defmt;
+#[cfg(feature = "half")]
+extern crate half;
+#[cfg(feature = "ndarray")]
+extern crate ndarray;
+#[cfg(feature = "image")]
+extern crate image;
+#[cfg(feature = "pyo3")]
+extern crate pyo3;
+#[cfg(feature = "wasm")]
+extern crate js_sys;
+#[cfg(feature = "derive")]
+extern crate safe_transmute_derive;
 
 mod full;
+#[cfg(any(feature = "simd", feature = "portable-simd"))]
+mod simd;
+#[cfg(any(feature = "glam", feature = "mint", feature = "nalgebra", feature = "fixed"))]
+mod math;
 
 pub mod base;
 pub mod bool;
+pub mod text;
+pub mod rows;
+pub mod audio;
+pub mod framing;
 pub mod util;
 pub mod align;
+pub mod aligned;
 pub mod error;
 pub mod guard;
 pub mod trivial;
+pub mod checked;
+pub mod float;
+pub mod ext;
+pub mod prelude;
+pub mod stream;
+pub mod endian;
+pub mod transparent;
+pub mod layout;
+pub mod atomic;
+pub mod volatile;
+pub mod ffi;
+pub mod uninit;
 pub mod to_bytes;
 pub mod migration;
+#[cfg(feature = "alloc")]
+pub mod owned;
+#[cfg(feature = "alloc")]
+pub mod shared;
+#[cfg(feature = "std")]
+pub mod fs;
+#[cfg(feature = "async")]
+pub mod async_io;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+#[cfg(feature = "bytes")]
+pub mod typed_bytes;
+#[cfg(feature = "ndarray")]
+pub mod typed_ndarray;
+#[cfg(feature = "image")]
+pub mod pixels;
+#[cfg(feature = "pyo3")]
+pub mod py_buffer;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(any(feature = "heapless", feature = "smallvec", feature = "arrayvec"))]
+pub mod inline;
+#[cfg(any(feature = "zerocopy", feature = "bytemuck"))]
+pub mod compat;
 
-pub use self::full::{transmute_many_permissive_mut, transmute_many_pedantic_mut, transmute_many_permissive, transmute_many_pedantic, transmute_one_pedantic,
-                     transmute_many, transmute_many_mut, transmute_one};
+pub use self::full::{transmute_many_permissive_mut, transmute_many_pedantic_mut, transmute_one_pedantic_const, transmute_one_ref_from_array,
+                     transmute_many_permissive, transmute_one_from_array, transmute_one_ref_mut, transmute_one_ref, transmute_many_mut_with, transmute_one_unaligned, transmute_many_pedantic,
+                     transmute_one_pedantic, transmute_one_const, transmute_many_with, transmute_value, transmute_ref_mut, transmute_ref, transmute_many_mut, transmute_many, transmute_one};
 #[cfg(feature = "alloc")]
-pub use self::full::transmute_vec;
+pub use self::full::{transmute_vec_grouped, transmute_vecdeque, transmute_to_vec, transmute_cow, transmute_box, transmute_vec};
+#[cfg(feature = "alloc")]
+pub use self::shared::{transmute_arc, transmute_rc, SharedSlice};
+#[cfg(feature = "alloc")]
+pub use self::owned::{OwnedTransmuted, AlignedBytes};
 
 
-pub use self::guard::{SingleValueGuard, PermissiveGuard, SingleManyGuard, PedanticGuard, Guard};
-pub use self::error::{UnalignedError, ErrorReason, GuardError, Error};
+pub use self::guard::{SingleValueGuard, PermissiveGuard, SingleManyGuard, ExactCountGuard, PedanticGuard, RuntimeGuard, AtLeastGuard, BoundedGuard, Guard};
+pub use self::error::{UnalignedError, TransmuteError, SequenceError, ErrorReason, Suggestion, GuardError, ErrorKind, Error};
 #[cfg(feature = "alloc")]
-pub use self::error::IncompatibleVecTargetError;
+pub use self::error::{IncompatibleVecTargetError, InvalidUtf8Error};
+#[cfg(feature = "std")]
+pub use self::error::LoadError;
 pub use self::trivial::{TriviallyTransmutable, align_to_mut, align_to};
+pub use self::checked::{CheckedTransmutable, transmute_many_checked, transmute_one_checked, CheckedSlice};
+pub use self::float::{transmute_many_f32, transmute_many_f64};
+pub use self::ext::{AsByteSliceExt, TransmuteView};
+#[cfg(feature = "alloc")]
+pub use self::ext::VecTransmuteExt;
+pub use self::transparent::TransparentWrapper;
+pub use self::atomic::{AtomicTransmutable, transmute_many_atomic};
+pub use self::volatile::{write_volatile_one, read_volatile_one};
+pub use self::aligned::{transmute_aligned, Aligned};
+pub use self::ffi::{slice_from_c_mut, slice_from_c};
+pub use self::uninit::{transmute_many_into_uninit, transmute_many_uninit};
+#[cfg(feature = "derive")]
+pub use safe_transmute_derive::{TriviallyTransmutable, TransparentWrapper};
+pub use self::stream::{StreamingTransmuter, UnalignedIter, unaligned_iter};
+pub use self::framing::{PageView, Frames};
+pub use self::endian::{transmute_one_endian, swap_bytes_in_place, swap_bytes_endian};
+#[cfg(feature = "alloc")]
+pub use self::endian::{transmute_many_endian, transmute_many_le, transmute_many_be};
+#[cfg(feature = "std")]
+pub use self::stream::{TransmuteReader, TransmuteWriter, write_transmuted, read_exact_typed};
+#[cfg(feature = "std")]
+pub use self::fs::{save_typed_atomic, load_typed, save_typed};
+#[cfg(feature = "async")]
+pub use self::async_io::{AsyncTransmuteReader, AsyncTransmuteWriter};
+#[cfg(feature = "mmap")]
+pub use self::mmap::{TypedMmapMut, TypedMmap};
+#[cfg(feature = "bytes")]
+pub use self::typed_bytes::{transmute_bytes_mut, TypedBytes};
+#[cfg(feature = "ndarray")]
+pub use self::typed_ndarray::{transmute_to_array_view_mut, transmute_to_array_view, transmute_to_array};
+#[cfg(feature = "image")]
+pub use self::pixels::{transmute_pixels_endian, transmute_pixels_mut, transmute_pixels};
+#[cfg(feature = "pyo3")]
+pub use self::py_buffer::{transmute_py_buffer_to_vec, transmute_py_buffer};
+#[cfg(feature = "wasm")]
+pub use self::wasm::{transmute_array_buffer, transmute_uint8_array};
+#[cfg(feature = "heapless")]
+pub use self::inline::transmute_to_heapless;
+#[cfg(feature = "smallvec")]
+pub use self::inline::transmute_to_smallvec;
+#[cfg(feature = "arrayvec")]
+pub use self::inline::transmute_to_arrayvec;
 
 pub use self::to_bytes::{transmute_one_to_bytes_mut, transmute_one_to_bytes, transmute_to_bytes_mut, transmute_to_bytes};
 #[cfg(feature = "alloc")]
 pub use self::to_bytes::transmute_to_bytes_vec;
 
 #[cfg(feature = "alloc")]
-pub use self::bool::{transmute_bool_vec_permissive, transmute_bool_vec_pedantic};
-pub use self::bool::{transmute_bool_permissive, transmute_bool_pedantic};
+pub use self::bool::{transmute_bool_vec_permissive, transmute_bool_vec_pedantic, transmute_bool_vec};
+pub use self::bool::{transmute_bool_permissive, transmute_bool_pedantic, transmute_bool_mut, transmute_bool};
+
+#[cfg(feature = "alloc")]
+pub use self::text::{transmute_vec_to_string, decode_utf16};
+pub use self::text::{transmute_bytes_to_str, transmute_to_utf16};
+
+pub use self::rows::{as_chunks_exact_mut, transmute_rows_mut, as_chunks_exact, transmute_rows, try_group_mut, as_chunks_mut, flatten_mut, StridedView, try_group, as_chunks,
+                     flatten};
+pub use self::audio::{transmute_frames_endian, transmute_samples_i16, transmute_samples_f32, transmute_frames_mut, transmute_samples, transmute_frames};
+#[cfg(feature = "alloc")]
+pub use self::audio::decode_samples;
@@ -0,0 +1,130 @@
+//! Implementations of `TriviallyTransmutable` for the vector, matrix, and
+//! quaternion types of math crates, with the `glam`, `mint`, and `nalgebra`
+//! features, and for fixed-point numbers, with the `fixed` feature.
+//!
+//! Only types without padding are covered: `glam`'s 16-byte-aligned
+//! three-element types (`Vec3A`, `Mat3A`, `Affine3A`), and `Affine2`, can
+//! not be safely viewed as bytes. For `nalgebra`, only statically-sized
+//! matrices, points, and quaternions are covered, as the others own heap
+//! storage.
+
+
+use self::super::trivial::TriviallyTransmutable;
+
+
+#[cfg(feature = "glam")]
+mod glam_impls {
+    use glam::{Vec2, Vec3, Vec4, Mat2, Mat3, Mat4, Quat, DVec2, DVec3, DVec4, DMat2, DMat3, DMat4, DQuat, DAffine2, DAffine3, I8Vec2, I8Vec3, I8Vec4,
+               U8Vec2, U8Vec3, U8Vec4, I16Vec2, I16Vec3, I16Vec4, U16Vec2, U16Vec3, U16Vec4, IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, I64Vec2,
+               I64Vec3, I64Vec4, U64Vec2, U64Vec3, U64Vec4};
+    use self::super::TriviallyTransmutable;
+
+    unsafe impl TriviallyTransmutable for Vec2 {}
+    unsafe impl TriviallyTransmutable for Vec3 {}
+    unsafe impl TriviallyTransmutable for Vec4 {}
+    unsafe impl TriviallyTransmutable for Mat2 {}
+    unsafe impl TriviallyTransmutable for Mat3 {}
+    unsafe impl TriviallyTransmutable for Mat4 {}
+    unsafe impl TriviallyTransmutable for Quat {}
+    unsafe impl TriviallyTransmutable for DVec2 {}
+    unsafe impl TriviallyTransmutable for DVec3 {}
+    unsafe impl TriviallyTransmutable for DVec4 {}
+    unsafe impl TriviallyTransmutable for DMat2 {}
+    unsafe impl TriviallyTransmutable for DMat3 {}
+    unsafe impl TriviallyTransmutable for DMat4 {}
+    unsafe impl TriviallyTransmutable for DQuat {}
+    unsafe impl TriviallyTransmutable for DAffine2 {}
+    unsafe impl TriviallyTransmutable for DAffine3 {}
+    unsafe impl TriviallyTransmutable for I8Vec2 {}
+    unsafe impl TriviallyTransmutable for I8Vec3 {}
+    unsafe impl TriviallyTransmutable for I8Vec4 {}
+    unsafe impl TriviallyTransmutable for U8Vec2 {}
+    unsafe impl TriviallyTransmutable for U8Vec3 {}
+    unsafe impl TriviallyTransmutable for U8Vec4 {}
+    unsafe impl TriviallyTransmutable for I16Vec2 {}
+    unsafe impl TriviallyTransmutable for I16Vec3 {}
+    unsafe impl TriviallyTransmutable for I16Vec4 {}
+    unsafe impl TriviallyTransmutable for U16Vec2 {}
+    unsafe impl TriviallyTransmutable for U16Vec3 {}
+    unsafe impl TriviallyTransmutable for U16Vec4 {}
+    unsafe impl TriviallyTransmutable for IVec2 {}
+    unsafe impl TriviallyTransmutable for IVec3 {}
+    unsafe impl TriviallyTransmutable for IVec4 {}
+    unsafe impl TriviallyTransmutable for UVec2 {}
+    unsafe impl TriviallyTransmutable for UVec3 {}
+    unsafe impl TriviallyTransmutable for UVec4 {}
+    unsafe impl TriviallyTransmutable for I64Vec2 {}
+    unsafe impl TriviallyTransmutable for I64Vec3 {}
+    unsafe impl TriviallyTransmutable for I64Vec4 {}
+    unsafe impl TriviallyTransmutable for U64Vec2 {}
+    unsafe impl TriviallyTransmutable for U64Vec3 {}
+    unsafe impl TriviallyTransmutable for U64Vec4 {}
+}
+
+
+#[cfg(feature = "mint")]
+mod mint_impls {
+    use mint::{Vector2, Vector3, Vector4, Point2, Point3, Quaternion, RowMatrix2, RowMatrix2x3, RowMatrix2x4, RowMatrix3x2, RowMatrix3, RowMatrix3x4,
+               RowMatrix4x2, RowMatrix4x3, RowMatrix4, ColumnMatrix2, ColumnMatrix2x3, ColumnMatrix2x4, ColumnMatrix3x2, ColumnMatrix3,
+               ColumnMatrix3x4, ColumnMatrix4x2, ColumnMatrix4x3, ColumnMatrix4};
+    use self::super::TriviallyTransmutable;
+
+    // All of these are repr(C) structs whose fields are all either T, or another one of these over T
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for Vector2<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for Vector3<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for Vector4<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for Point2<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for Point3<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for Quaternion<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for RowMatrix2<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for RowMatrix2x3<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for RowMatrix2x4<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for RowMatrix3x2<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for RowMatrix3<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for RowMatrix3x4<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for RowMatrix4x2<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for RowMatrix4x3<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for RowMatrix4<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for ColumnMatrix2<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for ColumnMatrix2x3<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for ColumnMatrix2x4<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for ColumnMatrix3x2<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for ColumnMatrix3<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for ColumnMatrix3x4<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for ColumnMatrix4x2<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for ColumnMatrix4x3<T> {}
+    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for ColumnMatrix4<T> {}
+}
+
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impls {
+    use nalgebra::{ArrayStorage, Quaternion, Matrix, Scalar, Point, Const};
+    use self::super::TriviallyTransmutable;
+
+    // A repr(C) ArrayStorage, itself a [[T; R]; C], next to a PhantomData
+    unsafe impl<T: Scalar + TriviallyTransmutable, const R: usize, const C: usize> TriviallyTransmutable
+        for Matrix<T, Const<R>, Const<C>, ArrayStorage<T, R, C>> {
+    }
+    // A repr(C) statically-sized column vector
+    unsafe impl<T: Scalar + TriviallyTransmutable, const D: usize> TriviallyTransmutable for Point<T, D> {}
+    // A repr(C) four-element column vector
+    unsafe impl<T: Scalar + TriviallyTransmutable> TriviallyTransmutable for Quaternion<T> {}
+}
+
+
+#[cfg(feature = "fixed")]
+mod fixed_impls {
+    use fixed::{FixedU8, FixedI8, FixedU16, FixedI16, FixedU32, FixedI32, FixedU64, FixedI64};
+    use self::super::TriviallyTransmutable;
+
+    // repr(transparent) over the integer of the same width, next to a PhantomData
+    unsafe impl<Frac> TriviallyTransmutable for FixedU8<Frac> {}
+    unsafe impl<Frac> TriviallyTransmutable for FixedI8<Frac> {}
+    unsafe impl<Frac> TriviallyTransmutable for FixedU16<Frac> {}
+    unsafe impl<Frac> TriviallyTransmutable for FixedI16<Frac> {}
+    unsafe impl<Frac> TriviallyTransmutable for FixedU32<Frac> {}
+    unsafe impl<Frac> TriviallyTransmutable for FixedI32<Frac> {}
+    unsafe impl<Frac> TriviallyTransmutable for FixedU64<Frac> {}
+    unsafe impl<Frac> TriviallyTransmutable for FixedI64<Frac> {}
+}
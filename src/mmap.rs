@@ -0,0 +1,216 @@
+//! Typed views over memory-mapped files.
+//!
+//! The containers in this module take ownership of a memory map from the
+//! `memmap2` crate, and view its contents as a slice of a trivially
+//! transmutable type for as long as they live. The map is validated against
+//! the guard and for memory alignment once, upon construction.
+//!
+//! As memory maps start on a page boundary, the alignment check only ever
+//! fails for types with an alignment larger than the page size.
+//!
+//! Does not exist without the `mmap` feature.
+
+
+use self::super::trivial::TriviallyTransmutable;
+use self::super::align::check_alignment;
+use std::io::{self, ErrorKind};
+use self::super::guard::Guard;
+use memmap2::{MmapMut, Mmap};
+use core::ops::{DerefMut, Deref};
+use core::marker::PhantomData;
+use std::fs::OpenOptions;
+use self::super::Error;
+use std::path::Path;
+use std::fs::File;
+use core::slice;
+use core::fmt;
+
+
+/// Check the given mapped bytes against the guard `G` and `T`'s alignment,
+/// returning the number of `T`s they hold.
+fn check_map<T, G: Guard>(bytes: &[u8]) -> Result<usize, Error<'static, u8, T>> {
+    let len = G::check::<T>(bytes)?;
+    check_alignment::<_, T>(bytes).map_err(|e| Error::from(e).without_src())?;
+    Ok(len)
+}
+
+fn invalid_data<T>(e: Error<u8, T>) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, e.to_string())
+}
+
+
+/// A read-only memory map viewed as a slice of a trivially transmutable type.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use safe_transmute::mmap::TypedMmap;
+/// # use safe_transmute::PedanticGuard;
+/// # fn run() -> std::io::Result<()> {
+/// let column = unsafe { TypedMmap::<f64>::open::<PedanticGuard, _>("prices.f64")? };
+/// let total: f64 = column.iter().sum();
+/// # Ok(())
+/// # }
+/// ```
+pub struct TypedMmap<T> {
+    map: Mmap,
+    len: usize,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: TriviallyTransmutable> TypedMmap<T> {
+    /// View the given memory map as a slice of `T`s.
+    ///
+    /// The required byte length of the map depends on the chosen boundary guard.
+    /// Please see the [Guard API](../guard/index.html).
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in one of the following situations:
+    ///
+    /// - The map does not have a memory alignment compatible with `T`.
+    /// - The data does not comply with the policies of the given guard `G`.
+    pub fn new<G: Guard>(map: Mmap) -> Result<TypedMmap<T>, Error<'static, u8, T>> {
+        let len = check_map::<T, G>(&map)?;
+        Ok(TypedMmap {
+            map: map,
+            len: len,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Map the file at the given path read-only, viewing it as a slice of `T`s.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified, including by other processes, for as
+    /// long as the map is alive. See
+    /// [`Mmap::map()`](https://docs.rs/memmap2/0.9/memmap2/struct.Mmap.html#method.map).
+    ///
+    /// # Errors
+    ///
+    /// Any error opening or mapping the file is passed through. If the data
+    /// fails the checks of [`new()`](#method.new), an error of kind `InvalidData`
+    /// is returned.
+    pub unsafe fn open<G: Guard, P: AsRef<Path>>(path: P) -> io::Result<TypedMmap<T>> {
+        let map = Mmap::map(&File::open(path)?)?;
+        TypedMmap::new::<G>(map).map_err(invalid_data)
+    }
+
+    /// Unwrap the underlying memory map.
+    pub fn into_inner(self) -> Mmap {
+        self.map
+    }
+}
+
+impl<T: TriviallyTransmutable> Deref for TypedMmap<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        // The map is aligned for `T`, and contains at least `len` values
+        unsafe { slice::from_raw_parts(self.map.as_ptr() as *const T, self.len) }
+    }
+}
+
+impl<T: TriviallyTransmutable + fmt::Debug> fmt::Debug for TypedMmap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+
+/// A writable memory map viewed as a mutable slice of a trivially transmutable type.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use safe_transmute::mmap::TypedMmapMut;
+/// # use safe_transmute::PedanticGuard;
+/// # fn run() -> std::io::Result<()> {
+/// let mut counters = unsafe { TypedMmapMut::<u64>::open::<PedanticGuard, _>("counters.u64")? };
+/// counters[0] += 1;
+/// counters.flush()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct TypedMmapMut<T> {
+    map: MmapMut,
+    len: usize,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: TriviallyTransmutable> TypedMmapMut<T> {
+    /// View the given writable memory map as a slice of `T`s.
+    ///
+    /// The required byte length of the map depends on the chosen boundary guard.
+    /// Please see the [Guard API](../guard/index.html).
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in one of the following situations:
+    ///
+    /// - The map does not have a memory alignment compatible with `T`.
+    /// - The data does not comply with the policies of the given guard `G`.
+    pub fn new<G: Guard>(map: MmapMut) -> Result<TypedMmapMut<T>, Error<'static, u8, T>> {
+        let len = check_map::<T, G>(&map)?;
+        Ok(TypedMmapMut {
+            map: map,
+            len: len,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Map the file at the given path for reading and writing, viewing it as a slice of `T`s.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified by other means, including by other
+    /// processes, for as long as the map is alive. See
+    /// [`MmapMut::map_mut()`](https://docs.rs/memmap2/0.9/memmap2/struct.MmapMut.html#method.map_mut).
+    ///
+    /// # Errors
+    ///
+    /// Any error opening or mapping the file is passed through. If the data
+    /// fails the checks of [`new()`](#method.new), an error of kind `InvalidData`
+    /// is returned.
+    pub unsafe fn open<G: Guard, P: AsRef<Path>>(path: P) -> io::Result<TypedMmapMut<T>> {
+        let map = MmapMut::map_mut(&OpenOptions::new().read(true).write(true).open(path)?)?;
+        TypedMmapMut::new::<G>(map).map_err(invalid_data)
+    }
+
+    /// Flush outstanding modifications to the file.
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by the underlying map is passed through.
+    pub fn flush(&self) -> io::Result<()> {
+        self.map.flush()
+    }
+
+    /// Unwrap the underlying memory map.
+    pub fn into_inner(self) -> MmapMut {
+        self.map
+    }
+}
+
+impl<T: TriviallyTransmutable> Deref for TypedMmapMut<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        // The map is aligned for `T`, and contains at least `len` values
+        unsafe { slice::from_raw_parts(self.map.as_ptr() as *const T, self.len) }
+    }
+}
+
+impl<T: TriviallyTransmutable> DerefMut for TypedMmapMut<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        // The map is aligned for `T`, and contains at least `len` values
+        unsafe { slice::from_raw_parts_mut(self.map.as_mut_ptr() as *mut T, self.len) }
+    }
+}
+
+impl<T: TriviallyTransmutable + fmt::Debug> fmt::Debug for TypedMmapMut<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
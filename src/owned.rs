@@ -0,0 +1,276 @@
+//! Owning containers of transmuted data.
+//!
+//! Does not exist without the `alloc` feature.
+
+
+use self::super::trivial::TriviallyTransmutable;
+use self::super::align::check_alignment;
+use self::super::full::transmute_to_vec;
+use self::super::guard::Guard;
+use alloc::alloc::{alloc_zeroed, handle_alloc_error, dealloc, Layout};
+use core::mem::align_of;
+use core::ops::{DerefMut, Deref};
+use self::super::Error;
+use alloc::boxed::Box;
+use core::ptr::NonNull;
+use alloc::vec::Vec;
+use core::slice;
+use core::fmt;
+
+
+/// A byte buffer owned as a slice of a trivially transmutable type.
+///
+/// The buffer is validated against the guard once, upon construction, after
+/// which the container dereferences to `[T]` for as long as it lives.
+///
+/// If the given bytes happen to be properly aligned for `T`, they are kept
+/// as-is and no copy is made. Otherwise, the values are copied into new,
+/// properly aligned storage, so construction never fails due to memory
+/// alignment constraints.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{OwnedTransmuted, PedanticGuard};
+/// let bytes = vec![0x01, 0x00, 0x02, 0x00];
+/// let words = OwnedTransmuted::<u16>::new::<PedanticGuard>(bytes).unwrap();
+///
+/// assert_eq!(&*words, &[u16::from_le(0x0001), u16::from_le(0x0002)]);
+/// ```
+pub struct OwnedTransmuted<T> {
+    storage: Storage<T>,
+    len: usize,
+}
+
+enum Storage<T> {
+    Bytes(Vec<u8>),
+    Values(Vec<T>),
+}
+
+impl<T: TriviallyTransmutable> OwnedTransmuted<T> {
+    /// Take ownership of the given byte vector, viewing it as a slice of `T`s.
+    ///
+    /// The required byte length of the vector depends on the chosen boundary guard.
+    /// Please see the [Guard API](../guard/index.html).
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the data does not comply with the policies of the
+    /// given guard `G`.
+    pub fn new<G: Guard>(bytes: Vec<u8>) -> Result<Self, Error<'static, u8, T>> {
+        let len = G::check::<T>(&bytes)?;
+
+        if check_alignment::<_, T>(&bytes).is_ok() {
+            Ok(OwnedTransmuted {
+                len,
+                storage: Storage::Bytes(bytes),
+            })
+        } else {
+            let values = transmute_to_vec::<T, G>(&bytes).map_err(Error::without_src)?;
+            Ok(OwnedTransmuted {
+                len: values.len(),
+                storage: Storage::Values(values),
+            })
+        }
+    }
+
+    /// Take ownership of the given boxed byte slice, viewing it as a slice of `T`s.
+    ///
+    /// Equivalent to [`new()`](#method.new), but for boxed slices.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the data does not comply with the policies of the
+    /// given guard `G`.
+    pub fn from_boxed<G: Guard>(bytes: Box<[u8]>) -> Result<Self, Error<'static, u8, T>> {
+        OwnedTransmuted::new::<G>(bytes.into_vec())
+    }
+
+    /// Whether the original byte buffer is being reused (`true`), or the values
+    /// had to be copied out of it (`false`).
+    pub fn is_in_place(&self) -> bool {
+        match self.storage {
+            Storage::Bytes(_) => true,
+            Storage::Values(_) => false,
+        }
+    }
+
+    /// Copy the values out into a vector.
+    ///
+    /// If the values were already copied upon construction, that vector is
+    /// returned instead.
+    pub fn into_vec(self) -> Vec<T> {
+        match self.storage {
+            Storage::Bytes(_) => self.to_vec(),
+            Storage::Values(values) => values,
+        }
+    }
+}
+
+impl<T: TriviallyTransmutable> Deref for OwnedTransmuted<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        match self.storage {
+            // The bytes are aligned for `T`, and contain at least `len` values
+            Storage::Bytes(ref bytes) => unsafe { slice::from_raw_parts(bytes.as_ptr() as *const T, self.len) },
+            Storage::Values(ref values) => values,
+        }
+    }
+}
+
+impl<T: TriviallyTransmutable> DerefMut for OwnedTransmuted<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        match self.storage {
+            // The bytes are aligned for `T`, and contain at least `len` values
+            Storage::Bytes(ref mut bytes) => unsafe { slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut T, self.len) },
+            Storage::Values(ref mut values) => values,
+        }
+    }
+}
+
+impl<T: TriviallyTransmutable> Clone for OwnedTransmuted<T> {
+    fn clone(&self) -> Self {
+        match self.storage {
+            // A clone of the bytes could have been allocated with a different alignment
+            Storage::Bytes(_) => {
+                OwnedTransmuted {
+                    storage: Storage::Values(self.to_vec()),
+                    len: self.len,
+                }
+            }
+            Storage::Values(ref values) => {
+                OwnedTransmuted {
+                    storage: Storage::Values(values.clone()),
+                    len: self.len,
+                }
+            }
+        }
+    }
+}
+
+impl<T: TriviallyTransmutable + fmt::Debug> fmt::Debug for OwnedTransmuted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+
+/// A heap-allocated byte buffer with a chosen memory alignment.
+///
+/// The buffer is zero-filled upon construction, and dereferences to `[u8]`.
+/// Transmuting it into a slice of any type with an alignment no greater than
+/// its own never fails with an `Unaligned` error.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PedanticGuard, AlignedBytes, transmute_many};
+/// let mut bytes = AlignedBytes::with_alignment_of::<u32>(8);
+/// bytes[0] = 0x01;
+///
+/// assert_eq!(transmute_many::<u32, PedanticGuard>(&bytes), Ok(&[u32::from_le(1), 0][..]));
+/// ```
+pub struct AlignedBytes {
+    ptr: NonNull<u8>,
+    len: usize,
+    align: usize,
+}
+
+// The buffer is uniquely owned, like a `Vec<u8>`
+unsafe impl Send for AlignedBytes {}
+unsafe impl Sync for AlignedBytes {}
+
+impl AlignedBytes {
+    /// Allocate `len` zero bytes aligned to `align` bytes.
+    ///
+    /// # Panics
+    ///
+    /// If `align` is not a power of two, or `len` rounded up to it overflows
+    /// `isize`.
+    pub fn with_alignment(len: usize, align: usize) -> AlignedBytes {
+        let layout = match Layout::from_size_align(len, align) {
+            Ok(layout) => layout,
+            Err(e) => panic!("invalid buffer layout of {} bytes aligned to {}: {}", len, align, e),
+        };
+
+        let ptr = if len == 0 {
+            // Never dereferenced, but aligned all the same, since `align` is non-zero
+            unsafe { NonNull::new_unchecked(align as *mut u8) }
+        } else {
+            match NonNull::new(unsafe { alloc_zeroed(layout) }) {
+                Some(ptr) => ptr,
+                None => handle_alloc_error(layout),
+            }
+        };
+
+        AlignedBytes {
+            ptr,
+            len,
+            align,
+        }
+    }
+
+    /// Allocate `len` zero bytes with a memory alignment compatible with `T`.
+    pub fn with_alignment_of<T>(len: usize) -> AlignedBytes {
+        AlignedBytes::with_alignment(len, align_of::<T>())
+    }
+
+    /// Copy the given bytes into a new buffer with a memory alignment
+    /// compatible with `T`.
+    pub fn from_slice_with_alignment_of<T>(bytes: &[u8]) -> AlignedBytes {
+        let mut buffer = AlignedBytes::with_alignment_of::<T>(bytes.len());
+        buffer.copy_from_slice(bytes);
+        buffer
+    }
+
+    /// The memory alignment of the buffer, in bytes.
+    pub fn alignment(&self) -> usize {
+        self.align
+    }
+}
+
+impl Deref for AlignedBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl DerefMut for AlignedBytes {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl Drop for AlignedBytes {
+    fn drop(&mut self) {
+        if self.len != 0 {
+            // Same layout as upon allocation, which was validated then
+            unsafe { dealloc(self.ptr.as_ptr(), Layout::from_size_align_unchecked(self.len, self.align)) }
+        }
+    }
+}
+
+impl Clone for AlignedBytes {
+    fn clone(&self) -> Self {
+        let mut buffer = AlignedBytes::with_alignment(self.len, self.align);
+        buffer.copy_from_slice(self);
+        buffer
+    }
+}
+
+impl PartialEq for AlignedBytes {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for AlignedBytes {}
+
+impl fmt::Debug for AlignedBytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
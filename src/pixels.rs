@@ -0,0 +1,123 @@
+//! Views of byte slices as pixels of the `image` crate.
+//!
+//! The `Rgb`, `Rgba`, `Luma`, and `LumaA` pixel types are transparent
+//! wrappers around arrays of their channels, so they are
+//! [`TriviallyTransmutable`](../trivial/trait.TriviallyTransmutable.html)
+//! whenever their channel type is. Raw texture data can thus be viewed as a
+//! slice of pixels without copying, and pixels can be viewed as bytes with
+//! [`transmute_to_bytes()`](../fn.transmute_to_bytes.html).
+//!
+//! Formats with 16-bit channels are stored in a fixed byte order, which
+//! [`transmute_pixels_endian()`](fn.transmute_pixels_endian.html) converts to
+//! the native one in place.
+//!
+//! Does not exist without the `image` feature.
+
+
+use self::super::full::{transmute_many_mut, transmute_many};
+use self::super::endian::{swap_bytes_endian, SwapBytes, ByteOrder};
+use self::super::trivial::TriviallyTransmutable;
+use image::{Primitive, LumaA, Pixel, Rgba, Luma, Rgb};
+use self::super::guard::Guard;
+use self::super::Error;
+
+
+macro_rules! pixel_impls {
+    ($($pixel:ident)*) => {
+        $(
+            unsafe impl<T: TriviallyTransmutable + Primitive> TriviallyTransmutable for $pixel<T> {}
+
+            impl<T: SwapBytes + Primitive> SwapBytes for $pixel<T> {
+                fn swap_bytes(self) -> $pixel<T> {
+                    $pixel(self.0.map(SwapBytes::swap_bytes))
+                }
+            }
+        )*
+    };
+}
+
+pixel_impls!(Rgb Rgba Luma LumaA);
+
+
+/// View a byte slice as a slice of pixels.
+///
+/// The required byte length of the slice depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `P`.
+/// - The data does not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate image;
+/// # extern crate safe_transmute;
+/// # use safe_transmute::pixels::transmute_pixels;
+/// # use safe_transmute::PedanticGuard;
+/// # use image::Rgba;
+/// # fn main() {
+/// let texture = [0xFF, 0x00, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0x80];
+/// let pixels = transmute_pixels::<Rgba<u8>, PedanticGuard>(&texture).unwrap();
+///
+/// assert_eq!(pixels, &[Rgba([0xFF, 0x00, 0x00, 0xFF]), Rgba([0x00, 0xFF, 0x00, 0x80])]);
+/// # }
+/// ```
+pub fn transmute_pixels<P: Pixel + TriviallyTransmutable, G: Guard>(bytes: &[u8]) -> Result<&[P], Error<u8, P>> {
+    transmute_many::<P, G>(bytes)
+}
+
+/// View a mutable byte slice as a mutable slice of pixels.
+///
+/// See [`transmute_pixels()`](fn.transmute_pixels.html) for details.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `P`.
+/// - The data does not comply with the policies of the given guard `G`.
+pub fn transmute_pixels_mut<P: Pixel + TriviallyTransmutable, G: Guard>(bytes: &mut [u8]) -> Result<&mut [P], Error<u8, P>> {
+    transmute_many_mut::<P, G>(bytes)
+}
+
+/// View a mutable byte slice of pixels whose channels are stored in the byte
+/// order `E` as a mutable slice of native pixels, converting them in place.
+///
+/// The data is left untouched if `E` is the native byte order.
+///
+/// The required byte length of the slice depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `P`.
+/// - The data does not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate image;
+/// # extern crate safe_transmute;
+/// # use safe_transmute::pixels::transmute_pixels_endian;
+/// # use safe_transmute::endian::BigEndian;
+/// # use safe_transmute::{PedanticGuard, transmute_to_bytes_mut};
+/// # use image::Luma;
+/// # fn main() {
+/// // 16-bit grayscale, as stored in PNG files
+/// let mut samples = [u16::from_be(0x1234), u16::from_be(0xFFFF)];
+/// let pixels = transmute_pixels_endian::<Luma<u16>, BigEndian, PedanticGuard>(transmute_to_bytes_mut(&mut samples)).unwrap();
+///
+/// assert_eq!(pixels, &[Luma([0x1234]), Luma([0xFFFF])]);
+/// # }
+/// ```
+pub fn transmute_pixels_endian<P: Pixel + SwapBytes, E: ByteOrder, G: Guard>(bytes: &mut [u8]) -> Result<&mut [P], Error<u8, P>> {
+    let pixels = transmute_many_mut::<P, G>(bytes)?;
+    swap_bytes_endian::<P, E>(pixels);
+    Ok(pixels)
+}
@@ -0,0 +1,13 @@
+//! The extension traits of this crate, and the types most often named
+//! alongside them.
+//!
+//! ```
+//! use safe_transmute::prelude::*;
+//! ```
+
+
+pub use self::super::guard::{SingleValueGuard, PermissiveGuard, SingleManyGuard, PedanticGuard, Guard};
+pub use self::super::trivial::TriviallyTransmutable;
+pub use self::super::ext::{AsByteSliceExt, TransmuteView};
+#[cfg(feature = "alloc")]
+pub use self::super::ext::VecTransmuteExt;
@@ -0,0 +1,114 @@
+//! Views and copies of Python buffers, like the data of numpy arrays or
+//! `bytes` objects, through `pyo3`.
+//!
+//! Buffers are taken as a `PyUntypedBuffer`, so that their format is not
+//! checked against the target type: a `float32` numpy array is as good a
+//! source of `[f32; 3]` vertices as an array of bytes. A `PyBuffer<T>`
+//! dereferences to one as well.
+//!
+//! The errors of this crate convert into Python `ValueError`s, so can be
+//! propagated out of `#[pyfunction]`s with `?`.
+//!
+//! # Examples
+//!
+//! ```
+//! # extern crate safe_transmute;
+//! # extern crate pyo3;
+//! # use safe_transmute::py_buffer::transmute_py_buffer_to_vec;
+//! # use safe_transmute::SingleManyGuard;
+//! # use pyo3::buffer::PyUntypedBuffer;
+//! # use pyo3::prelude::*;
+//! # use pyo3::types::PyBytes;
+//! # fn main() {
+//! # Python::initialize();
+//! Python::attach(|py| -> PyResult<()> {
+//!     let bytes = PyBytes::new(py, &[0x01, 0x00, 0x02, 0x00]);
+//!     let buffer = PyUntypedBuffer::get(bytes.as_any())?;
+//!
+//!     assert_eq!(transmute_py_buffer_to_vec::<u16, SingleManyGuard>(py, &buffer)?,
+//!                [u16::from_le(0x0001), u16::from_le(0x0002)]);
+//!     Ok(())
+//! }).unwrap();
+//! # }
+//! ```
+
+
+use self::super::error::{TransmuteError, Error};
+use self::super::trivial::TriviallyTransmutable;
+use self::super::full::{transmute_to_vec, transmute_many};
+use pyo3::exceptions::{PyBufferError, PyValueError};
+use pyo3::buffer::PyUntypedBuffer;
+use self::super::guard::Guard;
+use pyo3::{PyResult, PyErr, Python};
+use alloc::vec::Vec;
+use core::slice;
+
+
+impl<'a, S, T> From<Error<'a, S, T>> for PyErr {
+    fn from(e: Error<'a, S, T>) -> PyErr {
+        PyValueError::new_err(e.to_string())
+    }
+}
+
+impl From<TransmuteError> for PyErr {
+    fn from(e: TransmuteError) -> PyErr {
+        PyValueError::new_err(e.to_string())
+    }
+}
+
+
+/// The memory of a C-contiguous buffer.
+///
+/// The memory must not be modified while the slice is alive.
+unsafe fn buffer_bytes(buffer: &PyUntypedBuffer) -> PyResult<&[u8]> {
+    if !buffer.is_c_contiguous() {
+        Err(PyBufferError::new_err("buffer is not C-contiguous"))
+    } else if buffer.len_bytes() == 0 {
+        Ok(&[])
+    } else {
+        Ok(slice::from_raw_parts(buffer.buf_ptr() as *const u8, buffer.len_bytes()))
+    }
+}
+
+/// View the memory of a Python buffer as a slice of values.
+///
+/// The required byte length of the buffer depends on the chosen boundary
+/// guard. Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The buffer is not C-contiguous, as a `BufferError`.
+/// - The data does not have a memory alignment compatible with `T`, as a
+///   `ValueError`. Use
+///   [`transmute_py_buffer_to_vec()`](fn.transmute_py_buffer_to_vec.html)
+///   to copy it instead.
+/// - The data does not comply with the policies of the given guard `G`, as a
+///   `ValueError`.
+///
+/// # Safety
+///
+/// The memory of the buffer must not be modified while the returned slice is
+/// alive. Unless the buffer belongs to an immutable object, like `bytes`, any
+/// Python code run in the meantime could do so, as could other threads.
+pub unsafe fn transmute_py_buffer<T: TriviallyTransmutable, G: Guard>(buffer: &PyUntypedBuffer) -> PyResult<&[T]> {
+    Ok(transmute_many::<T, G>(buffer_bytes(buffer)?)?)
+}
+
+/// Copy the memory of a Python buffer into a vector of values.
+///
+/// The required byte length of the buffer depends on the chosen boundary
+/// guard. Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The buffer is not C-contiguous, as a `BufferError`.
+/// - The data does not comply with the policies of the given guard `G`, as a
+///   `ValueError`.
+pub fn transmute_py_buffer_to_vec<T: TriviallyTransmutable, G: Guard>(_py: Python, buffer: &PyUntypedBuffer) -> PyResult<Vec<T>> {
+    // The copy is made while attached to the interpreter, without running any Python code
+    Ok(transmute_to_vec::<T, G>(unsafe { buffer_bytes(buffer)? })?)
+}
@@ -0,0 +1,372 @@
+//! Views of byte slices as two-dimensional data.
+//!
+//! Images and matrices are commonly stored one row after another. The
+//! functions in this module view such data as slices of rows, so that values
+//! can be indexed as `data[y][x]` without chunking the data manually.
+//!
+//! Rows padded to a larger stride, as in GPU readbacks or BMP images, are
+//! instead viewed one at a time through a
+//! [`StridedView`](struct.StridedView.html).
+//!
+//! Slices of arrays, like pixels of channels, are flattened into slices of
+//! their values, and grouped back, with [`flatten()`](fn.flatten.html) and
+//! [`try_group()`](fn.try_group.html). Byte slices are split into fixed-size
+//! records with [`as_chunks()`](fn.as_chunks.html).
+
+
+use self::super::full::{transmute_many_mut, transmute_many};
+use self::super::trivial::TriviallyTransmutable;
+use self::super::error::{ErrorReason, GuardError};
+use self::super::guard::{AllOrNothingGuard, Guard};
+use self::super::align::check_alignment;
+use core::mem::{size_of_val, size_of};
+use core::marker::PhantomData;
+use self::super::Error;
+use core::slice;
+
+
+/// View a byte slice as a slice of rows of `W` values each.
+///
+/// The byte slice must hold a whole number of rows, possibly none.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`.
+/// - The data does not hold a whole number of rows, or `W` is `0`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{Error, transmute_to_bytes, transmute_rows};
+/// # fn run() -> Result<(), Error<'static, u8, [u16; 3]>> {
+/// let pixels = transmute_to_bytes(&[1u16, 2, 3, 4, 5, 6]);
+/// let rows = transmute_rows::<u16, 3>(pixels)?;
+///
+/// assert_eq!(rows.len(), 2);
+/// assert_eq!(rows[1][0], 4);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+pub fn transmute_rows<T: TriviallyTransmutable, const W: usize>(bytes: &[u8]) -> Result<&[[T; W]], Error<'_, u8, [T; W]>> {
+    transmute_many::<[T; W], AllOrNothingGuard>(bytes)
+}
+
+/// View a mutable byte slice as a mutable slice of rows of `W` values each.
+///
+/// See [`transmute_rows()`](fn.transmute_rows.html) for details.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`.
+/// - The data does not hold a whole number of rows, or `W` is `0`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{transmute_to_bytes_mut, transmute_rows_mut};
+/// let mut matrix = [0u32; 4];
+/// transmute_rows_mut::<u32, 2>(transmute_to_bytes_mut(&mut matrix)).unwrap()[1][0] = 7;
+///
+/// assert_eq!(matrix, [0, 0, 7, 0]);
+/// ```
+pub fn transmute_rows_mut<T: TriviallyTransmutable, const W: usize>(bytes: &mut [u8]) -> Result<&mut [[T; W]], Error<'_, u8, [T; W]>> {
+    transmute_many_mut::<[T; W], AllOrNothingGuard>(bytes)
+}
+
+
+/// View a slice of arrays as a flat slice of their values.
+///
+/// This is the inverse of [`try_group()`](fn.try_group.html), as between
+/// slices of pixels and of their channels.
+///
+/// # Errors
+///
+/// An error is returned if the length of the flat slice overflows `usize`,
+/// which is only possible for zero-sized `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::rows::flatten;
+/// let pixels = [[0xFFu8, 0x00, 0x00], [0x00, 0xFF, 0x00]];
+///
+/// assert_eq!(flatten(&pixels), Ok(&[0xFF, 0x00, 0x00, 0x00, 0xFF, 0x00][..]));
+/// ```
+pub fn flatten<T, const N: usize>(arrays: &[[T; N]]) -> Result<&[T], Error<'_, [T; N], T>> {
+    let len = flat_len(arrays)?;
+    // Arrays hold their values contiguously, without padding
+    unsafe { Ok(slice::from_raw_parts(arrays.as_ptr() as *const T, len)) }
+}
+
+/// View a mutable slice of arrays as a flat mutable slice of their values.
+///
+/// See [`flatten()`](fn.flatten.html) for details.
+///
+/// # Errors
+///
+/// An error is returned if the length of the flat slice overflows `usize`,
+/// which is only possible for zero-sized `T`.
+pub fn flatten_mut<T, const N: usize>(arrays: &mut [[T; N]]) -> Result<&mut [T], Error<'_, [T; N], T>> {
+    let len = flat_len(arrays)?;
+    // Arrays hold their values contiguously, without padding
+    unsafe { Ok(slice::from_raw_parts_mut(arrays.as_mut_ptr() as *mut T, len)) }
+}
+
+fn flat_len<T, const N: usize>(arrays: &[[T; N]]) -> Result<usize, GuardError> {
+    arrays.len().checked_mul(N).ok_or(GuardError {
+        required: 0,
+        actual: size_of_val(arrays),
+        reason: ErrorReason::ZeroSizedTarget,
+    })
+}
+
+/// View a slice of values as a slice of arrays of `N` values each.
+///
+/// The slice must hold a whole number of arrays, possibly none.
+///
+/// # Errors
+///
+/// An error is returned if the slice does not hold a whole number of arrays,
+/// if `N` is `0`, or if `T` is zero-sized.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::rows::try_group;
+/// let channels = [0xFFu8, 0x00, 0x00, 0x00, 0xFF, 0x00];
+///
+/// assert_eq!(try_group::<_, 3>(&channels), Ok(&[[0xFF, 0x00, 0x00], [0x00, 0xFF, 0x00]][..]));
+/// assert!(try_group::<_, 4>(&channels).is_err());
+/// ```
+pub fn try_group<T, const N: usize>(values: &[T]) -> Result<&[[T; N]], Error<'_, T, [T; N]>> {
+    let len = AllOrNothingGuard::check_len::<[T; N]>(size_of_val(values))?;
+    // The values are aligned for arrays of them, and make up `len` arrays
+    unsafe { Ok(slice::from_raw_parts(values.as_ptr() as *const [T; N], len)) }
+}
+
+/// View a mutable slice of values as a mutable slice of arrays of `N` values
+/// each.
+///
+/// See [`try_group()`](fn.try_group.html) for details.
+///
+/// # Errors
+///
+/// An error is returned if the slice does not hold a whole number of arrays,
+/// if `N` is `0`, or if `T` is zero-sized.
+pub fn try_group_mut<T, const N: usize>(values: &mut [T]) -> Result<&mut [[T; N]], Error<'_, T, [T; N]>> {
+    let len = AllOrNothingGuard::check_len::<[T; N]>(size_of_val(values))?;
+    // The values are aligned for arrays of them, and make up `len` arrays
+    unsafe { Ok(slice::from_raw_parts_mut(values.as_mut_ptr() as *mut [T; N], len)) }
+}
+
+
+struct AssertNonEmptyChunk<const N: usize>;
+
+impl<const N: usize> AssertNonEmptyChunk<N> {
+    const OK: () = assert!(N != 0, "the chunk size is zero");
+}
+
+/// Split a byte slice into chunks of `N` bytes, and the remaining bytes.
+///
+/// This is an equivalent of the `<[u8]>::as_chunks()` method, on any Rust
+/// version this crate supports. `N` must not be zero, which is checked at
+/// compile time.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::rows::as_chunks;
+/// let (records, rest) = as_chunks::<3>(&[1, 2, 3, 4, 5, 6, 7]);
+///
+/// assert_eq!(records, &[[1, 2, 3], [4, 5, 6]]);
+/// assert_eq!(rest, &[7]);
+/// ```
+///
+/// Empty chunks are rejected at compile time:
+///
+/// ```compile_fail
+/// # use safe_transmute::rows::as_chunks;
+/// as_chunks::<0>(&[1, 2]);
+/// ```
+pub fn as_chunks<const N: usize>(bytes: &[u8]) -> (&[[u8; N]], &[u8]) {
+    let () = AssertNonEmptyChunk::<N>::OK;
+    let len = bytes.len() / N;
+    let (chunks, rest) = bytes.split_at(len * N);
+    // Byte arrays have no alignment requirement, and `chunks` holds `len` of them
+    unsafe { (slice::from_raw_parts(chunks.as_ptr() as *const [u8; N], len), rest) }
+}
+
+/// Split a mutable byte slice into chunks of `N` bytes, and the remaining
+/// bytes.
+///
+/// See [`as_chunks()`](fn.as_chunks.html) for details.
+pub fn as_chunks_mut<const N: usize>(bytes: &mut [u8]) -> (&mut [[u8; N]], &mut [u8]) {
+    let () = AssertNonEmptyChunk::<N>::OK;
+    let len = bytes.len() / N;
+    let (chunks, rest) = bytes.split_at_mut(len * N);
+    // Byte arrays have no alignment requirement, and `chunks` holds `len` of them
+    unsafe { (slice::from_raw_parts_mut(chunks.as_mut_ptr() as *mut [u8; N], len), rest) }
+}
+
+/// View a byte slice as chunks of exactly `N` bytes each, without remaining
+/// bytes.
+///
+/// # Errors
+///
+/// An error is returned if the slice does not hold a whole number of chunks,
+/// or `N` is `0`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::rows::as_chunks_exact;
+/// assert_eq!(as_chunks_exact::<2>(&[1, 2, 3, 4]), Ok(&[[1, 2], [3, 4]][..]));
+/// assert!(as_chunks_exact::<2>(&[1, 2, 3]).is_err());
+/// ```
+pub fn as_chunks_exact<const N: usize>(bytes: &[u8]) -> Result<&[[u8; N]], Error<'_, u8, [u8; N]>> {
+    try_group(bytes)
+}
+
+/// View a mutable byte slice as chunks of exactly `N` bytes each, without
+/// remaining bytes.
+///
+/// See [`as_chunks_exact()`](fn.as_chunks_exact.html) for details.
+///
+/// # Errors
+///
+/// An error is returned if the slice does not hold a whole number of chunks,
+/// or `N` is `0`.
+pub fn as_chunks_exact_mut<const N: usize>(bytes: &mut [u8]) -> Result<&mut [[u8; N]], Error<'_, u8, [u8; N]>> {
+    try_group_mut(bytes)
+}
+
+
+/// A view of rows of values in a byte slice, each starting `stride` bytes
+/// after the previous one.
+///
+/// The padding between rows is never read. The last row does not need to be
+/// followed by any.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{Error, StridedView, transmute_to_bytes};
+/// # fn run() -> Result<(), Error<'static, u8, u16>> {
+/// // Rows of 3 pixels, padded to 8 bytes
+/// let pixels = transmute_to_bytes(&[1u16, 2, 3, 0xFFFF, 4, 5, 6, 0xFFFF]);
+/// let view = StridedView::<u16>::new(pixels, 3, 8)?;
+///
+/// assert_eq!(view.height(), 2);
+/// assert_eq!(view.row(1), &[4, 5, 6]);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct StridedView<'a, T> {
+    bytes: &'a [u8],
+    width: usize,
+    stride: usize,
+    height: usize,
+    _phantom: PhantomData<&'a [T]>,
+}
+
+impl<'a, T: TriviallyTransmutable> StridedView<'a, T> {
+    /// View the given bytes as rows of `width` values, each starting `stride`
+    /// bytes after the previous one.
+    ///
+    /// The view holds as many rows as fit in the data; extraneous bytes after
+    /// the last one are ignored.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in one of the following situations:
+    ///
+    /// - Any of the rows does not have a memory alignment compatible with `T`,
+    ///   reported for the first one which does not.
+    /// - `T` is zero-sized.
+    /// - `stride` is zero, or shorter than a row of `width` values, reported
+    ///   as `Error::InvalidStride`.
+    pub fn new(bytes: &'a [u8], width: usize, stride: usize) -> Result<StridedView<'a, T>, Error<'a, u8, T>> {
+        if size_of::<T>() == 0 {
+            return Err(Error::Guard(GuardError {
+                required: 0,
+                actual: bytes.len(),
+                reason: ErrorReason::ZeroSizedTarget,
+            }));
+        }
+        let row_bytes = match width.checked_mul(size_of::<T>()) {
+            Some(row_bytes) if stride != 0 && stride >= row_bytes => row_bytes,
+            _ => {
+                return Err(Error::InvalidStride {
+                    stride,
+                    row_size: width.saturating_mul(size_of::<T>()),
+                })
+            }
+        };
+        check_alignment::<_, T>(bytes)?;
+
+        let height = if bytes.len() < row_bytes {
+            0
+        } else {
+            (bytes.len() - row_bytes) / stride + 1
+        };
+        if height > 1 {
+            // Every row is aligned if the first two are
+            check_alignment::<_, T>(&bytes[stride..])?;
+        }
+
+        Ok(StridedView {
+            bytes,
+            width,
+            stride,
+            height,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// The number of values in each row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of bytes from the start of one row to the start of the next.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// The row at the given index.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `y` is not less than the height.
+    pub fn row(&self, y: usize) -> &'a [T] {
+        self.get(y).unwrap_or_else(|| panic!("row {} out of bounds for a view of {} rows", y, self.height))
+    }
+
+    /// The row at the given index, or `None` if it is out of bounds.
+    pub fn get(&self, y: usize) -> Option<&'a [T]> {
+        if y < self.height {
+            // Every row is aligned for `T`, and the first `height` rows hold `width` values each
+            Some(unsafe { slice::from_raw_parts(self.bytes.as_ptr().add(y * self.stride) as *const T, self.width) })
+        } else {
+            None
+        }
+    }
+
+    /// An iterator over the rows, from the first one.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + 'a {
+        let view = *self;
+        (0..self.height).map(move |y| view.row(y))
+    }
+}
@@ -0,0 +1,249 @@
+//! Transmutation of reference-counted byte slices.
+//!
+//! The functions in this module turn an `Rc<[u8]>` or `Arc<[u8]>` into a
+//! reference-counted slice of a trivially transmutable type. This is mostly
+//! useful for parsers which share sub-buffers of a single input between
+//! multiple owners.
+//!
+//! Whenever the shared allocation can be reinterpreted in place, it is reused
+//! as-is, without copying. This is the case when:
+//!
+//! - the minimum memory alignment of `T` is 1, like that of byte arrays or
+//!   of the [`endian`](../endian/index.html) primitives, as a shared
+//!   allocation can only be taken over by a type of the same alignment, and
+//! - there are no extraneous bytes after the last `T` value.
+//!
+//! Otherwise, the data is copied into a newly allocated shared slice of `T`s,
+//! which, unlike the vector transmutation functions, makes the conversion
+//! infallible as far as memory alignment is concerned.
+//!
+//! [`SharedSlice`](struct.SharedSlice.html) instead keeps the byte slice as it
+//! is, and views windows into it, so that a single loaded file can be handed
+//! out to multiple parsers without copying.
+//!
+//! Does not exist without the `alloc` feature.
+
+
+use self::super::trivial::TriviallyTransmutable;
+use self::super::error::copy_to_vec_unchecked;
+use self::super::align::check_alignment;
+use self::super::guard::{bytes_for, value_bytes, Guard};
+use core::ops::{RangeBounds, Bound, Deref};
+use core::mem::{align_of, size_of};
+use core::marker::PhantomData;
+use self::super::Error;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use alloc::rc::Rc;
+use core::slice;
+use core::ptr;
+use core::fmt;
+
+
+/// Check the given shared byte slice against the guard `G`, returning the
+/// number of `T`s it holds and whether its allocation can be reused for them.
+fn check_layout<T, G: Guard>(bytes: &[u8]) -> Result<(usize, bool), Error<'static, u8, T>> {
+    let len = G::check::<T>(bytes)?;
+
+    let reusable = align_of::<T>() == 1 && bytes_for::<T>(len) == bytes.len();
+    Ok((len, reusable))
+}
+
+/// Create a copy of the first `len` values of `T` in the given bytes.
+fn copy_values<T: TriviallyTransmutable>(bytes: &[u8], len: usize) -> Vec<T> {
+    unsafe {
+        // no value checks needed thanks to `TriviallyTransmutable`
+        copy_to_vec_unchecked::<u8, T>(value_bytes::<T>(bytes, len))
+    }
+}
+
+
+/// Transmute a reference-counted byte slice into a reference-counted slice of
+/// a trivially transmutable type.
+///
+/// The required byte length of the slice depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// The allocation is reused if possible (see the
+/// [module-level documentation](index.html)), otherwise the values are copied
+/// into a new one.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::shared::transmute_rc;
+/// # use safe_transmute::PedanticGuard;
+/// # use std::rc::Rc;
+/// let bytes: Rc<[u8]> = Rc::from(&[0x01u8, 0x00, 0x02, 0x00][..]);
+/// let words = transmute_rc::<u16, PedanticGuard>(bytes).unwrap();
+///
+/// assert_eq!(&*words, &[u16::from_le(0x0001), u16::from_le(0x0002)]);
+/// ```
+pub fn transmute_rc<T: TriviallyTransmutable, G: Guard>(bytes: Rc<[u8]>) -> Result<Rc<[T]>, Error<'static, u8, T>> {
+    match check_layout::<T, G>(&bytes)? {
+        (len, true) => unsafe { Ok(Rc::from_raw(ptr::slice_from_raw_parts(Rc::into_raw(bytes) as *const T, len))) },
+        (len, false) => Ok(Rc::from(copy_values::<T>(&bytes, len))),
+    }
+}
+
+/// Transmute an atomically reference-counted byte slice into an atomically
+/// reference-counted slice of a trivially transmutable type.
+///
+/// The required byte length of the slice depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// The allocation is reused if possible (see the
+/// [module-level documentation](index.html)), otherwise the values are copied
+/// into a new one.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::shared::transmute_arc;
+/// # use safe_transmute::SingleManyGuard;
+/// # use std::sync::Arc;
+/// let bytes: Arc<[u8]> = Arc::from(&[0x01u8, 0x00, 0x02, 0x00, 0x03][..]);
+/// let words = transmute_arc::<u16, SingleManyGuard>(bytes).unwrap();
+///
+/// assert_eq!(&*words, &[u16::from_le(0x0001), u16::from_le(0x0002)]);
+/// ```
+pub fn transmute_arc<T: TriviallyTransmutable, G: Guard>(bytes: Arc<[u8]>) -> Result<Arc<[T]>, Error<'static, u8, T>> {
+    match check_layout::<T, G>(&bytes)? {
+        (len, true) => unsafe { Ok(Arc::from_raw(ptr::slice_from_raw_parts(Arc::into_raw(bytes) as *const T, len))) },
+        (len, false) => Ok(Arc::from(copy_values::<T>(&bytes, len))),
+    }
+}
+
+
+/// An atomically reference-counted byte slice viewed as a slice of a
+/// trivially transmutable type, or a window into one.
+///
+/// Cloning and slicing this are as cheap as cloning the underlying `Arc`, so
+/// multiple owners can hold typed windows into one buffer without lifetimes.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::shared::SharedSlice;
+/// # use safe_transmute::{PedanticGuard, Error, transmute_to_bytes};
+/// # use std::sync::Arc;
+/// # fn run() -> Result<(), Error<'static, u8, u32>> {
+/// let file: Arc<[u8]> = Arc::from(transmute_to_bytes(&[1u32, 2, 3, 4]));
+/// let words = SharedSlice::<u32>::new::<PedanticGuard>(file)?;
+///
+/// let header = words.slice(..1);
+/// let body = words.slice(1..);
+/// assert_eq!(&*header, &[1]);
+/// assert_eq!(&*body.slice(1..), &[3, 4]);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+pub struct SharedSlice<T> {
+    bytes: Arc<[u8]>,
+    offset: usize,
+    len: usize,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: TriviallyTransmutable> SharedSlice<T> {
+    /// View the given shared byte slice as a slice of `T`s.
+    ///
+    /// The required byte length of the slice depends on the chosen boundary guard.
+    /// Please see the [Guard API](../guard/index.html).
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in one of the following situations:
+    ///
+    /// - The data does not have a memory alignment compatible with `T`.
+    /// - The data does not comply with the policies of the given guard `G`.
+    pub fn new<G: Guard>(bytes: Arc<[u8]>) -> Result<SharedSlice<T>, Error<'static, u8, T>> {
+        let len = G::check::<T>(&bytes)?;
+        check_alignment::<_, T>(&bytes).map_err(|e| Error::from(e).without_src())?;
+        Ok(SharedSlice {
+            bytes,
+            offset: 0,
+            len,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// A window into the given range of values of this slice, sharing the
+    /// same buffer.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the range is out of bounds, like slice
+    /// indexing.
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> SharedSlice<T> {
+        self.get(range).unwrap_or_else(|| panic!("range out of bounds for a slice of {} values", self.len))
+    }
+
+    /// A window into the given range of values of this slice, sharing the
+    /// same buffer, or `None` if the range is out of bounds.
+    pub fn get<R: RangeBounds<usize>>(&self, range: R) -> Option<SharedSlice<T>> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1)?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len,
+        };
+        if start > end || end > self.len {
+            return None;
+        }
+
+        Some(SharedSlice {
+            bytes: self.bytes.clone(),
+            offset: self.offset + start * size_of::<T>(),
+            len: end - start,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// The whole underlying buffer, including any bytes outside of this
+    /// window.
+    pub fn as_bytes(&self) -> &Arc<[u8]> {
+        &self.bytes
+    }
+}
+
+impl<T: TriviallyTransmutable> Deref for SharedSlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        // The buffer is aligned for `T`, contains at least `len` values past `offset`, and never moves
+        unsafe { slice::from_raw_parts(self.bytes.as_ptr().add(self.offset) as *const T, self.len) }
+    }
+}
+
+impl<T> Clone for SharedSlice<T> {
+    fn clone(&self) -> SharedSlice<T> {
+        SharedSlice {
+            bytes: self.bytes.clone(),
+            offset: self.offset,
+            len: self.len,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: TriviallyTransmutable + fmt::Debug> fmt::Debug for SharedSlice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
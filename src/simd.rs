@@ -0,0 +1,67 @@
+//! Implementations of `TriviallyTransmutable` for the SIMD vector types of
+//! `core::arch`, with the `simd` feature, and for `core::simd` vectors, with
+//! the `portable-simd` feature.
+//!
+//! Their alignment is usually stricter than that of their lanes, so views of
+//! byte slices as slices of these fail unless the data is suitably aligned.
+
+
+use self::super::trivial::TriviallyTransmutable;
+#[cfg(feature = "portable-simd")]
+use core::simd::{SimdElement, Simd};
+
+
+#[cfg(all(feature = "simd", target_arch = "x86"))]
+use core::arch::x86::{__m128, __m128d, __m128i, __m256, __m256d, __m256i};
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+use core::arch::x86_64::{__m128, __m128d, __m128i, __m256, __m256d, __m256i};
+
+#[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64")))]
+unsafe impl TriviallyTransmutable for __m128 {}
+#[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64")))]
+unsafe impl TriviallyTransmutable for __m128d {}
+#[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64")))]
+unsafe impl TriviallyTransmutable for __m128i {}
+#[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64")))]
+unsafe impl TriviallyTransmutable for __m256 {}
+#[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64")))]
+unsafe impl TriviallyTransmutable for __m256d {}
+#[cfg(all(feature = "simd", any(target_arch = "x86", target_arch = "x86_64")))]
+unsafe impl TriviallyTransmutable for __m256i {}
+
+
+#[cfg(all(feature = "simd", target_arch = "aarch64"))]
+mod neon {
+    use core::arch::aarch64::{int8x8_t, int8x16_t, int16x4_t, int16x8_t, int32x2_t, int32x4_t, int64x1_t, int64x2_t, uint8x8_t, uint8x16_t, uint16x4_t,
+                              uint16x8_t, uint32x2_t, uint32x4_t, uint64x1_t, uint64x2_t, float32x2_t, float32x4_t, float64x1_t, float64x2_t};
+    use self::super::TriviallyTransmutable;
+
+    unsafe impl TriviallyTransmutable for int8x8_t {}
+    unsafe impl TriviallyTransmutable for int8x16_t {}
+    unsafe impl TriviallyTransmutable for int16x4_t {}
+    unsafe impl TriviallyTransmutable for int16x8_t {}
+    unsafe impl TriviallyTransmutable for int32x2_t {}
+    unsafe impl TriviallyTransmutable for int32x4_t {}
+    unsafe impl TriviallyTransmutable for int64x1_t {}
+    unsafe impl TriviallyTransmutable for int64x2_t {}
+    unsafe impl TriviallyTransmutable for uint8x8_t {}
+    unsafe impl TriviallyTransmutable for uint8x16_t {}
+    unsafe impl TriviallyTransmutable for uint16x4_t {}
+    unsafe impl TriviallyTransmutable for uint16x8_t {}
+    unsafe impl TriviallyTransmutable for uint32x2_t {}
+    unsafe impl TriviallyTransmutable for uint32x4_t {}
+    unsafe impl TriviallyTransmutable for uint64x1_t {}
+    unsafe impl TriviallyTransmutable for uint64x2_t {}
+    unsafe impl TriviallyTransmutable for float32x2_t {}
+    unsafe impl TriviallyTransmutable for float32x4_t {}
+    unsafe impl TriviallyTransmutable for float64x1_t {}
+    unsafe impl TriviallyTransmutable for float64x2_t {}
+}
+
+
+#[cfg(all(feature = "simd", target_arch = "wasm32"))]
+unsafe impl TriviallyTransmutable for core::arch::wasm32::v128 {}
+
+
+#[cfg(feature = "portable-simd")]
+unsafe impl<T: SimdElement + TriviallyTransmutable, const N: usize> TriviallyTransmutable for Simd<T, N> {}
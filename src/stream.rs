@@ -0,0 +1,620 @@
+//! Sequential transmutation of byte data, without memory alignment requirements.
+//!
+//! Rather than viewing a whole byte slice as a slice of another type, which
+//! requires the data to be properly aligned, the facilities in this module
+//! copy values out one by one with unaligned reads. This is best suited for
+//! data which is only traversed once.
+//!
+//! With the `std` feature, [`TransmuteReader`](struct.TransmuteReader.html)
+//! additionally reads values out of any `io::Read` implementor, and
+//! [`TransmuteWriter`](struct.TransmuteWriter.html) writes them into any
+//! `io::Write` implementor.
+
+
+use self::super::error::{SequenceError, ErrorReason, GuardError};
+use self::super::trivial::TriviallyTransmutable;
+use core::mem::{MaybeUninit, size_of};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::{slice, cmp, fmt, ptr};
+#[cfg(feature = "std")]
+use self::super::to_bytes::{transmute_one_to_bytes, transmute_to_bytes_mut, transmute_to_bytes};
+#[cfg(feature = "std")]
+use std::io::{self, ErrorKind, Write, Read};
+#[cfg(feature = "std")]
+use core::mem;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+
+
+/// An iterator over the values of a trivially transmutable type in a byte
+/// slice, regardless of its memory alignment.
+///
+/// Created by [`unaligned_iter()`](fn.unaligned_iter.html).
+#[derive(Clone, Debug)]
+pub struct UnalignedIter<'a, T> {
+    bytes: &'a [u8],
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T: TriviallyTransmutable> UnalignedIter<'a, T> {
+    /// The bytes which have not been consumed yet, including any extraneous
+    /// bytes after the last full value.
+    pub fn remainder(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a, T: TriviallyTransmutable> Iterator for UnalignedIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if size_of::<T>() == 0 || self.bytes.len() < size_of::<T>() {
+            return None;
+        }
+
+        let (value, rest) = self.bytes.split_at(size_of::<T>());
+        self.bytes = rest;
+        // no value checks needed thanks to `TriviallyTransmutable`
+        Some(unsafe { ptr::read_unaligned(value.as_ptr() as *const T) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: TriviallyTransmutable> DoubleEndedIterator for UnalignedIter<'a, T> {
+    fn next_back(&mut self) -> Option<T> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+
+        let value = &self.bytes[(len - 1) * size_of::<T>()..len * size_of::<T>()];
+        self.bytes = &self.bytes[..(len - 1) * size_of::<T>()];
+        Some(unsafe { ptr::read_unaligned(value.as_ptr() as *const T) })
+    }
+}
+
+impl<'a, T: TriviallyTransmutable> ExactSizeIterator for UnalignedIter<'a, T> {
+    fn len(&self) -> usize {
+        if size_of::<T>() == 0 {
+            0
+        } else {
+            self.bytes.len() / size_of::<T>()
+        }
+    }
+}
+
+impl<'a, T: TriviallyTransmutable> FusedIterator for UnalignedIter<'a, T> {}
+
+
+/// Iterate over the values of a trivially transmutable type in a byte slice,
+/// copying each one out with an unaligned read.
+///
+/// The iterator yields as many values as will fit, rounded down; extraneous
+/// bytes at the end are available through
+/// [`UnalignedIter::remainder()`](struct.UnalignedIter.html#method.remainder)
+/// once the values in front of them have been consumed.
+///
+/// Unlike [`transmute_many()`](../fn.transmute_many.html), this never fails
+/// due to memory alignment, and unlike copying the values into a vector, no
+/// additional memory is allocated.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::stream::unaligned_iter;
+/// let bytes = &[0xFF, 0x01, 0x00, 0x02, 0x00, 0x03];
+///
+/// assert_eq!(unaligned_iter::<u16>(&bytes[1..]).collect::<Vec<_>>(),
+///            vec![u16::from_le(0x0001), u16::from_le(0x0002)]);
+/// ```
+pub fn unaligned_iter<T: TriviallyTransmutable>(bytes: &[u8]) -> UnalignedIter<'_, T> {
+    UnalignedIter {
+        bytes,
+        _phantom: PhantomData,
+    }
+}
+
+
+/// Copy out the field of a [`transmute_sequence!()`](../macro.transmute_sequence.html)
+/// starting at `offset`, advancing it past the field.
+#[doc(hidden)]
+pub fn read_sequence_field<T: TriviallyTransmutable>(bytes: &[u8], offset: &mut usize, field: &'static str) -> Result<T, SequenceError> {
+    let rest = &bytes[*offset..];
+    if rest.len() < size_of::<T>() {
+        return Err(SequenceError {
+            field,
+            offset: *offset,
+            error: GuardError {
+                required: size_of::<T>(),
+                actual: rest.len(),
+                reason: ErrorReason::NotEnoughBytes,
+            },
+        });
+    }
+
+    *offset += size_of::<T>();
+    // `rest` holds enough bytes for a `T`, which are valid thanks to `TriviallyTransmutable`
+    Ok(unsafe { ptr::read_unaligned(rest.as_ptr() as *const T) })
+}
+
+
+/// A decoder of values of a trivially transmutable type out of a stream of
+/// byte chunks, such as network packets or pipe reads.
+///
+/// Element boundaries need not coincide with chunk boundaries: the bytes of a
+/// value split across chunks are buffered internally until it is complete.
+/// No memory is allocated in the process.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::stream::StreamingTransmuter;
+/// let mut decoder = StreamingTransmuter::<u16>::new();
+///
+/// assert_eq!(decoder.push(&[0x01, 0x00, 0x02]).collect::<Vec<_>>(), vec![u16::from_le(0x0001)]);
+/// assert_eq!(decoder.pending(), &[0x02]);
+/// assert_eq!(decoder.push(&[0x00, 0x03, 0x00]).collect::<Vec<_>>(),
+///            vec![u16::from_le(0x0002), u16::from_le(0x0003)]);
+/// assert_eq!(decoder.finish(), Ok(()));
+/// ```
+pub struct StreamingTransmuter<T> {
+    partial: MaybeUninit<T>,
+    filled: usize,
+}
+
+impl<T: TriviallyTransmutable> StreamingTransmuter<T> {
+    /// Create a decoder with no buffered bytes.
+    pub fn new() -> StreamingTransmuter<T> {
+        StreamingTransmuter {
+            partial: MaybeUninit::uninit(),
+            filled: 0,
+        }
+    }
+
+    /// Feed the next chunk of bytes into the decoder, returning an iterator
+    /// over the values completed by it.
+    ///
+    /// Any trailing bytes not making up a full value are buffered before this
+    /// function returns, and will be prepended to the next chunk. The values
+    /// yielded by the iterator, on the other hand, are not retained: those not
+    /// consumed from it are lost.
+    pub fn push<'c>(&mut self, chunk: &'c [u8]) -> StreamingValues<'c, T> {
+        let size = size_of::<T>();
+        if size == 0 {
+            return StreamingValues {
+                head: None,
+                rest: unaligned_iter(&[]),
+            };
+        }
+
+        let mut chunk = chunk;
+        let mut head = None;
+        if self.filled != 0 {
+            let missing = cmp::min(size - self.filled, chunk.len());
+            self.buffer(&chunk[..missing]);
+            chunk = &chunk[missing..];
+
+            if self.filled == size {
+                self.filled = 0;
+                head = Some(unsafe { self.partial.assume_init() });
+            }
+        }
+
+        let whole = chunk.len() - chunk.len() % size;
+        self.buffer(&chunk[whole..]);
+
+        StreamingValues {
+            head,
+            rest: unaligned_iter(&chunk[..whole]),
+        }
+    }
+
+    /// The bytes of an incomplete value buffered so far.
+    pub fn pending(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.partial.as_ptr() as *const u8, self.filled) }
+    }
+
+    /// Finish decoding the stream, ensuring that no incomplete value was left
+    /// over.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if some bytes of an incomplete value are still
+    /// buffered.
+    pub fn finish(self) -> Result<(), GuardError> {
+        if self.filled != 0 {
+            Err(GuardError {
+                required: size_of::<T>(),
+                actual: self.filled,
+                reason: ErrorReason::NotEnoughBytes,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    fn buffer(&mut self, bytes: &[u8]) {
+        unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), (self.partial.as_mut_ptr() as *mut u8).add(self.filled), bytes.len()) };
+        self.filled += bytes.len();
+    }
+}
+
+impl<T: TriviallyTransmutable> Clone for StreamingTransmuter<T> {
+    fn clone(&self) -> StreamingTransmuter<T> {
+        *self
+    }
+}
+
+impl<T: TriviallyTransmutable> Copy for StreamingTransmuter<T> {}
+
+impl<T: TriviallyTransmutable> Default for StreamingTransmuter<T> {
+    fn default() -> StreamingTransmuter<T> {
+        StreamingTransmuter::new()
+    }
+}
+
+impl<T: TriviallyTransmutable> fmt::Debug for StreamingTransmuter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StreamingTransmuter").field("pending", &self.pending()).finish()
+    }
+}
+
+
+/// An iterator over the values completed by a chunk of bytes.
+///
+/// Created by [`StreamingTransmuter::push()`](struct.StreamingTransmuter.html#method.push).
+#[derive(Clone, Debug)]
+#[must_use = "values not consumed from the iterator are lost"]
+pub struct StreamingValues<'a, T> {
+    head: Option<T>,
+    rest: UnalignedIter<'a, T>,
+}
+
+impl<'a, T: TriviallyTransmutable> Iterator for StreamingValues<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.head.take().or_else(|| self.rest.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: TriviallyTransmutable> ExactSizeIterator for StreamingValues<'a, T> {
+    fn len(&self) -> usize {
+        self.head.is_some() as usize + self.rest.len()
+    }
+}
+
+impl<'a, T: TriviallyTransmutable> FusedIterator for StreamingValues<'a, T> {}
+
+
+/// An iterator reading values of a trivially transmutable type out of an
+/// [`io::Read`](https://doc.rust-lang.org/std/io/trait.Read.html) implementor.
+///
+/// The data is read in large blocks into an internal buffer, which is
+/// properly aligned for `T`, so the values can be taken out of it directly.
+///
+/// If the reader reaches its end in the middle of a value, an error of kind
+/// `UnexpectedEof` is yielded before the iteration ends.
+///
+/// Does not exist without the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::stream::TransmuteReader;
+/// # use std::io::Cursor;
+/// let file = Cursor::new(vec![0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///                             0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+/// let records = TransmuteReader::<_, u64>::new(file).collect::<Result<Vec<_>, _>>().unwrap();
+///
+/// assert_eq!(records, vec![u64::from_le(1), u64::from_le(2)]);
+/// ```
+#[cfg(feature = "std")]
+pub struct TransmuteReader<R, T> {
+    reader: R,
+    buffer: ReadBuffer<T>,
+}
+
+#[cfg(feature = "std")]
+impl<R: Read, T: TriviallyTransmutable> TransmuteReader<R, T> {
+    /// Create a reader with a buffer of approximately 8 KiB.
+    pub fn new(reader: R) -> TransmuteReader<R, T> {
+        TransmuteReader::with_capacity(ReadBuffer::<T>::DEFAULT_CAPACITY, reader)
+    }
+
+    /// Create a reader with a buffer of (at least one) `capacity` values.
+    pub fn with_capacity(capacity: usize, reader: R) -> TransmuteReader<R, T> {
+        TransmuteReader {
+            reader,
+            buffer: ReadBuffer::with_capacity(capacity),
+        }
+    }
+
+    /// Get a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Get a mutable reference to the underlying reader.
+    ///
+    /// Reading directly from it will skip over data not yet yielded by this iterator.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Unwrap this iterator, returning the underlying reader.
+    ///
+    /// Any buffered data not yet yielded by this iterator is lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read, T: TriviallyTransmutable> Iterator for TransmuteReader<R, T> {
+    type Item = io::Result<T>;
+
+    fn next(&mut self) -> Option<io::Result<T>> {
+        loop {
+            if let Some(value) = self.buffer.take() {
+                return value;
+            }
+
+            match self.reader.read(self.buffer.spare()) {
+                Ok(read) => self.buffer.filled(read),
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: fmt::Debug, T> fmt::Debug for TransmuteReader<R, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TransmuteReader")
+            .field("reader", &self.reader)
+            .field("buffered", &self.buffer.buffered())
+            .finish()
+    }
+}
+
+
+/// Read exactly enough bytes out of an
+/// [`io::Read`](https://doc.rust-lang.org/std/io/trait.Read.html)
+/// implementor to fill the given values, straight into their in-memory byte
+/// representation.
+///
+/// Does not exist without the `std` feature.
+///
+/// # Errors
+///
+/// Any error returned by the reader is passed through, as for
+/// [`Read::read_exact()`](https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact):
+/// running out of data is reported as `ErrorKind::UnexpectedEof`, after which
+/// the values are left partially overwritten.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::stream::read_exact_typed;
+/// let mut dump: &[u8] = &[0x01, 0x02, 0x03, 0x04, 0x05];
+/// let mut words = [0u16; 2];
+/// read_exact_typed(&mut dump, &mut words).unwrap();
+///
+/// assert_eq!(words, [u16::from_le(0x0201), u16::from_le(0x0403)]);
+/// assert_eq!(dump, &[0x05]);
+/// ```
+#[cfg(feature = "std")]
+pub fn read_exact_typed<T: TriviallyTransmutable, R: Read + ?Sized>(reader: &mut R, values: &mut [T]) -> io::Result<()> {
+    reader.read_exact(transmute_to_bytes_mut(values))
+}
+
+/// Write the given values into an
+/// [`io::Write`](https://doc.rust-lang.org/std/io/trait.Write.html)
+/// implementor, as their raw in-memory byte representation.
+///
+/// Does not exist without the `std` feature.
+///
+/// # Errors
+///
+/// Any error returned by the writer is passed through.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::stream::write_transmuted;
+/// let mut dump = Vec::new();
+/// write_transmuted(&mut dump, &[u16::from_le(0x0201), u16::from_le(0x0403)]).unwrap();
+///
+/// assert_eq!(dump, vec![0x01, 0x02, 0x03, 0x04]);
+/// ```
+#[cfg(feature = "std")]
+pub fn write_transmuted<W: Write + ?Sized, T: TriviallyTransmutable>(writer: &mut W, values: &[T]) -> io::Result<()> {
+    writer.write_all(transmute_to_bytes(values))
+}
+
+
+/// A writer of values of a trivially transmutable type into an
+/// [`io::Write`](https://doc.rust-lang.org/std/io/trait.Write.html)
+/// implementor, as their raw in-memory byte representation.
+///
+/// No buffering is done by this type: writing values one at a time into an
+/// unbuffered writer should be done through a `BufWriter`.
+///
+/// Does not exist without the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::stream::TransmuteWriter;
+/// let mut writer = TransmuteWriter::<_, u32>::new(Vec::new());
+/// writer.write_value(u32::from_le(0x0403_0201)).unwrap();
+/// writer.write_values(&[u32::from_le(0x0807_0605)]).unwrap();
+///
+/// assert_eq!(writer.into_inner(), vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+/// ```
+#[cfg(feature = "std")]
+pub struct TransmuteWriter<W, T> {
+    writer: W,
+    _phantom: PhantomData<T>,
+}
+
+#[cfg(feature = "std")]
+impl<W: Write, T: TriviallyTransmutable> TransmuteWriter<W, T> {
+    /// Create a value writer over the given byte writer.
+    pub fn new(writer: W) -> TransmuteWriter<W, T> {
+        TransmuteWriter {
+            writer,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Write a single value.
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by the writer is passed through.
+    pub fn write_value(&mut self, value: T) -> io::Result<()> {
+        self.writer.write_all(transmute_one_to_bytes(&value))
+    }
+
+    /// Write a slice of values.
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by the writer is passed through.
+    pub fn write_values(&mut self, values: &[T]) -> io::Result<()> {
+        write_transmuted(&mut self.writer, values)
+    }
+
+    /// Write all values yielded by the given iterator, stopping at the first error.
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by the writer is passed through.
+    pub fn write_iter<I: IntoIterator<Item = T>>(&mut self, values: I) -> io::Result<()> {
+        for value in values {
+            self.write_value(value)?;
+        }
+        Ok(())
+    }
+
+    /// Flush the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by the writer is passed through.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Get a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Get a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Unwrap this writer, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: fmt::Debug, T> fmt::Debug for TransmuteWriter<W, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TransmuteWriter").field("writer", &self.writer).finish()
+    }
+}
+
+
+/// A buffer of values being read from a byte stream, properly aligned for `T`.
+#[cfg(feature = "std")]
+pub(crate) struct ReadBuffer<T> {
+    buffer: Vec<T>,
+    start: usize,
+    end: usize,
+    done: bool,
+}
+
+#[cfg(feature = "std")]
+impl<T> ReadBuffer<T> {
+    /// The amount of buffered bytes.
+    pub fn buffered(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: TriviallyTransmutable> ReadBuffer<T> {
+    /// The amount of values fitting in approximately 8 KiB.
+    pub const DEFAULT_CAPACITY: usize = 8 * 1024 / if size_of::<T>() == 0 { 1 } else { size_of::<T>() };
+
+    pub fn with_capacity(capacity: usize) -> ReadBuffer<T> {
+        ReadBuffer {
+            // any byte combination is a valid `T`
+            buffer: vec![unsafe { mem::zeroed() }; cmp::max(capacity, 1)],
+            start: 0,
+            end: 0,
+            done: false,
+        }
+    }
+
+    /// Take the next value out of the buffer.
+    ///
+    /// Returns `Some(_)` with the next item of the stream, or `None` if more data needs to be read.
+    pub fn take(&mut self) -> Option<Option<io::Result<T>>> {
+        let size = size_of::<T>();
+        if size == 0 {
+            Some(None)
+        } else if self.end - self.start >= size {
+            // `start` is always a multiple of the size
+            let value = self.buffer[self.start / size];
+            self.start += size;
+            Some(Some(Ok(value)))
+        } else if self.done {
+            if self.end != self.start {
+                self.start = self.end;
+                Some(Some(Err(io::Error::new(ErrorKind::UnexpectedEof, "stream ended in the middle of a value"))))
+            } else {
+                Some(None)
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Move the buffered bytes to the front, returning the free space after them.
+    pub fn spare(&mut self) -> &mut [u8] {
+        let bytes = transmute_to_bytes_mut(&mut self.buffer[..]);
+        bytes.copy_within(self.start..self.end, 0);
+        self.end -= self.start;
+        self.start = 0;
+        &mut bytes[self.end..]
+    }
+
+    /// Mark `read` bytes of the free space as filled, with zero marking the end of the stream.
+    ///
+    /// Readers claiming to have read more than the free space are only trusted up to its end.
+    pub fn filled(&mut self, read: usize) {
+        if read == 0 {
+            self.done = true;
+        }
+        self.end = cmp::min(self.end.saturating_add(read), self.buffer.len() * size_of::<T>());
+    }
+}
@@ -0,0 +1,156 @@
+//! Functions for safe transmutation to text.
+//!
+//! UTF-8 text is checked to be valid before being handed out. Invalid data is
+//! reported as an
+//! [`Error::InvalidValueAt`](../error/enum.Error.html#variant.InvalidValueAt)
+//! error, with the byte index and the bytes of the first invalid sequence.
+//!
+//! UTF-16 text, like the wide strings of Windows, can either be viewed as
+//! native-endian code units, or decoded from either byte order into a
+//! `String`, with an optional byte order mark.
+
+
+#[cfg(feature = "alloc")]
+use self::super::error::InvalidUtf8Error;
+#[cfg(feature = "alloc")]
+use self::super::endian::ByteOrder;
+use self::super::guard::AllOrNothingGuard;
+#[cfg(feature = "alloc")]
+use self::super::guard::Guard;
+use self::super::full::transmute_many;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::char;
+use self::super::Error;
+use core::str;
+
+
+/// View a byte slice as a string slice.
+///
+/// # Errors
+///
+/// An error is returned if the bytes are not valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{Error, transmute_bytes_to_str};
+/// assert_eq!(transmute_bytes_to_str(b"PE\0\0"), Ok("PE\0\0"));
+/// assert_eq!(transmute_bytes_to_str(&[0x50, 0x45, 0xFF]),
+///            Err(Error::InvalidValueAt { index: 2, bytes: &[0xFF] }));
+/// ```
+pub fn transmute_bytes_to_str(bytes: &[u8]) -> Result<&str, Error<'_, u8, u8>> {
+    str::from_utf8(bytes).map_err(|e| {
+        let index = e.valid_up_to();
+        Error::InvalidValueAt {
+            index,
+            bytes: &bytes[index..index + e.error_len().unwrap_or(bytes.len() - index)],
+        }
+    })
+}
+
+/// Transform a byte vector into a string.
+///
+/// The vector's allocated byte buffer is always reused.
+///
+/// # Errors
+///
+/// An error is returned if the bytes are not valid UTF-8, giving back the
+/// vector.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{Error, transmute_vec_to_string};
+/// assert_eq!(transmute_vec_to_string(b".text".to_vec()), Ok(".text".to_string()));
+/// assert_eq!(transmute_vec_to_string(vec![0x2E, 0xC3]).unwrap_err().error(),
+///            Error::InvalidValueAt { index: 1, bytes: &[0xC3] });
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_vec_to_string(bytes: Vec<u8>) -> Result<String, InvalidUtf8Error> {
+    String::from_utf8(bytes).map_err(|e| {
+        let error = e.utf8_error();
+        InvalidUtf8Error::new(e.into_bytes(), error)
+    })
+}
+
+/// View a byte slice as a slice of native-endian UTF-16 code units.
+///
+/// The code units are not validated, so may contain unpaired surrogates, as
+/// wide strings on Windows are allowed to. The byte order mark, if any, is
+/// left in place.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `u16`.
+/// - The data has an odd amount of bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{transmute_to_utf16, transmute_to_bytes};
+/// let wide: &[u16] = &[0x0043, 0x003A, 0x005C];
+/// assert_eq!(transmute_to_utf16(transmute_to_bytes(wide)), Ok(wide));
+/// ```
+pub fn transmute_to_utf16(bytes: &[u8]) -> Result<&[u16], Error<'_, u8, u16>> {
+    transmute_many::<u16, AllOrNothingGuard>(bytes)
+}
+
+/// Decode UTF-16 bytes into a string.
+///
+/// A leading byte order mark selects the byte order, and is not included in
+/// the result; `E` is the byte order of data without one. The data does not
+/// need to be aligned.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data has an odd amount of bytes.
+/// - The data contains an unpaired surrogate, reported with the index of its
+///   code unit, counting the byte order mark, and its two bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::endian::{LittleEndian, BigEndian};
+/// # use safe_transmute::{Error, decode_utf16};
+/// assert_eq!(decode_utf16::<LittleEndian>(&[0x50, 0x00, 0x45, 0x00]), Ok("PE".to_string()));
+/// assert_eq!(decode_utf16::<LittleEndian>(&[0xFE, 0xFF, 0x00, 0x50, 0x00, 0x45]), Ok("PE".to_string()));
+/// assert_eq!(decode_utf16::<BigEndian>(&[0x00, 0x50, 0xDC, 0x00]),
+///            Err(Error::InvalidValueAt { index: 1, bytes: &[0xDC, 0x00] }));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_utf16<E: ByteOrder>(bytes: &[u8]) -> Result<String, Error<'_, u8, u16>> {
+    AllOrNothingGuard::check::<u16>(bytes)?;
+
+    let (little_endian, skip) = match bytes {
+        [0xFF, 0xFE, ..] => (true, 1),
+        [0xFE, 0xFF, ..] => (false, 1),
+        _ => (E::NATIVE == cfg!(target_endian = "little"), 0),
+    };
+    let units = bytes.chunks_exact(2).skip(skip).map(|unit| if little_endian {
+        u16::from_le_bytes([unit[0], unit[1]])
+    } else {
+        u16::from_be_bytes([unit[0], unit[1]])
+    });
+
+    let mut text = String::with_capacity(bytes.len() / 2);
+    let mut index = skip;
+    for c in char::decode_utf16(units) {
+        let c = c.map_err(|_| {
+                Error::InvalidValueAt {
+                    index,
+                    bytes: &bytes[index * 2..index * 2 + 2],
+                }
+            })?;
+        index += c.len_utf16();
+        text.push(c);
+    }
+    Ok(text)
+}
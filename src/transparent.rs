@@ -0,0 +1,103 @@
+//! Conversions between `repr(transparent)` newtypes and the types they wrap.
+//!
+//! A newtype implementing [`TransparentWrapper`](trait.TransparentWrapper.html)
+//! can be peeled off of, and wrapped around, references, slices, and vectors
+//! of the inner type at no cost.
+//!
+//! With the `derive` feature, the trait can be derived for `repr(transparent)`
+//! structs, which the compiler then proves to have the same layout as the
+//! wrapped type:
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # mod derived {
+//! # use safe_transmute::TransparentWrapper;
+//! #[derive(TransparentWrapper)]
+//! #[repr(transparent)]
+//! struct Meters(f64);
+//!
+//! # pub fn main() {
+//! let distances = [1.0, 2.5];
+//! let meters: &[Meters] = Meters::wrap_slice(&distances);
+//!
+//! assert_eq!(meters[1].0, 2.5);
+//! # }
+//! # }
+//! # fn main() {
+//! # #[cfg(feature = "derive")]
+//! # derived::main();
+//! # }
+//! ```
+
+
+#[cfg(feature = "alloc")]
+use core::mem::ManuallyDrop;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::slice;
+
+
+/// Type with the same memory layout as, and no more validity requirements
+/// than, `Inner`.
+///
+/// # Safety
+///
+/// It is only safe to implement `TransparentWrapper<Inner>` for a type `T` if
+/// `T` is a `repr(transparent)` struct whose only non-zero-sized field is of
+/// type `Inner`, and whose other fields, if any, can be constructed from
+/// nothing (like `PhantomData`).
+pub unsafe trait TransparentWrapper<Inner>: Sized {
+    /// Wrap a reference to the inner type.
+    fn wrap_ref(inner: &Inner) -> &Self {
+        unsafe { &*(inner as *const Inner as *const Self) }
+    }
+
+    /// Wrap a mutable reference to the inner type.
+    fn wrap_mut(inner: &mut Inner) -> &mut Self {
+        unsafe { &mut *(inner as *mut Inner as *mut Self) }
+    }
+
+    /// Peel off this wrapper from a reference.
+    fn peel_ref(&self) -> &Inner {
+        unsafe { &*(self as *const Self as *const Inner) }
+    }
+
+    /// Peel off this wrapper from a mutable reference.
+    fn peel_mut(&mut self) -> &mut Inner {
+        unsafe { &mut *(self as *mut Self as *mut Inner) }
+    }
+
+    /// Wrap every value in a slice of the inner type.
+    fn wrap_slice(inner: &[Inner]) -> &[Self] {
+        unsafe { slice::from_raw_parts(inner.as_ptr() as *const Self, inner.len()) }
+    }
+
+    /// Wrap every value in a mutable slice of the inner type.
+    fn wrap_slice_mut(inner: &mut [Inner]) -> &mut [Self] {
+        unsafe { slice::from_raw_parts_mut(inner.as_mut_ptr() as *mut Self, inner.len()) }
+    }
+
+    /// Peel off this wrapper from every value in a slice.
+    fn peel_slice(values: &[Self]) -> &[Inner] {
+        unsafe { slice::from_raw_parts(values.as_ptr() as *const Inner, values.len()) }
+    }
+
+    /// Peel off this wrapper from every value in a mutable slice.
+    fn peel_slice_mut(values: &mut [Self]) -> &mut [Inner] {
+        unsafe { slice::from_raw_parts_mut(values.as_mut_ptr() as *mut Inner, values.len()) }
+    }
+
+    /// Wrap every value in a vector of the inner type, reusing its allocation.
+    #[cfg(feature = "alloc")]
+    fn wrap_vec(inner: Vec<Inner>) -> Vec<Self> {
+        let mut inner = ManuallyDrop::new(inner);
+        unsafe { Vec::from_raw_parts(inner.as_mut_ptr() as *mut Self, inner.len(), inner.capacity()) }
+    }
+
+    /// Peel off this wrapper from every value in a vector, reusing its allocation.
+    #[cfg(feature = "alloc")]
+    fn peel_vec(values: Vec<Self>) -> Vec<Inner> {
+        let mut values = ManuallyDrop::new(values);
+        unsafe { Vec::from_raw_parts(values.as_mut_ptr() as *mut Inner, values.len(), values.capacity()) }
+    }
+}
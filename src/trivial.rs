@@ -15,13 +15,17 @@
 //! address flooring (e.g. ARM), but this is undefined behavior nonetheless.
 
 
-use self::super::guard::{PermissiveGuard, PedanticGuard, Guard};
-use self::super::base::{transmute_many, transmute_many_mut, from_bytes};
+use self::super::base::{transmute_many_mut_with, transmute_many_with, transmute_many_mut, transmute_many, from_bytes};
+use self::super::guard::{PermissiveGuard, PedanticGuard, RuntimeGuard, Guard};
 #[cfg(feature = "alloc")]
 use self::super::base::transmute_vec;
+use core::num::{NonZeroUsize, NonZeroIsize, NonZeroU16, NonZeroI16, NonZeroU32, NonZeroI32, NonZeroU64, NonZeroI64, NonZeroU8, NonZeroI8,
+                Saturating, Wrapping};
 use self::super::Error;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+#[cfg(feature = "uuid")]
+use uuid::Uuid;
 
 
 /// Type that can be constructed from any combination of bytes.
@@ -34,13 +38,39 @@ use alloc::vec::Vec;
 ///
 /// This serves as a marker trait for all functions in this module.
 ///
-/// Enable the `const_generics` feature to implement this for arbitrary `[T: TriviallyTransmutable, N]` arrays,
-/// instead of just 1-32.
-/// This, of course, requires a sufficiently fresh rustc (at least 1.51).
+/// This is implemented for arrays of any length of trivially transmutable types,
+/// for `Wrapping` and `Saturating` trivially transmutable types, and for
+/// optional `NonZero` integers. With the `simd` feature, it is also implemented
+/// for the SIMD vector types of `core::arch`, like `__m128i` or `uint8x16_t`,
+/// and with the `portable-simd` feature, for `core::simd::Simd` vectors.
+/// With the `glam`, `mint`, and `nalgebra` features, it is implemented for
+/// those crates' vector, matrix, and quaternion types which have no padding,
+/// like `glam::Vec3`, `mint::Point3<f32>`, or `nalgebra::Matrix4<f32>`, and
+/// with the `fixed` feature, for fixed-point numbers like `fixed::FixedU16<Frac>`.
+/// With the `uuid` feature, it is implemented for `uuid::Uuid`.
+///
+/// With the `derive` feature, it can be derived for `repr(C)`,
+/// `repr(transparent)`, and `repr(packed)` structs whose fields are all
+/// trivially transmutable, optionally along with a description of their
+/// [`layout`](../layout/index.html). Structs with padding, whose bytes would
+/// be exposed by [`transmute_to_bytes()`](../to_bytes/fn.transmute_to_bytes.html),
+/// are rejected at compile time:
+///
+#[cfg_attr(feature = "derive",
+           doc = r#"```compile_fail,E0080
+# use safe_transmute::TriviallyTransmutable;
+#[derive(TriviallyTransmutable, Clone, Copy)]
+#[repr(C)]
+struct Padded {
+    tag: u8,
+    value: u32,
+}
+# fn main() {}
+```"#)]
 ///
 /// *Warning*: if you transmute into a floating-point type you will have a chance to create a signaling NaN,
 /// which, while not illegal, can be unwieldy. Check out [`util::designalise_f{32,64}()`](util/index.html)
-/// for a remedy.
+/// or the [`float`](../float/index.html) module for a remedy.
 ///
 /// *Nota bene*: `bool` is not `TriviallyTransmutable` because they're restricted to
 /// being `0` or `1`, which means that an additional value check is required.
@@ -69,50 +99,28 @@ unsafe impl TriviallyTransmutable for usize {}
 unsafe impl TriviallyTransmutable for isize {}
 unsafe impl TriviallyTransmutable for f32 {}
 unsafe impl TriviallyTransmutable for f64 {}
-#[cfg(i128_type)]
 unsafe impl TriviallyTransmutable for u128 {}
-#[cfg(i128_type)]
 unsafe impl TriviallyTransmutable for i128 {}
 
-#[cfg(not(feature = "const_generics"))]
-mod trivially_transmutable_arrays {
-    use self::super::TriviallyTransmutable;
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 1] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 2] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 3] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 4] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 5] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 6] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 7] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 8] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 9] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 10] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 11] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 12] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 13] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 14] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 15] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 16] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 17] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 18] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 19] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 20] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 21] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 22] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 23] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 24] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 25] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 26] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 27] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 28] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 29] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 30] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 31] {}
-    unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for [T; 32] {}
-}
-
-#[cfg(feature = "const_generics")]
 unsafe impl<T: TriviallyTransmutable, const N: usize> TriviallyTransmutable for [T; N] {}
+unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for Wrapping<T> {}
+unsafe impl<T: TriviallyTransmutable> TriviallyTransmutable for Saturating<T> {}
+
+// All-zero bytes are `None`, thanks to the niche
+unsafe impl TriviallyTransmutable for Option<NonZeroU8> {}
+unsafe impl TriviallyTransmutable for Option<NonZeroI8> {}
+unsafe impl TriviallyTransmutable for Option<NonZeroU16> {}
+unsafe impl TriviallyTransmutable for Option<NonZeroI16> {}
+unsafe impl TriviallyTransmutable for Option<NonZeroU32> {}
+unsafe impl TriviallyTransmutable for Option<NonZeroI32> {}
+unsafe impl TriviallyTransmutable for Option<NonZeroU64> {}
+unsafe impl TriviallyTransmutable for Option<NonZeroI64> {}
+unsafe impl TriviallyTransmutable for Option<NonZeroUsize> {}
+unsafe impl TriviallyTransmutable for Option<NonZeroIsize> {}
+
+// repr(transparent) over [u8; 16]
+#[cfg(feature = "uuid")]
+unsafe impl TriviallyTransmutable for Uuid {}
 
 /// Transmute the slice to a slice of another type, ensuring alignment of the types is maintained.
 ///
@@ -312,6 +320,40 @@ pub unsafe fn transmute_trivial_many_mut<T: TriviallyTransmutable, G: Guard>(byt
     transmute_many_mut::<T, G>(bytes)
 }
 
+/// Transmute a byte slice into a sequence of values of a trivially
+/// transmutable type, checking it against a guard value.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard.
+///
+/// # Safety
+///
+/// This function invokes undefined behavior if the data does not have a memory
+/// alignment compatible with `T`. If this cannot be ensured, you will have to
+/// make a copy of the data, or change how it was originally made.
+pub unsafe fn transmute_trivial_many_with<T: TriviallyTransmutable, G: RuntimeGuard>(bytes: &[u8], guard: G) -> Result<&[T], Error<'_, u8, T>> {
+    transmute_many_with::<T, G>(bytes, guard)
+}
+
+/// Transmute a mutable byte slice into a mutable sequence of values of a
+/// trivially transmutable type, checking it against a guard value.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard.
+///
+/// # Safety
+///
+/// This function invokes undefined behavior if the data does not have a memory
+/// alignment compatible with `T`. If this cannot be ensured, you will have to
+/// make a copy of the data, or change how it was originally made.
+pub unsafe fn transmute_trivial_many_mut_with<T: TriviallyTransmutable, G: RuntimeGuard>(bytes: &mut [u8], guard: G) -> Result<&mut [T], Error<'_, u8, T>> {
+    transmute_many_mut_with::<T, G>(bytes, guard)
+}
+
 /// View a byte slice as a slice of a trivially transmutable type.
 ///
 /// The resulting slice will have as many instances of a type as will fit, rounded down.
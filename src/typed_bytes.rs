@@ -0,0 +1,149 @@
+//! Typed views over the buffers of the `bytes` crate.
+//!
+//! [`TypedBytes`](struct.TypedBytes.html) views a reference-counted
+//! `Bytes` buffer as a slice of a trivially transmutable type, sharing the
+//! allocation between clones, while
+//! [`transmute_bytes_mut()`](fn.transmute_bytes_mut.html) views a `BytesMut`
+//! buffer in place.
+//!
+//! Both are validated against a guard and for memory alignment. As `Bytes`
+//! are often slices of larger buffers, the latter may fail arbitrarily; see
+//! the [crate-level documentation](../index.html) for ways around it.
+//!
+//! Does not exist without the `bytes` feature.
+
+
+use self::super::trivial::TriviallyTransmutable;
+use self::super::align::check_alignment;
+use self::super::full::transmute_many_mut;
+use self::super::guard::Guard;
+use core::marker::PhantomData;
+use bytes::{BytesMut, Bytes};
+use self::super::Error;
+use core::ops::Deref;
+use core::slice;
+use core::fmt;
+
+
+/// A reference-counted `Bytes` buffer viewed as a slice of a trivially
+/// transmutable type.
+///
+/// Cloning this is as cheap as cloning the underlying `Bytes`.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate bytes;
+/// # extern crate safe_transmute;
+/// # use safe_transmute::typed_bytes::TypedBytes;
+/// # use safe_transmute::{PedanticGuard, transmute_to_bytes};
+/// # use bytes::Bytes;
+/// # fn main() {
+/// let packet = Bytes::from(transmute_to_bytes(&[1u32, 2, 3]).to_vec());
+/// # if let Ok(words) = TypedBytes::<u32>::new::<PedanticGuard>(packet) {
+/// # /*
+/// let words = TypedBytes::<u32>::new::<PedanticGuard>(packet)?;
+/// # */
+///
+/// assert_eq!(&*words, &[1, 2, 3]);
+/// # }
+/// # }
+/// ```
+pub struct TypedBytes<T> {
+    bytes: Bytes,
+    len: usize,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: TriviallyTransmutable> TypedBytes<T> {
+    /// View the given buffer as a slice of `T`s.
+    ///
+    /// The required byte length of the buffer depends on the chosen boundary guard.
+    /// Please see the [Guard API](../guard/index.html).
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in one of the following situations:
+    ///
+    /// - The data does not have a memory alignment compatible with `T`.
+    /// - The data does not comply with the policies of the given guard `G`.
+    pub fn new<G: Guard>(bytes: Bytes) -> Result<TypedBytes<T>, Error<'static, u8, T>> {
+        let len = G::check::<T>(&bytes)?;
+        check_alignment::<_, T>(&bytes).map_err(|e| Error::from(e).without_src())?;
+        Ok(TypedBytes {
+            bytes: bytes,
+            len: len,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// The underlying buffer, including any extraneous bytes after the last value.
+    pub fn as_bytes(&self) -> &Bytes {
+        &self.bytes
+    }
+
+    /// Unwrap the underlying buffer, including any extraneous bytes after the last value.
+    pub fn into_bytes(self) -> Bytes {
+        self.bytes
+    }
+}
+
+impl<T: TriviallyTransmutable> Deref for TypedBytes<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        // The buffer is aligned for `T`, contains at least `len` values, and never moves
+        unsafe { slice::from_raw_parts(self.bytes.as_ptr() as *const T, self.len) }
+    }
+}
+
+impl<T> Clone for TypedBytes<T> {
+    fn clone(&self) -> TypedBytes<T> {
+        TypedBytes {
+            bytes: self.bytes.clone(),
+            len: self.len,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: TriviallyTransmutable + fmt::Debug> fmt::Debug for TypedBytes<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+
+/// View a `BytesMut` buffer as a mutable slice of a trivially transmutable type.
+///
+/// The required byte length of the buffer depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`.
+/// - The data does not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate bytes;
+/// # extern crate safe_transmute;
+/// # use safe_transmute::typed_bytes::transmute_bytes_mut;
+/// # use safe_transmute::{SingleManyGuard, transmute_to_bytes};
+/// # use bytes::BytesMut;
+/// # fn main() {
+/// let mut buffer = BytesMut::from(transmute_to_bytes(&[1u16, 2]));
+/// # if let Ok(words) = transmute_bytes_mut::<u16, SingleManyGuard>(&mut buffer) {
+/// # /*
+/// let words = transmute_bytes_mut::<u16, SingleManyGuard>(&mut buffer)?;
+/// # */
+/// words[1] = 3;
+/// # }
+/// # }
+/// ```
+pub fn transmute_bytes_mut<T: TriviallyTransmutable, G: Guard>(bytes: &mut BytesMut) -> Result<&mut [T], Error<u8, T>> {
+    transmute_many_mut::<T, G>(bytes)
+}
@@ -0,0 +1,123 @@
+//! Views of byte slices as n-dimensional arrays of the `ndarray` crate.
+//!
+//! The functions in this module check that the data fills the given shape
+//! exactly, in standard (row-major) layout, and that it is properly aligned
+//! for the element type, going from raw binary dumps to arrays in one call.
+//!
+//! Does not exist without the `ndarray` feature.
+
+
+use self::super::full::{transmute_vec_or_copy, transmute_many_mut, transmute_many};
+use ndarray::{ArrayViewMut, IntoDimension, ArrayView, Dimension, Array};
+use self::super::trivial::TriviallyTransmutable;
+use self::super::guard::{AllOrNothingGuard, bytes_for};
+use self::super::error::{ErrorReason, GuardError};
+use self::super::Error;
+use alloc::vec::Vec;
+
+
+/// Check the given byte length against the size of the given shape.
+fn check_shape<T, D: Dimension>(len: usize, dim: &D) -> Result<(), GuardError> {
+    let required = dim.size_checked().map_or(usize::MAX, bytes_for::<T>);
+    if len != required {
+        Err(GuardError {
+            required: required,
+            actual: len,
+            reason: ErrorReason::InexactByteCount,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+
+/// View a byte slice as an n-dimensional array of the given shape.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`.
+/// - The data does not have exactly enough bytes for the shape.
+/// - `T` is zero-sized.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate ndarray;
+/// # extern crate safe_transmute;
+/// # use safe_transmute::typed_ndarray::transmute_to_array_view;
+/// # use safe_transmute::{Error, transmute_to_bytes};
+/// # fn run() -> Result<(), Error<'static, u8, f32>> {
+/// let dump = transmute_to_bytes(&[1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0]);
+/// let matrix = transmute_to_array_view::<f32, _>(dump, (2, 3))?;
+///
+/// assert_eq!(matrix[[1, 0]], 4.0);
+/// assert_eq!(matrix.shape(), &[2, 3]);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # run().unwrap();
+/// # }
+/// ```
+pub fn transmute_to_array_view<T: TriviallyTransmutable, Sh: IntoDimension>(bytes: &[u8], shape: Sh) -> Result<ArrayView<T, Sh::Dim>, Error<u8, T>> {
+    let dim = shape.into_dimension();
+    check_shape::<T, _>(bytes.len(), &dim)?;
+    let values = transmute_many::<T, AllOrNothingGuard>(bytes)?;
+    Ok(ArrayView::from_shape(dim, values).expect("the values fill the shape"))
+}
+
+/// View a mutable byte slice as a mutable n-dimensional array of the given
+/// shape.
+///
+/// See [`transmute_to_array_view()`](fn.transmute_to_array_view.html) for
+/// details.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`.
+/// - The data does not have exactly enough bytes for the shape.
+/// - `T` is zero-sized.
+pub fn transmute_to_array_view_mut<T: TriviallyTransmutable, Sh: IntoDimension>(bytes: &mut [u8], shape: Sh)
+                                                                               -> Result<ArrayViewMut<T, Sh::Dim>, Error<u8, T>> {
+    let dim = shape.into_dimension();
+    check_shape::<T, _>(bytes.len(), &dim)?;
+    let values = transmute_many_mut::<T, AllOrNothingGuard>(bytes)?;
+    Ok(ArrayViewMut::from_shape(dim, values).expect("the values fill the shape"))
+}
+
+/// Transform a byte vector into an owned n-dimensional array of the given
+/// shape.
+///
+/// The vector's allocation is reused if possible, and the values are copied
+/// into a new one otherwise, so the data does not need to be aligned.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have exactly enough bytes for the shape.
+/// - `T` is zero-sized.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate ndarray;
+/// # extern crate safe_transmute;
+/// # use safe_transmute::typed_ndarray::transmute_to_array;
+/// # use safe_transmute::transmute_to_bytes;
+/// # fn main() {
+/// let dump = transmute_to_bytes(&[1u16, 2, 3, 4, 5, 6, 7, 8]).to_vec();
+/// let cube = transmute_to_array::<u16, _>(dump, (2, 2, 2)).unwrap();
+///
+/// assert_eq!(cube[[1, 0, 1]], 6);
+/// # }
+/// ```
+pub fn transmute_to_array<T: TriviallyTransmutable, Sh: IntoDimension>(bytes: Vec<u8>, shape: Sh) -> Result<Array<T, Sh::Dim>, Error<'static, u8, T>> {
+    let dim = shape.into_dimension();
+    check_shape::<T, _>(bytes.len(), &dim)?;
+    let values = transmute_vec_or_copy::<T, AllOrNothingGuard>(bytes)?;
+    Ok(Array::from_shape_vec(dim, values).expect("the values fill the shape"))
+}
@@ -0,0 +1,89 @@
+//! Transmutation into possibly uninitialized values.
+//!
+//! Any combination of bytes is a valid `MaybeUninit<T>`, whatever `T`, so
+//! byte slices can be viewed as slices thereof without any requirements on
+//! `T`, other than memory alignment. There is no mutable counterpart, as
+//! writing an uninitialized value would make the underlying bytes unreadable.
+//!
+//! Conversely, [`transmute_many_into_uninit()`](fn.transmute_many_into_uninit.html)
+//! decodes trivially transmutable values into caller-provided uninitialized
+//! storage, without zeroing it first.
+
+
+use self::super::trivial::TriviallyTransmutable;
+use self::super::guard::{check_capacity, Guard};
+use self::super::align::check_alignment;
+use core::mem::{MaybeUninit, size_of};
+use self::super::Error;
+use core::{slice, ptr};
+
+
+/// View a byte slice as a slice of possibly uninitialized values.
+///
+/// The required byte length of the slice depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`.
+/// - The data does not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{SingleManyGuard, transmute_many_uninit, transmute_to_bytes};
+/// # use std::mem::MaybeUninit;
+/// let words: &[u32] = &[1, 2];
+/// # /*
+/// let values: &[MaybeUninit<bool>] = transmute_many_uninit::<bool, SingleManyGuard>(transmute_to_bytes(words))?;
+/// # */
+/// # let values: &[MaybeUninit<bool>] = transmute_many_uninit::<bool, SingleManyGuard>(transmute_to_bytes(words)).unwrap();
+///
+/// assert_eq!(values.len(), 8);
+/// ```
+pub fn transmute_many_uninit<T, G: Guard>(bytes: &[u8]) -> Result<&[MaybeUninit<T>], Error<'_, u8, T>> {
+    check_alignment::<_, T>(bytes)?;
+    let len = G::check::<T>(bytes)?;
+    Ok(unsafe { slice::from_raw_parts(bytes.as_ptr() as *const MaybeUninit<T>, len) })
+}
+
+/// Copy the values of a trivially transmutable type in a byte slice into the
+/// front of the given uninitialized storage, returning the initialized part.
+///
+/// The byte slice is not required to be aligned for `T`.
+///
+/// The required byte length of the slice depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not comply with the policies of the given guard `G`.
+/// - The data holds more values than fit in `dest`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{SingleManyGuard, Error, transmute_many_into_uninit};
+/// # use std::mem::MaybeUninit;
+/// # fn run() -> Result<(), Error<'static, u8, u16>> {
+/// let mut storage = [MaybeUninit::<u16>::uninit(); 16];
+/// let words = transmute_many_into_uninit::<u16, SingleManyGuard>(&[0x01, 0x00, 0x02, 0x00], &mut storage)?;
+///
+/// assert_eq!(words, &[u16::from_le(0x0001), u16::from_le(0x0002)]);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+pub fn transmute_many_into_uninit<'a, 'd, T: TriviallyTransmutable, G: Guard>(bytes: &'a [u8], dest: &'d mut [MaybeUninit<T>])
+                                                                             -> Result<&'d mut [T], Error<'a, u8, T>> {
+    let len = check_capacity::<T, G>(bytes, dest.len())?;
+    unsafe {
+        // `dest` holds at least `len` values, and `bytes` at least `len * size_of::<T>()` bytes
+        ptr::copy_nonoverlapping(bytes.as_ptr(), dest.as_mut_ptr() as *mut u8, len * size_of::<T>());
+        Ok(slice::from_raw_parts_mut(dest.as_mut_ptr() as *mut T, len))
+    }
+}
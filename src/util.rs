@@ -1,6 +1,10 @@
 //! Module containing various utility functions.
 
 
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+
+
 /// Retrieve the result of a transmutation,
 /// copying the data if it could not be safely performed due to memory alignment constraints.
 ///
@@ -86,6 +90,304 @@ macro_rules! try_copy_unchecked {
     }}
 }
 
+/// Include a file as a byte slice, aligned in memory as the given type.
+///
+/// This is equivalent to `include_bytes!()`, except that the resulting
+/// `&'static [u8]` is guaranteed to start at an address compatible with the
+/// alignment of the specified type, so that transmuting it into a slice
+/// of that type will not fail due to memory alignment constraints.
+///
+/// As with `include_bytes!()`, the file is located relative to the current file.
+///
+/// The bytes are stored in an [`Aligned`](aligned/struct.Aligned.html), and
+/// any of its alignment types, like `A16`, can be given instead of a type.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate safe_transmute;
+/// # use safe_transmute::{PermissiveGuard, transmute_many};
+/// # fn main() {
+/// # /*
+/// let bytes = include_bytes_aligned!(u32, "table.bin");
+/// # */
+/// # let bytes = include_bytes_aligned!(u32, "../LICENSE");
+/// let table = transmute_many::<u32, PermissiveGuard>(bytes).unwrap();
+///
+/// assert_eq!(table.len(), bytes.len() / 4);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! include_bytes_aligned {
+    ($align_ty:ty, $path:expr) => {{
+        static ALIGNED: &$crate::aligned::Aligned<$align_ty, [u8]> = &$crate::aligned::Aligned::new(*include_bytes!($path));
+
+        &**ALIGNED
+    }}
+}
+
+/// Copy a sequence of values of different types out of a byte slice, one
+/// right after another.
+///
+/// Each field is given as `name: Type`, and copied out with an unaligned
+/// read, so that neither the byte slice nor the field offsets need to be
+/// aligned; no padding is assumed between fields. Bytes after the last field
+/// are ignored.
+///
+/// This expands into a single expression of type
+/// `Result<(Type, ...), SequenceError>`, with one tuple element per field.
+///
+/// # Errors
+///
+/// A [`SequenceError`](error/struct.SequenceError.html) naming the first
+/// field which does not fit in the remaining bytes, and its offset.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate safe_transmute;
+/// # use safe_transmute::error::SequenceError;
+/// # fn main() -> Result<(), SequenceError> {
+/// let bytes = [b'W', b'A', b'V', b'E', 0x02, 0x00, 0x10, 0x00, 0x44, 0xAC, 0x00, 0x00];
+///
+/// let (magic, channels, bits, rate) = transmute_sequence!(bytes => magic: [u8; 4], channels: u16, bits: u16, rate: u32)?;
+/// assert_eq!(&magic, b"WAVE");
+/// assert_eq!((channels, bits, rate), (u16::from_le(2), u16::from_le(16), u32::from_le(44100)));
+///
+/// let err = transmute_sequence!(&bytes[..10] => magic: [u8; 4], channels: u16, bits: u16, rate: u32).unwrap_err();
+/// assert_eq!((err.field, err.offset), ("rate", 8));
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! transmute_sequence {
+    ($bytes:expr => $($field:ident: $ty:ty),+ $(,)*) => {{
+        let bytes: &[u8] = &$bytes[..];
+        let mut offset = 0;
+        (|| -> Result<_, $crate::error::SequenceError> {
+            Ok(($($crate::stream::read_sequence_field::<$ty>(bytes, &mut offset, stringify!($field))?,)+))
+        })()
+    }}
+}
+
+/// Implement [`TriviallyTransmutable`](trivial/trait.TriviallyTransmutable.html)
+/// for the given types.
+///
+/// Each type can be followed by its expected layout, as
+/// `{ size: N, align: M }`, which is then asserted at compile time, so that
+/// changes to the type that break assumptions made when writing the
+/// implementation, like additional fields or padding, are caught.
+///
+/// # Safety
+///
+/// Each type must uphold the requirements of
+/// [`TriviallyTransmutable`](trivial/trait.TriviallyTransmutable.html); the
+/// assertions only check its size and alignment.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate safe_transmute;
+/// # use safe_transmute::{SingleValueGuard, transmute_one};
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Pixel {
+///     rgba: [u8; 4],
+/// }
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     width: u16,
+///     height: u16,
+/// }
+///
+/// unsafe_trivially_transmutable!(Pixel, Header { size: 8, align: 4 });
+/// # fn main() {
+/// # let _ = transmute_one::<Header>(&[0; 8]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! unsafe_trivially_transmutable {
+    ($($ty:ty $({ size: $size:expr, align: $align:expr })?),+ $(,)?) => {
+        $(
+            unsafe impl $crate::TriviallyTransmutable for $ty {}
+
+            $(
+                const _: () = assert!($crate::__mem::size_of::<$ty>() == $size,
+                                      concat!("size of ", stringify!($ty), " is not ", stringify!($size)));
+                const _: () = assert!($crate::__mem::align_of::<$ty>() == $align,
+                                      concat!("alignment of ", stringify!($ty), " is not ", stringify!($align)));
+            )?
+        )+
+    }
+}
+
+/// Assert at compile time that a struct is made up of the given trivially
+/// transmutable fields, without any padding in between.
+///
+/// All fields of the struct have to be listed with their types. Compilation
+/// fails if any of them is missing, has a different type, or is not trivially
+/// transmutable, or if the size of the struct is not the sum of the sizes of
+/// its fields.
+///
+/// This does not implement
+/// [`TriviallyTransmutable`](trivial/trait.TriviallyTransmutable.html), but
+/// can guard a hand-written implementation thereof. Only structs with named
+/// fields are supported.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate safe_transmute;
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     width: u16,
+///     height: u16,
+/// }
+///
+/// assert_trivially_transmutable!(Header {
+///     magic: u32,
+///     width: u16,
+///     height: u16,
+/// });
+/// unsafe impl safe_transmute::TriviallyTransmutable for Header {}
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! assert_trivially_transmutable {
+    ($name:path { $($field:ident: $field_ty:ty),* $(,)? }) => {
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_fields(value: $name) {
+                fn assert_trivially_transmutable<T: $crate::TriviallyTransmutable>(_: T) {}
+
+                let $name { $($field),* } = value;
+                $(
+                    let $field: $field_ty = $field;
+                    assert_trivially_transmutable($field);
+                )*
+            }
+
+            assert!($crate::__mem::size_of::<$name>() == 0 $(+ $crate::__mem::size_of::<$field_ty>())*,
+                    concat!(stringify!($name), " contains padding"));
+        };
+    }
+}
+
+
+/// Floating-point type whose signaling NaNs can be made quiet.
+///
+/// Implemented for `f32` and `f64`, and, with the `half` feature, for
+/// `half::f16` and `half::bf16`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::util::Designalise;
+/// fn scrub<F: Designalise>(values: &mut [F]) {
+///     for v in values {
+///         *v = v.designalise();
+///     }
+/// }
+///
+/// let mut samples = [f32::from_bits(0x7F80_0001), 1.5];
+/// scrub(&mut samples);
+///
+/// assert_eq!(samples[0].to_bits(), 0x7FC0_0001);
+/// assert_eq!(samples[1], 1.5);
+/// ```
+pub trait Designalise: Copy {
+    /// The unsigned integer type of the same width.
+    type Bits;
+
+    /// If this is a signaling NaN, make it a quiet NaN.
+    fn designalise(self) -> Self;
+
+    /// Reinterpret the given bits as a float. If they are a signaling NaN
+    /// once interpreted, make it a quiet NaN.
+    fn from_bits_designalised(bits: Self::Bits) -> Self;
+}
+
+impl Designalise for f32 {
+    type Bits = u32;
+
+    #[inline]
+    fn designalise(self) -> f32 {
+        designalise_f32(self)
+    }
+
+    #[inline]
+    fn from_bits_designalised(bits: u32) -> f32 {
+        from_bits_f32_designalised(bits)
+    }
+}
+
+impl Designalise for f64 {
+    type Bits = u64;
+
+    #[inline]
+    fn designalise(self) -> f64 {
+        designalise_f64(self)
+    }
+
+    #[inline]
+    fn from_bits_designalised(bits: u64) -> f64 {
+        from_bits_f64_designalised(bits)
+    }
+}
+
+#[cfg(feature = "half")]
+impl Designalise for f16 {
+    type Bits = u16;
+
+    #[inline]
+    fn designalise(self) -> f16 {
+        f16::from_bits_designalised(self.to_bits())
+    }
+
+    fn from_bits_designalised(mut bits: u16) -> f16 {
+        const EXP_MASK: u16 = 0x7C00;
+        const QNAN_MASK: u16 = 0x0200;
+        const FRACT_MASK: u16 = 0x03FF;
+
+        if bits & EXP_MASK == EXP_MASK && bits & FRACT_MASK != 0 {
+            bits |= QNAN_MASK;
+        }
+
+        f16::from_bits(bits)
+    }
+}
+
+#[cfg(feature = "half")]
+impl Designalise for bf16 {
+    type Bits = u16;
+
+    #[inline]
+    fn designalise(self) -> bf16 {
+        bf16::from_bits_designalised(self.to_bits())
+    }
+
+    fn from_bits_designalised(mut bits: u16) -> bf16 {
+        const EXP_MASK: u16 = 0x7F80;
+        const QNAN_MASK: u16 = 0x0040;
+        const FRACT_MASK: u16 = 0x007F;
+
+        if bits & EXP_MASK == EXP_MASK && bits & FRACT_MASK != 0 {
+            bits |= QNAN_MASK;
+        }
+
+        bf16::from_bits(bits)
+    }
+}
+
 
 /// If the specified 32-bit float is a signaling NaN, make it a quiet NaN.
 ///
@@ -124,7 +426,7 @@ pub fn from_bits_f32_designalised(mut bits: u32) -> f32 {
 /// signaling NaN once interpreted, make it a quiet NaN.
 pub fn from_bits_f64_designalised(mut bits: u64) -> f64 {
     const EXP_MASK: u64 = 0x7FF0_0000_0000_0000;
-    const QNAN_MASK: u64 = 0x0001_0000_0000_0000;
+    const QNAN_MASK: u64 = 0x0008_0000_0000_0000;
     const FRACT_MASK: u64 = 0x000F_FFFF_FFFF_FFFF;
 
     if bits & EXP_MASK == EXP_MASK && bits & FRACT_MASK != 0 {
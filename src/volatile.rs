@@ -0,0 +1,75 @@
+//! Volatile access to values in byte slices.
+//!
+//! Memory-mapped peripheral registers must be read and written with volatile
+//! operations, so that the compiler neither elides nor merges the accesses.
+//! The functions in this module check that a register region holds a value of
+//! the given type, and that it is properly aligned for it, before accessing
+//! it.
+
+
+use self::super::align::{check_alignment_mut, check_alignment};
+use self::super::trivial::TriviallyTransmutable;
+use self::super::guard::{SingleManyGuard, Guard};
+use self::super::Error;
+use core::ptr;
+
+
+/// Read a single value out of a byte slice with a volatile read.
+///
+/// The byte slice must have at least enough bytes to fill a single instance of
+/// a type, extraneous data is ignored.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`.
+/// - The data does not have enough bytes for a single value `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::volatile::read_volatile_one;
+/// # use safe_transmute::transmute_to_bytes;
+/// let registers = [0x0000_0001u32, 0xDEAD_BEEF];
+/// let bytes = transmute_to_bytes(&registers);
+///
+/// assert_eq!(read_volatile_one::<u32>(&bytes[4..]), Ok(0xDEAD_BEEF));
+/// assert!(read_volatile_one::<u32>(&bytes[1..]).is_err());
+/// ```
+pub fn read_volatile_one<T: TriviallyTransmutable>(bytes: &[u8]) -> Result<T, Error<'_, u8, T>> {
+    check_alignment::<_, T>(bytes)?;
+    SingleManyGuard::check::<T>(bytes)?;
+    // The data is aligned for `T`, and holds at least one value
+    Ok(unsafe { ptr::read_volatile(bytes.as_ptr() as *const T) })
+}
+
+/// Write a single value into a byte slice with a volatile write.
+///
+/// The byte slice must have at least enough bytes to fill a single instance of
+/// a type, extraneous data is left as it is.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`.
+/// - The data does not have enough bytes for a single value `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::volatile::write_volatile_one;
+/// # use safe_transmute::transmute_to_bytes_mut;
+/// let mut registers = [0u32; 2];
+/// write_volatile_one::<u32>(&mut transmute_to_bytes_mut(&mut registers)[4..], 0x8000_0000).unwrap();
+///
+/// assert_eq!(registers, [0, 0x8000_0000]);
+/// ```
+pub fn write_volatile_one<T: TriviallyTransmutable>(bytes: &mut [u8], value: T) -> Result<(), Error<'_, u8, T>> {
+    let bytes = check_alignment_mut::<_, T>(bytes)?;
+    SingleManyGuard::check::<T>(bytes)?;
+    // The data is aligned for `T`, holds at least one value, and is borrowed exclusively
+    unsafe { ptr::write_volatile(bytes.as_mut_ptr() as *mut T, value) };
+    Ok(())
+}
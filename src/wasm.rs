@@ -0,0 +1,68 @@
+//! Copies of JavaScript binary data into vectors of values, through `js-sys`.
+//!
+//! The memory of a JavaScript `ArrayBuffer` is not a part of the WebAssembly
+//! module's own linear memory, and can be detached or modified by any call
+//! into JavaScript, so it can not be viewed in place. The functions in this
+//! module instead copy it straight into a vector, aligned for the target type,
+//! so that binary payloads, like fetched files or WebSocket messages, are
+//! decoded with a single copy.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # extern crate safe_transmute;
+//! # extern crate js_sys;
+//! # use safe_transmute::wasm::transmute_uint8_array;
+//! # use safe_transmute::PedanticGuard;
+//! # use js_sys::Uint8Array;
+//! # fn main() {
+//! let array = Uint8Array::from(&[0x01, 0x00, 0x02, 0x00][..]);
+//!
+//! assert_eq!(transmute_uint8_array::<u16, PedanticGuard>(&array), Ok(vec![u16::from_le(1), u16::from_le(2)]));
+//! # }
+//! ```
+
+
+use self::super::trivial::TriviallyTransmutable;
+use js_sys::{ArrayBuffer, Uint8Array};
+use self::super::guard::Guard;
+use self::super::Error;
+use core::mem::size_of;
+use alloc::vec::Vec;
+
+
+/// Copy the contents of a `Uint8Array` into a vector of values.
+///
+/// The required byte length of the array depends on the chosen boundary guard.
+/// Please see the [Guard API](../guard/index.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `G`.
+pub fn transmute_uint8_array<T: TriviallyTransmutable, G: Guard>(array: &Uint8Array) -> Result<Vec<T>, Error<'static, u8, T>> {
+    let len = G::check_len::<T>(array.length() as usize)?;
+    if size_of::<T>() == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut values = Vec::<T>::with_capacity(len);
+    unsafe {
+        // The vector has room for the `len` values' bytes, which are all valid thanks to `TriviallyTransmutable`
+        array.subarray(0, (len * size_of::<T>()) as u32).raw_copy_to_ptr(values.as_mut_ptr() as *mut u8);
+        values.set_len(len);
+    }
+    Ok(values)
+}
+
+/// Copy the contents of an `ArrayBuffer` into a vector of values.
+///
+/// See [`transmute_uint8_array()`](fn.transmute_uint8_array.html) for details.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `G`.
+pub fn transmute_array_buffer<T: TriviallyTransmutable, G: Guard>(buffer: &ArrayBuffer) -> Result<Vec<T>, Error<'static, u8, T>> {
+    transmute_uint8_array::<T, G>(&Uint8Array::new(buffer))
+}
@@ -0,0 +1,53 @@
+use safe_transmute::align::{check_alignment_to, check_ptr_alignment, offset_to_aligned, misalignment};
+use safe_transmute::aligned::{Aligned, A8};
+use core::mem::align_of;
+use core::ptr;
+
+
+#[test]
+fn ptr_alignment() {
+    let bytes = Aligned::<A8, _>::new([0u8; 16]);
+
+    assert_eq!(check_ptr_alignment::<u64>(bytes.as_ptr()), Ok(()));
+    assert_eq!(check_ptr_alignment::<u64>(bytes[1..].as_ptr()), Err(align_of::<u64>() - 1));
+    assert_eq!(check_ptr_alignment::<u16>(bytes[1..].as_ptr()), Err(1));
+    assert_eq!(check_ptr_alignment::<u16>(bytes[2..].as_ptr()), Ok(()));
+    assert_eq!(check_ptr_alignment::<u8>(bytes[3..].as_ptr()), Ok(()));
+    assert_eq!(check_ptr_alignment::<u64>(ptr::null()), Ok(()));
+}
+
+#[test]
+fn alignment_to() {
+    #[repr(C, align(32))]
+    struct Block([u8; 64]);
+    let block = Block([0; 64]);
+
+    assert_eq!(check_alignment_to(&block.0[..], 32), Some(Ok(())));
+    assert_eq!(check_alignment_to(&block.0[..], 16), Some(Ok(())));
+    assert_eq!(check_alignment_to(&block.0[16..], 32), Some(Err(16)));
+    assert_eq!(check_alignment_to(&block.0[16..], 16), Some(Ok(())));
+    assert_eq!(check_alignment_to(&block.0[31..], 32), Some(Err(1)));
+    assert_eq!(check_alignment_to(&block.0[5..], 1), Some(Ok(())));
+}
+
+#[test]
+fn alignment_to_not_power_of_two() {
+    assert_eq!(check_alignment_to(&[0u8; 4], 3), None);
+    assert_eq!(check_alignment_to(&[0u8; 4], 0), None);
+}
+
+#[test]
+fn introspection() {
+    let bytes = Aligned::<A8, _>::new([0u8; 16]);
+
+    for start in 0..8 {
+        assert_eq!(misalignment::<u64>(&bytes[start..]), start % align_of::<u64>());
+        assert_eq!(offset_to_aligned::<u64>(&bytes[start..]), (align_of::<u64>() - start % align_of::<u64>()) % align_of::<u64>());
+        assert_eq!(misalignment::<u32>(&bytes[start..]), start % 4);
+        assert_eq!(offset_to_aligned::<u32>(&bytes[start..]), (4 - start % 4) % 4);
+        assert_eq!(misalignment::<u8>(&bytes[start..]), 0);
+        assert_eq!(offset_to_aligned::<u8>(&bytes[start..]), 0);
+    }
+    assert_eq!(offset_to_aligned::<u32>(&bytes[15..]), 1);
+    assert_eq!(offset_to_aligned::<u32>(&bytes[15..15]), 1);
+}
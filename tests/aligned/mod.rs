@@ -0,0 +1,54 @@
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, Aligned, transmute_aligned};
+use safe_transmute::aligned::{A16, A64, A2, A8};
+use core::mem::{align_of, size_of};
+
+
+static WORDS: Aligned<A8, [u8; 16]> = Aligned::new([0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+
+
+#[test]
+fn alignments() {
+    assert_eq!(align_of::<Aligned<A2, [u8; 3]>>(), 2);
+    assert_eq!(align_of::<Aligned<A16, u8>>(), 16);
+    assert_eq!(align_of::<Aligned<u32, u8>>(), align_of::<u32>());
+    assert_eq!(size_of::<Aligned<A64, u8>>(), 64);
+    assert_eq!(size_of::<Aligned<A8, [u8; 16]>>(), 16);
+
+    assert_eq!((WORDS.as_ptr() as usize) % 8, 0);
+    assert_eq!((Aligned::<A64, _>::new([0u8; 3]).as_ptr() as usize) % 64, 0);
+}
+
+#[test]
+fn value() {
+    let mut value = Aligned::<A8, _>::new([1u8, 2, 3]);
+    value[0] = 4;
+    assert_eq!(*value, [4, 2, 3]);
+    assert_eq!(value, Aligned::new([4, 2, 3]));
+    assert_eq!(value.into_inner(), [4, 2, 3]);
+    assert_eq!(Aligned::<A2, [u8; 2]>::default().into_inner(), [0, 0]);
+}
+
+#[test]
+fn static_bytes() {
+    assert_eq!(transmute_aligned::<u64, _, PedanticGuard>(&WORDS), Ok(&[u64::from_le(1), u64::from_le(2)][..]));
+    assert_eq!(transmute_aligned::<u32, _, PedanticGuard>(&WORDS), Ok(&[u32::from_le(1), 0, u32::from_le(2), 0][..]));
+}
+
+#[test]
+fn guard() {
+    let bytes = Aligned::<A8, _>::new([0xFFu8; 10]);
+
+    assert_eq!(transmute_aligned::<u64, _, SingleManyGuard>(&bytes), Ok(&[0xFFFF_FFFF_FFFF_FFFF][..]));
+    assert_eq!(transmute_aligned::<u64, _, PedanticGuard>(&bytes),
+               Err(GuardError {
+                   required: 8,
+                   actual: 10,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+    assert_eq!(transmute_aligned::<u64, _, SingleManyGuard>(&Aligned::<A8, [u8; 0]>::new([])),
+               Err(GuardError {
+                   required: 8,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
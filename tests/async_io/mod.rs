@@ -0,0 +1,87 @@
+use std::task::{Context, Waker, Poll, Wake};
+use futures_io::{AsyncWrite, AsyncRead};
+use std::sync::Arc;
+use std::pin::Pin;
+use std::io;
+
+mod reader;
+mod writer;
+
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+/// Poll the given function until it's ready, spinning on `Pending`.
+fn block_on<R, F: FnMut(&mut Context) -> Poll<R>>(mut f: F) -> R {
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(r) = f(&mut cx) {
+            return r;
+        }
+    }
+}
+
+
+/// An IO object transferring at most `chunk` bytes at a time, pending before every transfer.
+struct Stutter {
+    data: Vec<u8>,
+    chunk: usize,
+    pending: bool,
+}
+
+impl Stutter {
+    fn new(data: Vec<u8>, chunk: usize) -> Stutter {
+        Stutter {
+            data: data,
+            chunk: chunk,
+            pending: false,
+        }
+    }
+
+    fn stutter(&mut self, cx: &mut Context) -> bool {
+        self.pending = !self.pending;
+        if self.pending {
+            cx.waker().wake_by_ref();
+        }
+        self.pending
+    }
+}
+
+impl AsyncRead for Stutter {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        if this.stutter(cx) {
+            return Poll::Pending;
+        }
+
+        let len = this.chunk.min(buf.len()).min(this.data.len());
+        buf[..len].copy_from_slice(&this.data[..len]);
+        this.data.drain(..len);
+        Poll::Ready(Ok(len))
+    }
+}
+
+impl AsyncWrite for Stutter {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        if this.stutter(cx) {
+            return Poll::Pending;
+        }
+
+        let len = this.chunk.min(buf.len());
+        this.data.extend_from_slice(&buf[..len]);
+        Poll::Ready(Ok(len))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
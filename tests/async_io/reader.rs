@@ -0,0 +1,43 @@
+use safe_transmute::{AsyncTransmuteReader, transmute_to_bytes};
+use self::super::{Stutter, block_on};
+use std::io::{self, ErrorKind};
+use futures_core::Stream;
+use std::pin::Pin;
+
+
+fn collect<S: Stream + Unpin>(mut stream: S) -> Vec<S::Item> {
+    let mut items = Vec::new();
+    while let Some(item) = block_on(|cx| Pin::new(&mut stream).poll_next(cx)) {
+        items.push(item);
+    }
+    items
+}
+
+
+#[test]
+fn empty() {
+    assert!(collect(AsyncTransmuteReader::<_, u32>::new(&[][..])).is_empty());
+}
+
+#[test]
+fn whole() {
+    let words: &[u64] = &[0x0100, 0x0200, 0x0300];
+    let reader = AsyncTransmuteReader::<_, u64>::new(transmute_to_bytes(words));
+    assert_eq!(collect(reader).into_iter().collect::<io::Result<Vec<_>>>().unwrap(), words);
+}
+
+#[test]
+fn split() {
+    let words: &[u32] = &[1, 2, 3, 4, 5, 6, 7];
+    let reader = AsyncTransmuteReader::<_, u32>::with_capacity(2, Stutter::new(transmute_to_bytes(words).to_vec(), 3));
+    assert_eq!(collect(reader).into_iter().collect::<io::Result<Vec<_>>>().unwrap(), words);
+}
+
+#[test]
+fn unexpected_eof() {
+    let words: &[u32] = &[1, 2];
+    let mut values = collect(AsyncTransmuteReader::<_, u32>::new(&transmute_to_bytes(words)[..7])).into_iter();
+    assert_eq!(values.next().unwrap().unwrap(), 1);
+    assert_eq!(values.next().unwrap().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    assert!(values.next().is_none());
+}
@@ -0,0 +1,31 @@
+use safe_transmute::{AsyncTransmuteWriter, transmute_to_bytes};
+use self::super::{Stutter, block_on};
+use futures_sink::Sink;
+use std::pin::Pin;
+use std::io;
+
+
+fn send_all<T: Copy, S: Sink<T, Error = io::Error> + Unpin>(sink: &mut S, values: &[T]) -> io::Result<()> {
+    for &value in values {
+        block_on(|cx| Pin::new(&mut *sink).poll_ready(cx))?;
+        Pin::new(&mut *sink).start_send(value)?;
+    }
+    block_on(|cx| Pin::new(&mut *sink).poll_close(cx))
+}
+
+
+#[test]
+fn whole() {
+    let words: &[u64] = &[0x0100, 0x0200, 0x0300];
+    let mut writer = AsyncTransmuteWriter::<_, u64>::new(Vec::new());
+    send_all(&mut writer, words).unwrap();
+    assert_eq!(writer.into_inner(), transmute_to_bytes(words));
+}
+
+#[test]
+fn split() {
+    let words: &[u32] = &[1, 2, 3, 4, 5, 6, 7];
+    let mut writer = AsyncTransmuteWriter::<_, u32>::with_capacity(2, Stutter::new(Vec::new(), 3));
+    send_all(&mut writer, words).unwrap();
+    assert_eq!(writer.get_ref().data, transmute_to_bytes(words));
+}
@@ -0,0 +1,52 @@
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, Error, transmute_to_bytes_mut, transmute_many_atomic};
+use core::sync::atomic::{AtomicU16, AtomicU32, Ordering};
+
+
+#[test]
+fn too_short() {
+    let mut words = [0u32; 1];
+    match transmute_many_atomic::<AtomicU32, SingleManyGuard>(&mut transmute_to_bytes_mut(&mut words)[..3]) {
+        Err(Error::Guard(e)) => {
+            assert_eq!(e,
+                       GuardError {
+                           required: 4,
+                           actual: 3,
+                           reason: ErrorReason::NotEnoughBytes,
+                       })
+        }
+        _ => panic!("expected a guard error"),
+    }
+}
+
+#[test]
+fn unaligned() {
+    let mut words = [0u32; 2];
+    match transmute_many_atomic::<AtomicU32, SingleManyGuard>(&mut transmute_to_bytes_mut(&mut words)[1..]) {
+        Err(Error::Unaligned(e)) => assert_eq!(e.offset, 3),
+        _ => panic!("expected an unaligned error"),
+    }
+}
+
+#[test]
+fn just_enough() {
+    let mut words = [1u32, 2, 3];
+    {
+        let atomics = transmute_many_atomic::<AtomicU32, PedanticGuard>(transmute_to_bytes_mut(&mut words)).unwrap();
+        assert_eq!(atomics.len(), 3);
+        assert_eq!(atomics[0].fetch_add(10, Ordering::Relaxed), 1);
+        atomics[2].store(7, Ordering::Relaxed);
+    }
+    assert_eq!(words, [11, 2, 7]);
+}
+
+#[test]
+fn narrower() {
+    let mut words = [0u32; 1];
+    {
+        let atomics = transmute_many_atomic::<AtomicU16, PedanticGuard>(transmute_to_bytes_mut(&mut words)).unwrap();
+        atomics[0].store(0x0102, Ordering::Relaxed);
+        atomics[1].store(0x0304, Ordering::Relaxed);
+    }
+    let expected: [u16; 2] = [0x0102, 0x0304];
+    assert_eq!(transmute_to_bytes_mut(&mut words), safe_transmute::transmute_to_bytes(&expected));
+}
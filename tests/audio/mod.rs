@@ -0,0 +1,75 @@
+use safe_transmute::{ErrorReason, GuardError, Error, transmute_frames_endian, transmute_samples_i16, transmute_samples_f32, transmute_to_bytes_mut,
+                     transmute_frames_mut, transmute_to_bytes, transmute_frames};
+use safe_transmute::endian::{LittleEndian, NativeEndian, BigEndian};
+#[cfg(feature = "alloc")]
+use safe_transmute::decode_samples;
+
+
+#[test]
+fn samples() {
+    let mut data = [0x0080u16.to_be(), 0xFF7Fu16.to_be()];
+    assert_eq!(transmute_samples_i16::<BigEndian>(transmute_to_bytes_mut(&mut data)), Ok(&mut [128, -129][..]));
+
+    let mut data = [1.5f32.to_bits().to_le()];
+    assert_eq!(transmute_samples_f32::<LittleEndian>(transmute_to_bytes_mut(&mut data)), Ok(&mut [1.5][..]));
+
+    let mut data = [0u16; 2];
+    assert_eq!(transmute_samples_i16::<NativeEndian>(&mut transmute_to_bytes_mut(&mut data)[..0]), Ok(&mut [][..]));
+}
+
+#[test]
+fn partial_sample() {
+    let mut data = [0u16; 2];
+    assert_eq!(transmute_samples_i16::<BigEndian>(&mut transmute_to_bytes_mut(&mut data)[..3]),
+               Err(Error::Guard(GuardError {
+                   required: 2,
+                   actual: 3,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn frames() {
+    let samples = [1i16, -1, 2, -2, 3, -3];
+    let bytes = transmute_to_bytes(&samples);
+
+    assert_eq!(transmute_frames::<i16, 2>(bytes), Ok(&[[1, -1], [2, -2], [3, -3]][..]));
+    assert_eq!(transmute_frames::<i16, 6>(bytes), Ok(&[[1, -1, 2, -2, 3, -3]][..]));
+    assert_eq!(transmute_frames::<i16, 4>(bytes),
+               Err(Error::Guard(GuardError {
+                   required: 8,
+                   actual: 12,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn frames_mut() {
+    let mut samples = [0.0f32; 4];
+    transmute_frames_mut::<f32, 2>(transmute_to_bytes_mut(&mut samples)).unwrap()[1][0] = 0.5;
+    assert_eq!(samples, [0.0, 0.0, 0.5, 0.0]);
+}
+
+#[test]
+fn frames_endian() {
+    let mut data = [1i32.to_le(), (-1i32).to_le(), 2i32.to_le(), (-2i32).to_le()];
+    assert_eq!(transmute_frames_endian::<i32, LittleEndian, 2>(transmute_to_bytes_mut(&mut data)), Ok(&mut [[1, -1], [2, -2]][..]));
+
+    let mut data = [0x0102u16.to_be(); 3];
+    assert_eq!(transmute_frames_endian::<u16, BigEndian, 3>(transmute_to_bytes_mut(&mut data)), Ok(&mut [[0x0102; 3]][..]));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn decode() {
+    assert_eq!(decode_samples::<i16, BigEndian>(&[0x80, 0x00, 0x7F, 0xFF, 0x00, 0x01]), Ok(vec![i16::MIN, i16::MAX, 1]));
+    assert_eq!(decode_samples::<f32, LittleEndian>(&[0x00, 0x00, 0x80, 0xBF]), Ok(vec![-1.0]));
+    // Unaligned data is fine
+    assert_eq!(decode_samples::<i16, LittleEndian>(&[0x00, 0x01, 0x00][1..]), Ok(vec![1]));
+    assert_eq!(decode_samples::<i16, LittleEndian>(&[0x01, 0x00, 0x02]),
+               Err(Error::Guard(GuardError {
+                   required: 2,
+                   actual: 3,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
@@ -1,4 +1,5 @@
 mod transmute_many_permissive;
+mod transmute_ptr;
 mod transmute_many_pedantic;
 mod from_bytes_pedantic;
 mod transmute_many;
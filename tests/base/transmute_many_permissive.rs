@@ -40,3 +40,10 @@ fn too_much() {
                    Ok(&words[..3]));
     }
 }
+
+#[test]
+fn zero_sized() {
+    unsafe {
+        assert_eq!(safe_transmute::base::transmute_many_permissive::<()>(&[0x00, 0x01]), &[]);
+    }
+}
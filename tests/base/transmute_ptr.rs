@@ -0,0 +1,45 @@
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, Error, transmute_to_bytes_mut, transmute_to_bytes};
+use safe_transmute::base::{transmute_ptr_mut, transmute_ptr};
+use core::ptr;
+
+
+#[test]
+fn too_short() {
+    assert_eq!(transmute_ptr::<u16, SingleManyGuard>(ptr::null(), 1),
+               Err(Error::Guard(GuardError {
+                   required: 16 / 8,
+                   actual: 1,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+    assert_eq!(transmute_ptr_mut::<u32, PedanticGuard>(ptr::null_mut(), 6),
+               Err(Error::Guard(GuardError {
+                   required: 32 / 8,
+                   actual: 6,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn just_enough() {
+    let words: &[u16] = &[0x0100, 0x0200, 0x0300];
+    let bytes = transmute_to_bytes(words);
+
+    let values = transmute_ptr::<u16, PedanticGuard>(bytes.as_ptr(), bytes.len()).unwrap();
+    assert_eq!(values, words.as_ptr());
+    unsafe {
+        assert_eq!(*values.add(2), 0x0300);
+    }
+}
+
+#[test]
+fn mutable() {
+    let mut words = [0x0100u16, 0x0200];
+    {
+        let bytes = transmute_to_bytes_mut(&mut words);
+        let values = transmute_ptr_mut::<u16, SingleManyGuard>(bytes.as_mut_ptr(), bytes.len()).unwrap();
+        unsafe {
+            *values.add(1) = 0xFFFF;
+        }
+    }
+    assert_eq!(words, [0x0100, 0xFFFF]);
+}
@@ -0,0 +1,25 @@
+use safe_transmute::{SingleManyGuard, ErrorReason, GuardError, Error, transmute_bool};
+use safe_transmute::guard::{ExactCountGuard, PermissiveGuard};
+
+
+#[test]
+fn valid() {
+    assert_eq!(transmute_bool::<PermissiveGuard>(&[]), Ok(&[][..]));
+    assert_eq!(transmute_bool::<SingleManyGuard>(&[0x01, 0x00, 0x01]), Ok(&[true, false, true][..]));
+    assert_eq!(transmute_bool::<ExactCountGuard<2>>(&[0x00, 0x01]), Ok(&[false, true][..]));
+}
+
+#[test]
+fn guarded() {
+    assert_eq!(transmute_bool::<ExactCountGuard<2>>(&[0x00, 0x01, 0x00]),
+               Err(Error::Guard(GuardError {
+                   required: 2,
+                   actual: 3,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn invalid_bytes() {
+    assert_eq!(transmute_bool::<PermissiveGuard>(&[0x00, 0x02]), Err(Error::InvalidValue));
+}
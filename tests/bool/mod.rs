@@ -2,3 +2,7 @@ mod vec_permissive;
 mod vec_pedantic;
 mod permissive;
 mod pedantic;
+mod guarded;
+mod mutable;
+#[cfg(feature = "alloc")]
+mod vec;
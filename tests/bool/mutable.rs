@@ -0,0 +1,33 @@
+use safe_transmute::{SingleManyGuard, ErrorReason, GuardError, Error, transmute_bool_mut};
+use safe_transmute::guard::PermissiveGuard;
+
+
+#[test]
+fn valid() {
+    let mut bytes = [0x00, 0x01, 0x00];
+    {
+        let flags = transmute_bool_mut::<SingleManyGuard>(&mut bytes).unwrap();
+        assert_eq!(flags, &[false, true, false]);
+        flags[2] = true;
+    }
+    assert_eq!(bytes, [0x00, 0x01, 0x01]);
+
+    assert_eq!(transmute_bool_mut::<PermissiveGuard>(&mut []), Ok(&mut [][..]));
+}
+
+#[test]
+fn too_short() {
+    assert_eq!(transmute_bool_mut::<SingleManyGuard>(&mut []),
+               Err(Error::Guard(GuardError {
+                   required: 1,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn invalid_bytes() {
+    let mut bytes = [0x00, 0x01, 0xFF];
+    assert_eq!(transmute_bool_mut::<SingleManyGuard>(&mut bytes), Err(Error::InvalidValue));
+    assert_eq!(bytes, [0x00, 0x01, 0xFF]);
+}
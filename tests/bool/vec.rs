@@ -0,0 +1,29 @@
+use safe_transmute::{SingleManyGuard, ErrorReason, GuardError, Error, transmute_bool_vec};
+use safe_transmute::guard::{ExactCountGuard, PermissiveGuard};
+
+
+#[test]
+fn valid() {
+    let bytes = vec![0x00, 0x01, 0x01];
+    let ptr = bytes.as_ptr();
+    let flags = transmute_bool_vec::<SingleManyGuard>(bytes).unwrap();
+    assert_eq!(flags, vec![false, true, true]);
+    assert_eq!(flags.as_ptr() as *const u8, ptr);
+
+    assert_eq!(transmute_bool_vec::<PermissiveGuard>(vec![]), Ok(vec![]));
+}
+
+#[test]
+fn guarded() {
+    assert_eq!(transmute_bool_vec::<ExactCountGuard<4>>(vec![0x00, 0x01]),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 2,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn invalid_bytes() {
+    assert_eq!(transmute_bool_vec::<PermissiveGuard>(vec![0x00, 0x03]), Err(Error::InvalidValue));
+}
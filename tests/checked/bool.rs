@@ -0,0 +1,15 @@
+use safe_transmute::{SingleManyGuard, Error, transmute_many_checked, transmute_one_checked};
+
+
+#[test]
+fn many() {
+    assert_eq!(transmute_many_checked::<bool, SingleManyGuard>(&[0x00, 0x01]), Ok(&[false, true][..]));
+    assert_eq!(transmute_many_checked::<bool, SingleManyGuard>(&[0x00, 0x01, 0x02, 0x01]),
+               Err(Error::InvalidValueAt { index: 2, bytes: &[0x02] }));
+}
+
+#[test]
+fn one() {
+    assert_eq!(transmute_one_checked::<bool>(&[0x01, 0x05]), Ok(true));
+    assert_eq!(transmute_one_checked::<bool>(&[0x05, 0x01]), Err(Error::InvalidValueAt { index: 0, bytes: &[0x05] }));
+}
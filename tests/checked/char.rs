@@ -0,0 +1,67 @@
+use safe_transmute::{SingleManyGuard, ErrorReason, GuardError, Error, transmute_many_checked, transmute_one_checked, transmute_to_bytes};
+use safe_transmute::guard::{AllOrNothingGuard, PermissiveGuard};
+
+
+#[test]
+fn valid() {
+    let utf32: &[u32] = &[0x0000, 0x007A, 0xD7FF, 0xE000, 0x01_F980, 0x10_FFFF];
+    assert_eq!(transmute_many_checked::<char, SingleManyGuard>(transmute_to_bytes(utf32)),
+               Ok(&['\0', 'z', '\u{D7FF}', '\u{E000}', '🦀', '\u{10FFFF}'][..]));
+    assert_eq!(transmute_many_checked::<char, PermissiveGuard>(&transmute_to_bytes(utf32)[..0]), Ok(&[][..]));
+}
+
+#[test]
+fn invalid() {
+    let utf32: &[u32] = &[0x0052, 0xD800, 0x0073, 0x11_0000];
+    assert_eq!(transmute_many_checked::<char, SingleManyGuard>(transmute_to_bytes(utf32)),
+               Err(Error::InvalidValueAt {
+                   index: 1,
+                   bytes: &0xD800u32.to_ne_bytes(),
+               }));
+    assert_eq!(transmute_many_checked::<char, SingleManyGuard>(&transmute_to_bytes(utf32)[8..]),
+               Err(Error::InvalidValueAt {
+                   index: 1,
+                   bytes: &0x11_0000u32.to_ne_bytes(),
+               }));
+    assert_eq!(transmute_many_checked::<char, SingleManyGuard>(&transmute_to_bytes(utf32)[..4]), Ok(&['R'][..]));
+}
+
+#[test]
+fn guarded() {
+    let utf32: &[u32] = &[0x0052, 0x0075];
+    assert_eq!(transmute_many_checked::<char, AllOrNothingGuard>(&transmute_to_bytes(utf32)[..6]),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 6,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn unaligned() {
+    let utf32: &[u32] = &[0x0052, 0x0075];
+    match transmute_many_checked::<char, SingleManyGuard>(&transmute_to_bytes(utf32)[1..]) {
+        Err(Error::Unaligned(e)) => assert_eq!(e.offset, 3),
+        r => panic!("expected an unaligned error, got {:?}", r),
+    }
+}
+
+#[test]
+fn one() {
+    let mut bytes = [0x00; 6];
+    bytes[1..5].copy_from_slice(&0x01_F980u32.to_ne_bytes());
+    assert_eq!(transmute_one_checked::<char>(&bytes[1..]), Ok('🦀'));
+    assert_eq!(transmute_one_checked::<char>(&bytes[1..4]),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+
+    bytes[1..5].copy_from_slice(&0xD800u32.to_ne_bytes());
+    assert_eq!(transmute_one_checked::<char>(&bytes[1..]),
+               Err(Error::InvalidValueAt {
+                   index: 0,
+                   bytes: &0xD800u32.to_ne_bytes(),
+               }));
+}
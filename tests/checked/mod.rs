@@ -0,0 +1,4 @@
+mod char;
+mod bool;
+mod non_zero;
+mod slice;
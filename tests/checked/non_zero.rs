@@ -0,0 +1,63 @@
+use safe_transmute::{SingleManyGuard, ErrorReason, GuardError, Error, transmute_many_checked, transmute_one_checked, transmute_to_bytes};
+use core::num::{NonZeroUsize, NonZeroU128, NonZeroI128, NonZeroU16, NonZeroI32, NonZeroU64, NonZeroU8, NonZeroI8};
+use safe_transmute::guard::PedanticGuard;
+
+
+#[test]
+fn valid() {
+    let ids: &[u16] = &[1, 2, 0xFFFF];
+    assert_eq!(transmute_many_checked::<NonZeroU16, SingleManyGuard>(transmute_to_bytes(ids)),
+               Ok(&[NonZeroU16::new(1).unwrap(), NonZeroU16::new(2).unwrap(), NonZeroU16::new(0xFFFF).unwrap()][..]));
+
+    let ids: &[i32] = &[-1, 7];
+    assert_eq!(transmute_many_checked::<NonZeroI32, SingleManyGuard>(transmute_to_bytes(ids)),
+               Ok(&[NonZeroI32::new(-1).unwrap(), NonZeroI32::new(7).unwrap()][..]));
+
+    assert_eq!(transmute_many_checked::<NonZeroI8, SingleManyGuard>(&[0x80]), Ok(&[NonZeroI8::new(-128).unwrap()][..]));
+}
+
+#[test]
+fn zero_index() {
+    let ids: &[u64] = &[3, 4, 0, 5, 0];
+    assert_eq!(transmute_many_checked::<NonZeroU64, SingleManyGuard>(transmute_to_bytes(ids)),
+               Err(Error::InvalidValueAt { index: 2, bytes: &[0; 8] }));
+
+    let ids: &[usize] = &[0];
+    assert_eq!(transmute_many_checked::<NonZeroUsize, SingleManyGuard>(transmute_to_bytes(ids)),
+               Err(Error::InvalidValueAt { index: 0, bytes: &0usize.to_ne_bytes() }));
+
+    assert_eq!(transmute_many_checked::<NonZeroU8, SingleManyGuard>(&[1, 2, 3, 0]), Err(Error::InvalidValueAt { index: 3, bytes: &[0] }));
+}
+
+#[test]
+fn guarded() {
+    let ids: &[u16] = &[1, 2];
+    assert_eq!(transmute_many_checked::<NonZeroU16, PedanticGuard>(&transmute_to_bytes(ids)[..3]),
+               Err(Error::Guard(GuardError {
+                   required: 2,
+                   actual: 3,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn one() {
+    assert_eq!(transmute_one_checked::<NonZeroU16>(&[0x00, 0x00, 0x01]), Err(Error::InvalidValueAt { index: 0, bytes: &[0x00, 0x00] }));
+    assert_eq!(transmute_one_checked::<NonZeroU16>(&[0x00, 0x01]), Ok(NonZeroU16::new(u16::from_ne_bytes([0x00, 0x01])).unwrap()));
+}
+
+#[test]
+fn wide() {
+    let ids: &[u128] = &[1, u128::MAX];
+    assert_eq!(transmute_many_checked::<NonZeroU128, SingleManyGuard>(transmute_to_bytes(ids)),
+               Ok(&[NonZeroU128::new(1).unwrap(), NonZeroU128::new(u128::MAX).unwrap()][..]));
+
+    let ids: &[u128] = &[1, 0];
+    assert_eq!(transmute_many_checked::<NonZeroU128, SingleManyGuard>(transmute_to_bytes(ids)),
+               Err(Error::InvalidValueAt { index: 1, bytes: &[0; 16] }));
+
+    let mut bytes = [0x00; 17];
+    assert_eq!(transmute_one_checked::<NonZeroI128>(&bytes[1..]), Err(Error::InvalidValueAt { index: 0, bytes: &[0; 16] }));
+    bytes[1..].copy_from_slice(&(-1i128).to_ne_bytes());
+    assert_eq!(transmute_one_checked::<NonZeroI128>(&bytes[1..]), Ok(NonZeroI128::new(-1).unwrap()));
+}
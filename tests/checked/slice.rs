@@ -0,0 +1,70 @@
+use safe_transmute::{SingleManyGuard, CheckedSlice, ErrorReason, GuardError, Error, transmute_to_bytes};
+use safe_transmute::guard::AllOrNothingGuard;
+use core::num::NonZeroU32;
+
+
+#[test]
+fn lazy() {
+    let utf32: &[u32] = &[0x0052, 0xD800, 0x0073, 0x11_0000];
+    let chars = CheckedSlice::<char>::new::<SingleManyGuard>(transmute_to_bytes(utf32)).unwrap();
+    assert_eq!(chars.len(), 4);
+    assert!(!chars.is_empty());
+    assert_eq!(chars.get(0), Some(Ok('R')));
+    assert_eq!(chars.get(1),
+               Some(Err(Error::InvalidValueAt {
+                   index: 1,
+                   bytes: &0xD800u32.to_ne_bytes(),
+               })));
+    assert_eq!(chars.get(2), Some(Ok('s')));
+    assert_eq!(chars.get(3),
+               Some(Err(Error::InvalidValueAt {
+                   index: 3,
+                   bytes: &0x11_0000u32.to_ne_bytes(),
+               })));
+}
+
+#[test]
+fn iter() {
+    let ids: &[u32] = &[3, 0, 5];
+    let ids = CheckedSlice::<NonZeroU32>::new::<SingleManyGuard>(transmute_to_bytes(ids)).unwrap();
+    let mut iter = ids.iter();
+    assert_eq!(iter.next(), Some(Ok(NonZeroU32::new(3).unwrap())));
+    assert_eq!(iter.next(), Some(Err(Error::InvalidValueAt { index: 1, bytes: &[0; 4] })));
+    assert_eq!(iter.next(), Some(Ok(NonZeroU32::new(5).unwrap())));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn unaligned() {
+    let mut bytes = [0x00; 9];
+    bytes[1..5].copy_from_slice(&0x01_F980u32.to_ne_bytes());
+    bytes[5..9].copy_from_slice(&0x007Au32.to_ne_bytes());
+    let chars = CheckedSlice::<char>::new::<SingleManyGuard>(&bytes[1..]).unwrap();
+    assert_eq!(chars.get(0), Some(Ok('🦀')));
+    assert_eq!(chars.get(1), Some(Ok('z')));
+}
+
+#[test]
+fn guarded() {
+    let bools = CheckedSlice::<bool>::new::<SingleManyGuard>(&[0x01, 0x07, 0x00]).unwrap();
+    assert_eq!(bools.len(), 3);
+    assert_eq!(bools.get(1), Some(Err(Error::InvalidValueAt { index: 1, bytes: &[0x07] })));
+
+    let utf32: &[u32] = &[0x0052, 0x0075];
+    let chars = CheckedSlice::<char>::new::<SingleManyGuard>(&transmute_to_bytes(utf32)[..7]).unwrap();
+    assert_eq!(chars.len(), 1);
+    assert_eq!(chars.as_bytes(), &transmute_to_bytes(utf32)[..4]);
+    assert_eq!(CheckedSlice::<char>::new::<AllOrNothingGuard>(&transmute_to_bytes(utf32)[..7]).map(|c| c.len()),
+               Err(GuardError {
+                   required: 4,
+                   actual: 7,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+}
+
+#[test]
+fn out_of_bounds() {
+    let bools = CheckedSlice::<bool>::new::<SingleManyGuard>(&[0x00, 0x01]).unwrap();
+    assert_eq!(bools.get(2), None);
+    assert_eq!(bools.get(usize::MAX), None);
+}
@@ -0,0 +1,41 @@
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, Error, transmute_bool_pedantic, transmute_to_bytes, transmute_many};
+use bytemuck::checked::CheckedCastError;
+use safe_transmute::compat::Bytemuck;
+use bytemuck::PodCastError;
+
+
+#[test]
+fn peel() {
+    let words: &[u32] = &[1, 2, 3];
+    let values = transmute_many::<Bytemuck<u32>, PedanticGuard>(transmute_to_bytes(words)).unwrap();
+    assert_eq!(values, &[Bytemuck(1), Bytemuck(2), Bytemuck(3)]);
+    assert_eq!(Bytemuck::peel_slice(values), words);
+    assert_eq!(Bytemuck::wrap_slice(words), values);
+}
+
+#[test]
+fn guard_error() {
+    let err = transmute_many::<Bytemuck<[u8; 4]>, SingleManyGuard>(&[0x00, 0x01, 0x02]).unwrap_err();
+    assert_eq!(err,
+               Error::Guard(GuardError {
+                   required: 4,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(CheckedCastError::from(err), CheckedCastError::PodCastError(PodCastError::SizeMismatch));
+
+    let err = transmute_many::<Bytemuck<[u8; 4]>, PedanticGuard>(&[0x00; 5]).unwrap_err();
+    assert_eq!(CheckedCastError::from(err), CheckedCastError::PodCastError(PodCastError::OutputSliceWouldHaveSlop));
+}
+
+#[test]
+fn unaligned() {
+    let words: &[u32] = &[1, 2, 3];
+    let err = transmute_many::<Bytemuck<u32>, PedanticGuard>(&transmute_to_bytes(words)[1..5]).unwrap_err();
+    assert_eq!(CheckedCastError::from(err), CheckedCastError::PodCastError(PodCastError::TargetAlignmentGreaterAndInputNotAligned));
+}
+
+#[test]
+fn invalid_value() {
+    assert_eq!(CheckedCastError::from(transmute_bool_pedantic(&[0x02]).unwrap_err()), CheckedCastError::InvalidBitPattern);
+}
@@ -0,0 +1,4 @@
+#[cfg(feature = "zerocopy")]
+mod zerocopy;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
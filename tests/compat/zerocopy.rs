@@ -0,0 +1,39 @@
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, Error, transmute_to_bytes, transmute_to_bytes_mut, transmute_many_mut, transmute_many};
+use safe_transmute::compat::Zerocopy;
+
+
+#[test]
+fn too_short() {
+    assert_eq!(transmute_many::<Zerocopy<[u8; 4]>, SingleManyGuard>(&[0x00, 0x01, 0x02]),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn peel() {
+    let words: &[u32] = &[1, 2, 3];
+    let values = transmute_many::<Zerocopy<u32>, PedanticGuard>(transmute_to_bytes(words)).unwrap();
+    assert_eq!(values, &[Zerocopy(1), Zerocopy(2), Zerocopy(3)]);
+    assert_eq!(Zerocopy::peel_slice(values), words);
+}
+
+#[test]
+fn peel_mut() {
+    let mut pairs = [[0u8; 2]; 2];
+    {
+        let bytes = transmute_to_bytes_mut(&mut pairs[..]);
+        let values = Zerocopy::peel_slice_mut(transmute_many_mut::<Zerocopy<[u8; 2]>, PedanticGuard>(bytes).unwrap());
+        values[1] = [3, 4];
+    }
+    assert_eq!(pairs, [[0, 0], [3, 4]]);
+}
+
+#[test]
+fn wrap() {
+    let words: &[u16] = &[1, 2];
+    assert_eq!(transmute_to_bytes(Zerocopy::wrap_slice(words)), transmute_to_bytes(words));
+}
+
@@ -0,0 +1,33 @@
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, Error, transmute_many_le, transmute_many_be};
+
+
+#[test]
+fn too_short() {
+    assert_eq!(transmute_many_le::<u32, SingleManyGuard>(&[0x00, 0x01, 0x02]),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+    assert_eq!(transmute_many_be::<u32, PedanticGuard>(&[0x00, 0x01, 0x02, 0x03, 0x04]),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 5,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn integers() {
+    let bytes: &[u8] = &[0xFF, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+    assert_eq!(transmute_many_le::<u32, PedanticGuard>(&bytes[1..]).unwrap(), vec![0x04030201, 0x08070605]);
+    assert_eq!(transmute_many_be::<u32, PedanticGuard>(&bytes[1..]).unwrap(), vec![0x01020304, 0x05060708]);
+    assert_eq!(transmute_many_le::<i16, SingleManyGuard>(&bytes[..3]).unwrap(), vec![0x01FF]);
+    assert_eq!(transmute_many_be::<i16, SingleManyGuard>(&bytes[..3]).unwrap(), vec![-255]);
+}
+
+#[test]
+fn floats() {
+    assert_eq!(transmute_many_le::<f32, PedanticGuard>(&[0x00, 0x00, 0x80, 0x3F]).unwrap(), vec![1.0]);
+    assert_eq!(transmute_many_be::<f64, PedanticGuard>(&[0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]).unwrap(), vec![-2.0]);
+}
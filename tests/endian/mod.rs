@@ -0,0 +1,5 @@
+#[cfg(feature = "alloc")]
+mod many;
+mod primitive;
+mod order;
+mod swap;
@@ -0,0 +1,47 @@
+use safe_transmute::endian::{LittleEndian, NativeEndian, ByteOrder, BigEndian};
+use safe_transmute::{ErrorReason, GuardError, Error, transmute_one_endian, swap_bytes_endian};
+#[cfg(feature = "alloc")]
+use safe_transmute::{SingleManyGuard, transmute_many_endian};
+
+
+#[test]
+fn native() {
+    assert_eq!([LittleEndian::NATIVE, BigEndian::NATIVE, NativeEndian::NATIVE],
+               [cfg!(target_endian = "little"), cfg!(target_endian = "big"), true]);
+}
+
+#[test]
+fn swap() {
+    let mut values = [0x0102u16];
+    swap_bytes_endian::<_, NativeEndian>(&mut values);
+    assert_eq!(values, [0x0102]);
+
+    let mut values = [u16::from_le(0x0102), u16::from_be(0x0304)];
+    swap_bytes_endian::<_, LittleEndian>(&mut values[..1]);
+    swap_bytes_endian::<_, BigEndian>(&mut values[1..]);
+    assert_eq!(values, [0x0102, 0x0304]);
+}
+
+#[test]
+fn one() {
+    let bytes: &[u8] = &[0xFF, 0x01, 0x02, 0x03, 0x04];
+    assert_eq!(transmute_one_endian::<u32, LittleEndian>(&bytes[1..]), Ok(0x04030201));
+    assert_eq!(transmute_one_endian::<u32, BigEndian>(&bytes[1..]), Ok(0x01020304));
+    assert_eq!(transmute_one_endian::<u32, NativeEndian>(&bytes[1..]), Ok(u32::from_ne_bytes([0x01, 0x02, 0x03, 0x04])));
+    assert_eq!(transmute_one_endian::<u32, BigEndian>(&bytes[..3]),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn many() {
+    let bytes: &[u8] = &[0x01, 0x02, 0x03, 0x04, 0x05];
+    assert_eq!(transmute_many_endian::<u16, LittleEndian, SingleManyGuard>(bytes).unwrap(), vec![0x0201, 0x0403]);
+    assert_eq!(transmute_many_endian::<u16, BigEndian, SingleManyGuard>(bytes).unwrap(), vec![0x0102, 0x0304]);
+    assert_eq!(transmute_many_endian::<u16, NativeEndian, SingleManyGuard>(bytes).unwrap(),
+               vec![u16::from_ne_bytes([0x01, 0x02]), u16::from_ne_bytes([0x03, 0x04])]);
+}
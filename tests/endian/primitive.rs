@@ -0,0 +1,40 @@
+use safe_transmute::endian::{F64Le, I32Be, U16Be, U16Le, U64Le, F32Be};
+use safe_transmute::{SingleManyGuard, transmute_many, transmute_to_bytes};
+
+
+#[test]
+fn representation() {
+    assert_eq!(U16Le::new(0x0102).to_bytes(), [0x02, 0x01]);
+    assert_eq!(U16Be::new(0x0102).to_bytes(), [0x01, 0x02]);
+    assert_eq!(I32Be::new(-2).to_bytes(), [0xFF, 0xFF, 0xFF, 0xFE]);
+    assert_eq!(F32Be::new(1.0).to_bytes(), [0x3F, 0x80, 0x00, 0x00]);
+    assert_eq!(U64Le::from_bytes([1, 0, 0, 0, 0, 0, 0, 0]).get(), 1);
+}
+
+#[test]
+fn get_set() {
+    let mut value = U16Be::default();
+    assert_eq!(value.get(), 0);
+
+    value.set(0xABCD);
+    assert_eq!(value.get(), 0xABCD);
+    assert_eq!(u16::from(value), 0xABCD);
+    assert_eq!(value, U16Be::from(0xABCD));
+
+    let mut value = F64Le::new(-0.5);
+    value.set(value.get() * 4.0);
+    assert_eq!(value.get(), -2.0);
+}
+
+#[test]
+fn unaligned() {
+    let data: &[u8] = &[0xFF, 0x12, 0x34, 0x56, 0x78];
+    assert_eq!(transmute_many::<U16Be, SingleManyGuard>(&data[1..]).unwrap(),
+               &[U16Be::new(0x1234), U16Be::new(0x5678)]);
+}
+
+#[test]
+fn to_bytes() {
+    let values = [U16Le::new(0x0102), U16Le::new(0x0304)];
+    assert_eq!(transmute_to_bytes(&values), &[0x02, 0x01, 0x04, 0x03]);
+}
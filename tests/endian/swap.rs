@@ -0,0 +1,42 @@
+use safe_transmute::{PedanticGuard, transmute_to_bytes_mut, swap_bytes_in_place, transmute_many_mut};
+use safe_transmute::endian::{U32Be, U16Le};
+
+
+#[test]
+fn empty() {
+    let mut values: [u32; 0] = [];
+    swap_bytes_in_place(&mut values);
+}
+
+#[test]
+fn primitives() {
+    let mut values = [0x01020304u32, 0x05060708];
+    swap_bytes_in_place(&mut values);
+    assert_eq!(values, [0x04030201, 0x08070605]);
+
+    let mut values = [1.0f32];
+    swap_bytes_in_place(&mut values);
+    assert_eq!(values[0].to_bits(), 0x0000803F);
+}
+
+#[test]
+fn wrappers() {
+    let mut values = [U16Le::new(0x0102), U16Le::new(0x0304)];
+    swap_bytes_in_place(&mut values);
+    assert_eq!(values, [U16Le::new(0x0201), U16Le::new(0x0403)]);
+
+    let mut values = [U32Be::new(1)];
+    swap_bytes_in_place(&mut values);
+    assert_eq!(values[0].get(), 0x01000000);
+}
+
+#[test]
+fn transmuted() {
+    let mut words = [0u32; 2];
+    let bytes = transmute_to_bytes_mut(&mut words);
+    bytes.copy_from_slice(&[0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01, 0x02]);
+
+    let values = transmute_many_mut::<u32, PedanticGuard>(bytes).unwrap();
+    swap_bytes_in_place(values);
+    assert_eq!(values, &[u32::from_ne_bytes([0x01, 0x00, 0x00, 0x00]), u32::from_ne_bytes([0x02, 0x01, 0x00, 0x00])]);
+}
@@ -0,0 +1,38 @@
+use safe_transmute::{ErrorReason, GuardError, Error, transmute_many_pedantic, transmute_to_bytes};
+#[cfg(feature = "alloc")]
+use safe_transmute::IncompatibleVecTargetError;
+#[cfg(feature = "alloc")]
+use alloc::string::ToString;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::error::Error as CoreError;
+
+
+fn source_of<E: CoreError>(err: &E) -> Option<&(dyn CoreError + 'static)> {
+    err.source()
+}
+
+#[test]
+fn guard_error() {
+    let err = GuardError {
+        required: 4,
+        actual: 3,
+        reason: ErrorReason::NotEnoughBytes,
+    };
+    assert!(source_of(&err).is_none());
+}
+
+#[test]
+fn errors() {
+    match transmute_many_pedantic::<u16>(&transmute_to_bytes::<u16>(&[0])[..1]) {
+        Err(err @ Error::Guard(_)) => assert!(source_of(&err).is_none()),
+        _ => panic!("expected a guard error"),
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn boxed() {
+    let err: Box<dyn CoreError> = Box::new(IncompatibleVecTargetError::<u8, u16>::new(vec![0x00]));
+    assert_eq!(err.to_string(), IncompatibleVecTargetError::<u8, u16>::new(vec![0x00]).to_string());
+}
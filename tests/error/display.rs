@@ -0,0 +1,27 @@
+use safe_transmute::{SingleManyGuard, transmute_bool_pedantic, transmute_one_unaligned, transmute_to_bytes, transmute_many, transmute_vec};
+use alloc::string::ToString;
+
+
+#[test]
+fn guard() {
+    assert_eq!(transmute_one_unaligned::<[u16; 3]>(&[0x00; 5]).unwrap_err().to_string(),
+               "Not enough bytes to fill type (required: 6, actual: 5) when transmuting into `[u16; 3]`");
+}
+
+#[test]
+fn unaligned() {
+    let words: &[u32] = &[0x0100, 0x0200];
+    assert_eq!(transmute_many::<u32, SingleManyGuard>(&transmute_to_bytes(words)[1..]).unwrap_err().to_string(),
+               "data is unaligned for `u32` (1 bytes past a 4-byte boundary, skip 3 bytes)");
+}
+
+#[test]
+fn invalid_value() {
+    assert_eq!(transmute_bool_pedantic(&[0x05]).unwrap_err().to_string(), "Invalid target value for `bool`");
+}
+
+#[test]
+fn incompatible_vec_target() {
+    assert_eq!(transmute_vec::<u8, u16>(vec![0x00]).unwrap_err().to_string(),
+               "incompatible target type `u16` (size: 2, align: 2) for transmutation from source `u8` (size: 1, align: 1)");
+}
@@ -0,0 +1,19 @@
+use safe_transmute::{UnalignedError, TransmuteError, ErrorReason, GuardError, ErrorKind, Error};
+#[cfg(feature = "alloc")]
+use safe_transmute::error::IncompatibleVecTargetError;
+use defmt::Format;
+
+
+fn assert_format<T: Format>() {}
+
+#[test]
+fn all_errors_format() {
+    assert_format::<Error<u8, u32>>();
+    assert_format::<UnalignedError<u8, u32>>();
+    assert_format::<GuardError>();
+    assert_format::<ErrorReason>();
+    assert_format::<ErrorKind>();
+    assert_format::<TransmuteError>();
+    #[cfg(feature = "alloc")]
+    assert_format::<IncompatibleVecTargetError<u8, u32>>();
+}
@@ -0,0 +1,30 @@
+use safe_transmute::error::IncompatibleVecTargetError;
+use safe_transmute::{TriviallyTransmutable, Error, transmute_vec};
+use alloc::vec::Vec;
+
+
+#[test]
+fn copy() {
+    let err = match transmute_vec::<u8, u16>(vec![0x00, 0x01, 0x00, 0x02, 0x03]) {
+        Err(Error::IncompatibleVecTarget(e)) => e,
+        _ => panic!("expected an incompatible target error"),
+    };
+    assert_eq!(err.copy(), vec![u16::from_le(0x0100), u16::from_le(0x0200)]);
+}
+
+#[test]
+fn copy_zero_sized() {
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
+    struct Marker;
+    unsafe impl TriviallyTransmutable for Marker {}
+
+    assert_eq!(IncompatibleVecTargetError::<u8, Marker>::new(vec![0x00, 0x01]).copy(), Vec::<Marker>::new());
+}
+
+#[test]
+fn into_boxed_bytes() {
+    let words = vec![u16::from_le(0x0100), u16::from_le(0x0302)];
+    let bytes = IncompatibleVecTargetError::<u16, u32>::new(words).into_boxed_bytes();
+    assert_eq!(&*bytes, &[0x00, 0x01, 0x02, 0x03][..]);
+}
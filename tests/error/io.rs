@@ -0,0 +1,42 @@
+use safe_transmute::{SingleManyGuard, ErrorReason, GuardError, transmute_bool_pedantic, transmute_one_unaligned, transmute_many};
+use std::io::{self, ErrorKind};
+
+
+fn parse_word(bytes: &[u8]) -> io::Result<u32> {
+    Ok(transmute_one_unaligned::<u32>(bytes)?)
+}
+
+#[test]
+fn not_enough_bytes() {
+    let err = parse_word(&[0x00, 0x01]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    assert_eq!(err.to_string(),
+               GuardError {
+                       required: 4,
+                       actual: 2,
+                       reason: ErrorReason::NotEnoughBytes,
+                   }
+                   .to_string());
+}
+
+#[test]
+fn invalid_data() {
+    let err: io::Error = transmute_bool_pedantic(&[0x05]).unwrap_err().into();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    let err: io::Error = GuardError {
+            required: 2,
+            actual: 3,
+            reason: ErrorReason::InexactByteCount,
+        }
+        .into();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn unaligned() {
+    let words: &[u16] = &[0x0100, 0x0200];
+    let bytes = safe_transmute::transmute_to_bytes(words);
+    let err: io::Error = transmute_many::<u16, SingleManyGuard>(&bytes[1..]).unwrap_err().into();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
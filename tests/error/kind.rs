@@ -0,0 +1,45 @@
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, ErrorKind, transmute_bool_pedantic, transmute_to_bytes, transmute_many};
+#[cfg(feature = "alloc")]
+use safe_transmute::transmute_vec;
+
+
+#[test]
+fn guard() {
+    let words: &[u32] = &[0x0100, 0x0200];
+    let err = transmute_many::<u32, PedanticGuard>(&transmute_to_bytes(words)[..6]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Guard(ErrorReason::InexactByteCount));
+    assert_eq!(err.required_bytes(), Some(4));
+    assert_eq!(err.actual_bytes(), Some(6));
+    assert_eq!(err.required_elements(), Some(1));
+
+    let owned = err.into_owned();
+    assert_eq!(owned.kind(), ErrorKind::Guard(ErrorReason::InexactByteCount));
+    assert_eq!(owned.required_bytes(), Some(4));
+    assert_eq!(owned.actual_bytes(), Some(6));
+}
+
+#[test]
+fn unaligned() {
+    let words: &[u32] = &[0x0100, 0x0200];
+    let err = transmute_many::<u32, SingleManyGuard>(&transmute_to_bytes(words)[1..]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Unaligned);
+    assert_eq!(err.required_bytes(), None);
+    assert_eq!(err.actual_bytes(), Some(7));
+    assert_eq!(err.required_elements(), None);
+    assert_eq!(err.into_owned().actual_bytes(), None);
+}
+
+#[test]
+fn invalid_value() {
+    let err = transmute_bool_pedantic(&[0x05]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidValue);
+    assert_eq!(err.actual_bytes(), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn incompatible_vec_target() {
+    let err = transmute_vec::<u8, u16>(vec![0x00, 0x01, 0x02]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleVecTarget);
+    assert_eq!(err.actual_bytes(), Some(3));
+}
@@ -1 +1,15 @@
 mod unaligned;
+mod core_error;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "alloc")]
+mod display;
+mod owned;
+#[cfg(feature = "alloc")]
+mod incompatible_vec_target;
+mod kind;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "defmt")]
+mod format;
+mod suggestion;
@@ -0,0 +1,48 @@
+use safe_transmute::{SingleManyGuard, TransmuteError, ErrorReason, GuardError, transmute_bytes_to_str, transmute_to_bytes, transmute_many};
+#[cfg(feature = "alloc")]
+use safe_transmute::transmute_vec;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+
+fn parse_words(bytes: &[u8]) -> Result<&[u32], TransmuteError> {
+    Ok(transmute_many::<u32, SingleManyGuard>(bytes)?)
+}
+
+#[test]
+fn guard() {
+    assert_eq!(parse_words(&transmute_to_bytes::<u32>(&[0])[..3]),
+               Err(TransmuteError::Guard(GuardError {
+                   required: 4,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn unaligned() {
+    let words: &[u32] = &[0x0100, 0x0200];
+    assert_eq!(parse_words(&transmute_to_bytes(words)[1..]), Err(TransmuteError::Unaligned { offset: 3 }));
+}
+
+#[test]
+fn invalid_value_at() {
+    assert_eq!(transmute_bytes_to_str(&[0x61, 0xFF]).map_err(|e| e.into_owned()),
+               Err(TransmuteError::InvalidValueAt { index: 1 }));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn incompatible_vec_target() {
+    assert_eq!(transmute_vec::<u8, u16>(vec![0x00]).map_err(|e| e.into_owned()), Err(TransmuteError::IncompatibleVecTarget));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn boxed() {
+    fn parse(bytes: &[u8]) -> Result<u32, Box<dyn core::error::Error + Send + Sync>> {
+        Ok(parse_words(bytes)?[0])
+    }
+
+    assert!(parse(&[0x00; 2]).is_err());
+}
@@ -0,0 +1,133 @@
+use safe_transmute::{SingleManyGuard, TransmuteError, ErrorReason, GuardError, transmute_to_bytes, transmute_many};
+#[cfg(feature = "alloc")]
+use safe_transmute::transmute_vec;
+use serde_test::{assert_ser_tokens, Token};
+
+
+#[test]
+fn guard_error() {
+    assert_ser_tokens(&GuardError {
+                          required: 4,
+                          actual: 3,
+                          reason: ErrorReason::NotEnoughBytes,
+                      },
+                      &[Token::Struct {
+                            name: "GuardError",
+                            len: 3,
+                        },
+                        Token::Str("required"),
+                        Token::U64(4),
+                        Token::Str("actual"),
+                        Token::U64(3),
+                        Token::Str("reason"),
+                        Token::UnitVariant {
+                            name: "ErrorReason",
+                            variant: "NotEnoughBytes",
+                        },
+                        Token::StructEnd]);
+}
+
+#[test]
+fn error() {
+    let words: &[u32] = &[0x0100, 0x0200];
+    let err = transmute_many::<u32, SingleManyGuard>(&transmute_to_bytes(words)[..3]).unwrap_err();
+    assert_ser_tokens(&err,
+                      &[Token::Struct {
+                            name: "Error",
+                            len: 5,
+                        },
+                        Token::Str("kind"),
+                        Token::NewtypeVariant {
+                            name: "ErrorKind",
+                            variant: "Guard",
+                        },
+                        Token::UnitVariant {
+                            name: "ErrorReason",
+                            variant: "NotEnoughBytes",
+                        },
+                        Token::Str("required_bytes"),
+                        Token::Some,
+                        Token::U64(4),
+                        Token::Str("actual_bytes"),
+                        Token::Some,
+                        Token::U64(3),
+                        Token::Str("source_type"),
+                        Token::Str("u8"),
+                        Token::Str("target_type"),
+                        Token::Str("u32"),
+                        Token::StructEnd]);
+}
+
+#[test]
+fn unaligned_error() {
+    let words: &[u32] = &[0x0100, 0x0200];
+    let err = match transmute_many::<u32, SingleManyGuard>(&transmute_to_bytes(words)[1..]) {
+        Err(safe_transmute::Error::Unaligned(e)) => e,
+        _ => panic!("expected an unaligned error"),
+    };
+    assert_ser_tokens(&err,
+                      &[Token::Struct {
+                            name: "UnalignedError",
+                            len: 6,
+                        },
+                        Token::Str("offset"),
+                        Token::U64(3),
+                        Token::Str("alignment"),
+                        Token::U64(4),
+                        Token::Str("misalignment"),
+                        Token::U64(1),
+                        Token::Str("source_bytes"),
+                        Token::U64(7),
+                        Token::Str("source_type"),
+                        Token::Str("u8"),
+                        Token::Str("target_type"),
+                        Token::Str("u32"),
+                        Token::StructEnd]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn incompatible_vec_target_error() {
+    let err = match transmute_vec::<u8, u16>(vec![0x00, 0x01, 0x02]) {
+        Err(safe_transmute::Error::IncompatibleVecTarget(e)) => e,
+        _ => panic!("expected an incompatible target error"),
+    };
+    assert_ser_tokens(&err,
+                      &[Token::Struct {
+                            name: "IncompatibleVecTargetError",
+                            len: 7,
+                        },
+                        Token::Str("len"),
+                        Token::U64(3),
+                        Token::Str("source_type"),
+                        Token::Str("u8"),
+                        Token::Str("source_size"),
+                        Token::U64(1),
+                        Token::Str("source_align"),
+                        Token::U64(1),
+                        Token::Str("target_type"),
+                        Token::Str("u16"),
+                        Token::Str("target_size"),
+                        Token::U64(2),
+                        Token::Str("target_align"),
+                        Token::U64(2),
+                        Token::StructEnd]);
+}
+
+#[test]
+fn transmute_error() {
+    assert_ser_tokens(&TransmuteError::Unaligned { offset: 2 },
+                      &[Token::StructVariant {
+                            name: "TransmuteError",
+                            variant: "Unaligned",
+                            len: 1,
+                        },
+                        Token::Str("offset"),
+                        Token::U64(2),
+                        Token::StructVariantEnd]);
+    assert_ser_tokens(&TransmuteError::InvalidValue,
+                      &[Token::UnitVariant {
+                            name: "TransmuteError",
+                            variant: "InvalidValue",
+                        }]);
+}
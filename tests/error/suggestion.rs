@@ -0,0 +1,53 @@
+use safe_transmute::guard::{SingleValueGuard, PedanticGuard, RuntimeGuard, BoundedGuard, Guard};
+use safe_transmute::{SingleManyGuard, TransmuteError, Suggestion, transmute_bool_pedantic, transmute_to_bytes, transmute_many};
+#[cfg(feature = "alloc")]
+use safe_transmute::transmute_vec;
+
+
+#[test]
+fn not_enough_bytes() {
+    let words: &[u32] = &[0x0100, 0x0200];
+    let err = transmute_many::<u32, SingleManyGuard>(&transmute_to_bytes(words)[..1]).unwrap_err();
+    assert_eq!(err.suggestion(), Some(Suggestion::ProvideMoreBytes { missing: 3 }));
+    assert_eq!(err.into_owned().suggestion(), Some(Suggestion::ProvideMoreBytes { missing: 3 }));
+}
+
+#[test]
+fn inexact_byte_count() {
+    assert_eq!(SingleValueGuard::check::<u16>(&[0x00; 3]).unwrap_err().suggestion(),
+               Some(Suggestion::TruncateTo { bytes: 2 }));
+    assert_eq!(SingleValueGuard::check::<u32>(&[0x00; 3]).unwrap_err().suggestion(),
+               Some(Suggestion::ProvideMoreBytes { missing: 1 }));
+    assert_eq!(PedanticGuard::check::<u16>(&[0x00; 5]).unwrap_err().suggestion(),
+               Some(Suggestion::TruncateTo { bytes: 2 }));
+}
+
+#[test]
+fn too_many_bytes() {
+    assert_eq!(BoundedGuard::new(1, 2).check_bytes::<u16>(&[0x00; 6]).unwrap_err().suggestion(),
+               Some(Suggestion::TruncateTo { bytes: 4 }));
+}
+
+#[test]
+fn zero_sized_target() {
+    assert_eq!(SingleManyGuard::check::<()>(&[0x00; 2]).unwrap_err().suggestion(), None);
+}
+
+#[test]
+fn unaligned() {
+    let words: &[u32] = &[0x0100, 0x0200];
+    let err = transmute_many::<u32, SingleManyGuard>(&transmute_to_bytes(words)[1..]).unwrap_err();
+    assert_eq!(err.suggestion(), Some(Suggestion::CopyToAlignedBuffer));
+    assert_eq!(TransmuteError::Unaligned { offset: 3 }.suggestion(), Some(Suggestion::CopyToAlignedBuffer));
+}
+
+#[test]
+fn invalid_value() {
+    assert_eq!(transmute_bool_pedantic(&[0x05]).unwrap_err().suggestion(), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn incompatible_vec_target() {
+    assert_eq!(transmute_vec::<u8, u16>(vec![0x00, 0x01]).unwrap_err().suggestion(), Some(Suggestion::CopyToAlignedBuffer));
+}
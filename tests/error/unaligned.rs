@@ -88,3 +88,44 @@ fn unaligned_slicing_integers_with_try_copy<'a>(bytes: &'a [u8]) -> Result<(), E
 
     Ok(())
 }
+
+#[test]
+fn diagnostics() {
+    let words: &[u64] = &[0x0706_0504_0302_0100, 0x0F0E_0D0C_0B0A_0908];
+    let bytes = transmute_to_bytes(words);
+
+    for skip in 1..4 {
+        match transmute_many_permissive::<u32>(&bytes[skip..]) {
+            Err(Error::Unaligned(e)) => {
+                assert_eq!(e.alignment(), 4);
+                assert_eq!(e.misalignment(), skip);
+                assert_eq!(e.offset, 4 - skip);
+                assert_eq!(e.realigned(), Some(&bytes[4..]));
+            }
+            _ => panic!("expected an unaligned error at offset {}", skip),
+        }
+    }
+
+    // the bytes to skip are counted up to the size, not the alignment, of the target
+    match transmute_many_permissive::<[u16; 3]>(&bytes[1..]) {
+        Err(Error::Unaligned(e)) => {
+            assert_eq!(e.alignment(), 2);
+            assert_eq!(e.misalignment(), 1);
+            assert_eq!(e.offset, 5);
+            assert_eq!(e.realigned(), Some(&bytes[6..]));
+        }
+        _ => panic!("expected an unaligned error"),
+    }
+}
+
+#[test]
+fn realigned_partial_source_element() {
+    let e = UnalignedError::<u16, u32>::new(3, &[0x0100, 0x0302]);
+    assert_eq!(e.realigned(), None);
+
+    let e = UnalignedError::<u16, u32>::new(2, &[0x0100, 0x0302]);
+    assert_eq!(e.realigned(), Some(&[0x0302][..]));
+
+    let e = UnalignedError::<u8, u32>::new(3, &[0x00, 0x01]);
+    assert_eq!(e.realigned(), None);
+}
@@ -0,0 +1,25 @@
+use safe_transmute::prelude::*;
+
+
+#[test]
+fn as_byte_slice() {
+    let words = [0x0102u16.to_be(), 0x0304u16.to_be()];
+    assert_eq!(words.as_byte_slice(), &[0x01, 0x02, 0x03, 0x04]);
+    assert_eq!(words[1..].as_byte_slice(), &[0x03, 0x04]);
+    assert_eq!(<[u32]>::as_byte_slice(&[]), &[] as &[u8]);
+}
+
+#[test]
+fn as_mut_byte_slice() {
+    let mut words = [0x0102u16.to_be(), 0x0304u16.to_be()];
+    words.as_mut_byte_slice()[1] = 0xFF;
+    words[1..].as_mut_byte_slice().copy_from_slice(&[0xAA, 0xBB]);
+    assert_eq!(u16::from_be(words[0]), 0x01FF);
+    assert_eq!(u16::from_be(words[1]), 0xAABB);
+}
+
+#[test]
+fn round_trip() {
+    let values = [1.5f64, -0.25];
+    assert_eq!(values.as_byte_slice().transmute_view::<f64, PedanticGuard>(), Ok(&values[..]));
+}
@@ -0,0 +1,4 @@
+mod bytes;
+mod view;
+#[cfg(feature = "alloc")]
+mod vec;
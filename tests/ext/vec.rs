@@ -0,0 +1,47 @@
+use safe_transmute::{ErrorReason, GuardError, Error};
+use safe_transmute::prelude::*;
+
+
+#[test]
+fn into_vec() {
+    let bytes = vec![0x00, 0x01, 0xFF];
+    let ptr = bytes.as_ptr();
+    let values = bytes.transmute_into_vec::<i8>().unwrap();
+    assert_eq!(values, vec![0x00, 0x01, -0x01]);
+    assert_eq!(values.as_ptr() as *const u8, ptr);
+
+    match vec![0x00, 0x01].transmute_into_vec::<u16>() {
+        Err(Error::IncompatibleVecTarget(e)) => assert_eq!(e.vec, vec![0x00, 0x01]),
+        r => panic!("expected an incompatible target error, got {:?}", r),
+    }
+}
+
+#[test]
+fn into_vec_or_copy() {
+    let bytes = vec![0x00, 0x01, 0xFF];
+    let ptr = bytes.as_ptr();
+    let values = bytes.transmute_into_vec_or_copy::<i8, PedanticGuard>().unwrap();
+    assert_eq!(values, vec![0x00, 0x01, -0x01]);
+    assert_eq!(values.as_ptr() as *const u8, ptr);
+
+    let bytes = vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+    let ptr = bytes.as_ptr();
+    let values = bytes.transmute_into_vec_or_copy::<[u8; 4], PedanticGuard>().unwrap();
+    assert_eq!(values, vec![[0x01, 0x02, 0x03, 0x04], [0x05, 0x06, 0x07, 0x08]]);
+    assert_eq!(values.as_ptr() as *const u8, ptr);
+
+    assert_eq!(vec![0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00].transmute_into_vec_or_copy::<u32, PedanticGuard>(),
+               Ok(vec![u32::from_le(0x01), u32::from_le(0x02)]));
+    assert_eq!(vec![0x01, 0x00, 0x00, 0x00, 0x02].transmute_into_vec_or_copy::<u32, PermissiveGuard>(),
+               Ok(vec![u32::from_le(0x01)]));
+}
+
+#[test]
+fn into_vec_or_copy_guarded() {
+    assert_eq!(vec![0x01, 0x00, 0x00, 0x00, 0x02].transmute_into_vec_or_copy::<u32, PedanticGuard>(),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 5,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
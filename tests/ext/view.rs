@@ -0,0 +1,42 @@
+use safe_transmute::{ErrorReason, GuardError, Error, transmute_to_bytes_mut, transmute_to_bytes};
+use safe_transmute::prelude::*;
+
+
+#[test]
+fn view() {
+    let words: &[u16] = &[0x0102, 0x0304, 0x0506];
+    let bytes = transmute_to_bytes(words);
+
+    assert_eq!(bytes.transmute_view::<u16, PedanticGuard>(), Ok(words));
+    assert_eq!(bytes[..5].transmute_view::<u16, PermissiveGuard>(), Ok(&words[..2]));
+    assert_eq!(bytes[..5].transmute_view::<u16, PedanticGuard>(),
+               Err(Error::Guard(GuardError {
+                   required: 2,
+                   actual: 5,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn view_mut() {
+    let mut words = [0x0102u16, 0x0304];
+    bytes_view(transmute_to_bytes_mut(&mut words));
+    assert_eq!(words, [0x0102, 0xFFFF]);
+
+    fn bytes_view(bytes: &mut [u8]) {
+        bytes.transmute_view_mut::<u16, SingleManyGuard>().unwrap()[1] = 0xFFFF;
+    }
+}
+
+#[test]
+fn one() {
+    let words: &[u32] = &[0x0102_0304, 0x0506_0708];
+    let bytes = transmute_to_bytes(words);
+
+    assert_eq!(bytes.transmute_one::<u32>(), Ok(0x0102_0304));
+    assert_eq!(bytes[4..].transmute_one::<u32>(), Ok(0x0506_0708));
+    match bytes[1..].transmute_one::<u32>() {
+        Err(Error::Unaligned(e)) => assert_eq!(e.offset, 3),
+        r => panic!("expected an unaligned error, got {:?}", r),
+    }
+}
@@ -0,0 +1,51 @@
+use safe_transmute::{SingleManyGuard, PermissiveGuard, PedanticGuard, ErrorReason, GuardError, Error, slice_from_c_mut, slice_from_c};
+use core::ffi::c_void;
+use core::ptr;
+
+
+#[test]
+fn slice() {
+    let words = [0x0102u16, 0x0304, 0x0506];
+    let data = words.as_ptr() as *const c_void;
+
+    assert_eq!(unsafe { slice_from_c::<u16, PedanticGuard>(data, 6) }, Ok(&words[..]));
+    assert_eq!(unsafe { slice_from_c::<u16, SingleManyGuard>(data, 5) }, Ok(&words[..2]));
+    assert_eq!(unsafe { slice_from_c::<u16, PedanticGuard>(data, 5) },
+               Err(Error::Guard(GuardError {
+                   required: 2,
+                   actual: 5,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn null() {
+    assert_eq!(unsafe { slice_from_c::<u32, PermissiveGuard>(ptr::null(), 0) }, Ok(&[][..]));
+    assert_eq!(unsafe { slice_from_c::<u32, PermissiveGuard>(ptr::null(), 16) }, Ok(&[][..]));
+    assert_eq!(unsafe { slice_from_c::<u32, SingleManyGuard>(ptr::null(), 16) },
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+    assert_eq!(unsafe { slice_from_c_mut::<u32, PermissiveGuard>(ptr::null_mut(), 0) }, Ok(&mut [][..]));
+}
+
+#[test]
+fn unaligned() {
+    let words = [0u32; 2];
+    let data = unsafe { (words.as_ptr() as *const u8).add(1) } as *const c_void;
+
+    match unsafe { slice_from_c::<u32, SingleManyGuard>(data, 4) } {
+        Err(Error::Unaligned(e)) => assert_eq!(e.offset, 3),
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn mutable() {
+    let mut words = [1u32, 2];
+    unsafe { slice_from_c_mut::<u32, PedanticGuard>(words.as_mut_ptr() as *mut c_void, 8) }.unwrap()[1] = 7;
+
+    assert_eq!(words, [1, 7]);
+}
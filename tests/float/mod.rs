@@ -0,0 +1,53 @@
+use safe_transmute::{SingleManyGuard, ErrorReason, GuardError, Error, transmute_to_bytes_mut, transmute_many_f32, transmute_many_f64};
+use safe_transmute::float::transmute_many_float;
+use safe_transmute::guard::PermissiveGuard;
+
+
+#[test]
+fn f32_designalised() {
+    let mut words = [0x7F80_0001u32, 0xFFA0_0000, 0x7FC0_0000, 0x7F80_0000, 2.5f32.to_bits()];
+    {
+        let floats = transmute_many_f32::<SingleManyGuard>(transmute_to_bytes_mut(&mut words), true).unwrap();
+        assert_eq!(floats.len(), 5);
+        assert_eq!(floats[4], 2.5);
+    }
+    assert_eq!(words, [0x7FC0_0001, 0xFFE0_0000, 0x7FC0_0000, 0x7F80_0000, 2.5f32.to_bits()]);
+}
+
+#[test]
+fn f32_kept() {
+    let mut words = [0x7F80_0001u32, 0xFFA0_0000];
+    assert_eq!(transmute_many_f32::<SingleManyGuard>(transmute_to_bytes_mut(&mut words), false).unwrap().len(), 2);
+    assert_eq!(words, [0x7F80_0001, 0xFFA0_0000]);
+}
+
+#[test]
+fn f64_designalised() {
+    let mut words = [0x7FF0_0000_0000_0001u64, 0xFFF8_0000_0000_0000, (-1.0f64).to_bits()];
+    transmute_many_f64::<SingleManyGuard>(transmute_to_bytes_mut(&mut words), true).unwrap();
+    assert_eq!(words, [0x7FF8_0000_0000_0001, 0xFFF8_0000_0000_0000, (-1.0f64).to_bits()]);
+
+    let mut words = [0x7FF0_0000_0000_0001u64];
+    transmute_many_float::<f64, PermissiveGuard>(transmute_to_bytes_mut(&mut words), false).unwrap();
+    assert_eq!(words, [0x7FF0_0000_0000_0001]);
+}
+
+#[test]
+fn guarded() {
+    let mut words = [0u32; 2];
+    assert_eq!(transmute_many_f64::<SingleManyGuard>(&mut transmute_to_bytes_mut(&mut words)[..4], true),
+               Err(Error::Guard(GuardError {
+                   required: 8,
+                   actual: 4,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn unaligned() {
+    let mut words = [0u32; 3];
+    match transmute_many_f32::<SingleManyGuard>(&mut transmute_to_bytes_mut(&mut words)[2..], true) {
+        Err(Error::Unaligned(e)) => assert_eq!(e.offset, 2),
+        r => panic!("expected an unaligned error, got {:?}", r),
+    }
+}
@@ -0,0 +1,77 @@
+use safe_transmute::{TransmuteError, ErrorReason, GuardError, Frames, transmute_to_bytes};
+use safe_transmute::endian::U16Be;
+
+
+#[test]
+fn records() {
+    let data = [0x01, 0x00, 0xAA, 0x00, 0x00, 0x00, 0x03, 0xBB, 0xCC, 0xDD];
+    let mut frames = Frames::<[u8; 2], u8, _>::new(&data, |header| header[0] as usize + header[1] as usize);
+
+    assert_eq!(frames.next(), Some(Ok(([0x01, 0x00], &[0xAA][..]))));
+    assert_eq!(frames.remainder(), &data[3..]);
+    assert_eq!(frames.next(), Some(Ok(([0x00, 0x00], &[][..]))));
+    assert_eq!(frames.next(), Some(Ok(([0x00, 0x03], &[0xBB, 0xCC, 0xDD][..]))));
+    assert_eq!(frames.next(), None);
+    assert_eq!(frames.remainder(), &[] as &[u8]);
+}
+
+#[test]
+fn capturing_closure() {
+    // The header counts units of a size only known at runtime
+    let unit = 2;
+    let data = [0x01, 0xAA, 0xBB, 0x00, 0x02, 0xCC, 0xDD, 0xEE, 0xFF];
+    let mut frames = Frames::<u8, u8, _>::new(&data, |&units| units as usize * unit);
+
+    assert_eq!(frames.next(), Some(Ok((0x01, &[0xAA, 0xBB][..]))));
+    assert_eq!(frames.next(), Some(Ok((0x00, &[][..]))));
+    assert_eq!(frames.next(), Some(Ok((0x02, &[0xCC, 0xDD, 0xEE, 0xFF][..]))));
+    assert_eq!(frames.clone().next(), None);
+}
+
+#[test]
+fn typed_payload() {
+    // Each record is a count of `u16`s, followed by them
+    let words = [2u16.to_be(), 0x1111, 0x2222, 1u16.to_be(), 0x3333];
+    let frames = Frames::<U16Be, u16, _>::new(transmute_to_bytes(&words), |header| header.get() as usize);
+
+    assert!(frames.map(|f| f.unwrap().1).eq([&[0x1111, 0x2222][..], &[0x3333][..]]));
+}
+
+#[test]
+fn truncated_header() {
+    let data = [0x00, 0x00, 0x01];
+    let mut frames = Frames::<[u8; 2], u8, _>::new(&data, |header| header[1] as usize);
+
+    assert_eq!(frames.next(), Some(Ok(([0x00, 0x00], &[][..]))));
+    assert_eq!(frames.next(),
+               Some(Err(TransmuteError::Guard(GuardError {
+                   required: 2,
+                   actual: 1,
+                   reason: ErrorReason::NotEnoughBytes,
+               }))));
+    assert_eq!(frames.next(), None);
+    assert_eq!(frames.remainder(), &[0x01]);
+}
+
+#[test]
+fn truncated_payload() {
+    let data = [0x00, 0x04, 0xAA, 0xBB];
+    let mut frames = Frames::<[u8; 2], u8, _>::new(&data, |header| header[1] as usize);
+
+    assert_eq!(frames.next(),
+               Some(Err(TransmuteError::Guard(GuardError {
+                   required: 4,
+                   actual: 2,
+                   reason: ErrorReason::NotEnoughBytes,
+               }))));
+    assert_eq!(frames.next(), None);
+    assert_eq!(frames.remainder(), &data[..]);
+}
+
+#[test]
+fn unaligned_payload() {
+    let words = [0x0100u16.to_be(), 0];
+    let mut frames = Frames::<u8, u16, _>::new(transmute_to_bytes(&words), |&len| len as usize);
+
+    assert_eq!(frames.next(), Some(Err(TransmuteError::Unaligned { offset: 1 })));
+}
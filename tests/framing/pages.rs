@@ -0,0 +1,68 @@
+use safe_transmute::{ErrorReason, GuardError, PageView, Error, transmute_to_bytes};
+
+
+#[test]
+fn pages() {
+    let data = [1u16, 0xAAAA, 0xBBBB, 2, 0xCCCC, 0xDDDD];
+    let bytes = transmute_to_bytes(&data);
+    let view = PageView::<u16, 6>::new(bytes).unwrap();
+
+    assert_eq!(view.len(), 2);
+    assert!(!view.is_empty());
+    assert_eq!(view.page(0), (&1, &bytes[2..6]));
+    assert_eq!(view.page(1), (&2, &bytes[8..12]));
+    assert_eq!(view.get(2), None);
+    assert!(view.pages().map(|(&header, _)| header).eq([1, 2]));
+}
+
+#[test]
+fn header_only() {
+    let data = [3u32, 4];
+    let view = PageView::<u32, 4>::new(transmute_to_bytes(&data)).unwrap();
+
+    assert_eq!(view.page(1), (&4, &[] as &[u8]));
+}
+
+#[test]
+fn empty() {
+    let data = [0u32; 1];
+    let view = PageView::<u32, 8>::new(&transmute_to_bytes(&data)[..0]).unwrap();
+
+    assert!(view.is_empty());
+    assert_eq!(view.pages().count(), 0);
+}
+
+#[test]
+fn partial_page() {
+    let data = [0u16; 5];
+    assert_eq!(PageView::<u16, 4>::new(transmute_to_bytes(&data)).map(|v| v.len()),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 10,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn unaligned() {
+    let data = [0u32; 4];
+    let bytes = transmute_to_bytes(&data);
+
+    assert!(PageView::<u32, 4>::new(&bytes[1..13]).is_err());
+    // The second page is misaligned
+    assert!(PageView::<u32, 6>::new(&bytes[..12]).is_err());
+    assert!(PageView::<u32, 6>::new(&bytes[..6]).is_ok());
+}
+
+#[test]
+#[should_panic]
+fn page_shorter_than_header() {
+    let _ = PageView::<u64, 4>::new(&[]);
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    let data = [0u8; 4];
+    PageView::<u8, 2>::new(&data).unwrap().page(2);
+}
@@ -0,0 +1,72 @@
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, LoadError, save_typed_atomic, transmute_to_bytes, load_typed, save_typed};
+use std::path::PathBuf;
+use std::io::ErrorKind;
+use std::{env, fs};
+
+
+fn temp_file(name: &str, contents: &[u8]) -> PathBuf {
+    let path = env::temp_dir().join(format!("safe-transmute-test-fs-{}", name));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+
+#[test]
+fn load() {
+    let path = temp_file("load", transmute_to_bytes(&[1u64, 2, 3]));
+    assert_eq!(load_typed::<u64, PedanticGuard, _>(&path).unwrap(), vec![1, 2, 3]);
+    assert_eq!(load_typed::<[u16; 4], PedanticGuard, _>(&path).unwrap().len(), 3);
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn extraneous_bytes() {
+    let path = temp_file("extraneous_bytes", &[0xFF; 7]);
+    assert_eq!(load_typed::<u16, SingleManyGuard, _>(&path).unwrap(), vec![0xFFFF; 3]);
+    match load_typed::<u16, PedanticGuard, _>(&path) {
+        Err(LoadError::Guard(e)) => {
+            assert_eq!(e,
+                       GuardError {
+                           required: 2,
+                           actual: 7,
+                           reason: ErrorReason::InexactByteCount,
+                       })
+        }
+        other => panic!("{:?}", other),
+    }
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn missing() {
+    match load_typed::<u32, PedanticGuard, _>(env::temp_dir().join("safe-transmute-test-fs-missing")) {
+        Err(LoadError::Io(e)) => assert_eq!(e.kind(), ErrorKind::NotFound),
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn save() {
+    let path = env::temp_dir().join("safe-transmute-test-fs-save");
+    save_typed(&path, &[0x0102u16, 0x0304]).unwrap();
+    assert_eq!(fs::read(&path).unwrap(), transmute_to_bytes(&[0x0102u16, 0x0304]));
+
+    save_typed(&path, &[[1.0f64; 2]; 3]).unwrap();
+    assert_eq!(load_typed::<[f64; 2], PedanticGuard, _>(&path).unwrap(), vec![[1.0; 2]; 3]);
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn save_atomic() {
+    let path = temp_file("save_atomic", b"old");
+    save_typed_atomic(&path, &[7u32, 8]).unwrap();
+    assert_eq!(load_typed::<u32, PedanticGuard, _>(&path).unwrap(), vec![7, 8]);
+    assert!(!path.with_file_name("safe-transmute-test-fs-save_atomic.tmp").exists());
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn save_atomic_failure() {
+    let path = env::temp_dir().join("safe-transmute-test-fs-missing-dir").join("file");
+    assert_eq!(save_typed_atomic(&path, &[1u8]).unwrap_err().kind(), ErrorKind::NotFound);
+}
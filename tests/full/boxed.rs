@@ -0,0 +1,30 @@
+#![cfg(feature = "alloc")]
+
+
+use safe_transmute::transmute_box;
+use alloc::boxed::Box;
+
+
+#[test]
+fn bad_size() {
+    assert_eq!(transmute_box::<u16, [u16; 2]>(Box::new(7)), Err(Box::new(7)));
+    assert_eq!(transmute_box::<[u8; 3], u16>(Box::new([1, 2, 3])), Err(Box::new([1, 2, 3])));
+}
+
+#[test]
+fn bad_alignment() {
+    assert_eq!(transmute_box::<[u8; 4], u32>(Box::new([1, 2, 3, 4])), Err(Box::new([1, 2, 3, 4])));
+    assert_eq!(transmute_box::<u64, [u32; 2]>(Box::new(5)), Err(Box::new(5)));
+}
+
+#[test]
+fn in_place() {
+    let words = Box::new([0x0102_0304u32, 0x0506_0708]);
+    let ptr = &*words as *const [u32; 2] as usize;
+
+    let signed = transmute_box::<_, [i32; 2]>(words).unwrap();
+    assert_eq!(&*signed as *const [i32; 2] as usize, ptr);
+    assert_eq!(*signed, [0x0102_0304, 0x0506_0708]);
+
+    assert_eq!(transmute_box::<_, u8>(Box::new(-1i8)), Ok(Box::new(0xFF)));
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "alloc")]
+
+
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, Error, transmute_to_bytes, transmute_cow};
+use alloc::borrow::Cow;
+
+
+#[test]
+fn borrowed_aligned() {
+    let words: &[u16] = &[0x0100, 0x0200, 0x0300];
+    let bytes = transmute_to_bytes(words);
+
+    match transmute_cow::<u16, SingleManyGuard>(Cow::Borrowed(&bytes[..5])) {
+        Ok(Cow::Borrowed(values)) => assert_eq!(values, &words[..2]),
+        r => panic!("expected borrowed values, got {:?}", r),
+    }
+}
+
+#[test]
+fn borrowed_unaligned() {
+    let words: &[u16] = &[0x0100, 0x0200, 0x0300];
+    let bytes = transmute_to_bytes(words);
+
+    match transmute_cow::<u16, SingleManyGuard>(Cow::Borrowed(&bytes[1..])) {
+        Ok(Cow::Owned(values)) => assert_eq!(values, vec![u16::from_ne_bytes([bytes[1], bytes[2]]), u16::from_ne_bytes([bytes[3], bytes[4]])]),
+        r => panic!("expected owned values, got {:?}", r),
+    }
+}
+
+#[test]
+fn owned() {
+    let bytes = vec![0x01, 0xFF];
+    let ptr = bytes.as_ptr();
+    match transmute_cow::<i8, PedanticGuard>(Cow::Owned(bytes)) {
+        Ok(Cow::Owned(values)) => {
+            assert_eq!(values, vec![0x01, -0x01]);
+            assert_eq!(values.as_ptr() as *const u8, ptr);
+        }
+        r => panic!("expected owned values, got {:?}", r),
+    }
+
+    let words: &[u32] = &[0x0100_0000, 0x0200_0000];
+    assert_eq!(transmute_cow::<u32, SingleManyGuard>(Cow::Owned(transmute_to_bytes(words)[..7].to_vec())),
+               Ok(Cow::Owned(vec![0x0100_0000])));
+}
+
+#[test]
+fn guarded() {
+    assert_eq!(transmute_cow::<u16, PedanticGuard>(Cow::Borrowed(&[0x00])),
+               Err(Error::Guard(GuardError {
+                   required: 2,
+                   actual: 1,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+    assert_eq!(transmute_cow::<u16, PedanticGuard>(Cow::Owned(vec![0x00; 3])),
+               Err(Error::Guard(GuardError {
+                   required: 2,
+                   actual: 3,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
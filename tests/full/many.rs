@@ -33,3 +33,23 @@ fn too_much() {
     assert_eq!(transmute_many::<u16, SingleManyGuard>(&bytes[..5]), Ok(&words[..2]));
     assert_eq!(transmute_many::<u16, SingleManyGuard>(&bytes[..7]), Ok(&words[..3]));
 }
+
+#[test]
+fn zero_sized() {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Empty;
+    unsafe impl safe_transmute::TriviallyTransmutable for Empty {}
+
+    assert_eq!(transmute_many::<Empty, SingleManyGuard>(&[0x00, 0x01]),
+               Err(Error::Guard(GuardError {
+                   required: 0,
+                   actual: 2,
+                   reason: ErrorReason::ZeroSizedTarget,
+               })));
+    assert_eq!(safe_transmute::transmute_one::<Empty>(&[]),
+               Err(Error::Guard(GuardError {
+                   required: 0,
+                   actual: 0,
+                   reason: ErrorReason::ZeroSizedTarget,
+               })));
+}
@@ -0,0 +1,43 @@
+use safe_transmute::{BoundedGuard, ErrorReason, GuardError, Error, transmute_many_mut_with, transmute_to_bytes_mut, transmute_many_with,
+                     transmute_to_bytes};
+
+
+#[test]
+fn too_short() {
+    let words: &[u16] = &[0x0100];
+    assert_eq!(transmute_many_with::<u16, _>(transmute_to_bytes(words), BoundedGuard::new(2, 4)),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 2,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn just_enough() {
+    let words: &[u16] = &[0x0100, 0x0200, 0x0300];
+    let bytes = transmute_to_bytes(words);
+    assert_eq!(transmute_many_with::<u16, _>(&bytes[..5], BoundedGuard::new(2, 3)), Ok(&words[..2]));
+    assert_eq!(transmute_many_with::<u16, _>(bytes, BoundedGuard::new(2, 3)), Ok(words));
+}
+
+#[test]
+fn too_much() {
+    let words: &[u16] = &[0x0100, 0x0200, 0x0300];
+    assert_eq!(transmute_many_with::<u16, _>(transmute_to_bytes(words), BoundedGuard::at_most(2)),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 6,
+                   reason: ErrorReason::TooManyBytes,
+               })));
+}
+
+#[test]
+fn mutable() {
+    let mut words: [u16; 2] = [0x0100, 0x0200];
+    {
+        let bytes = transmute_to_bytes_mut(&mut words[..]);
+        transmute_many_mut_with::<u16, _>(bytes, BoundedGuard::at_most(2)).unwrap()[1] = 0x0300;
+    }
+    assert_eq!(words, [0x0100, 0x0300]);
+}
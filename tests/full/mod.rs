@@ -1,6 +1,18 @@
 mod many_permissive;
+mod one_from_array;
 mod many_pedantic;
+mod one_unaligned;
 mod one_pedantic;
+mod vec_grouped;
+mod vecdeque;
+mod cow;
+mod one_const;
+mod many_with;
+mod to_vec;
 mod many;
+mod value;
+mod reference;
 mod one;
+mod one_ref;
 mod vec;
+mod boxed;
@@ -1,4 +1,5 @@
 use safe_transmute::{ErrorReason, GuardError, Error, transmute_to_bytes, transmute_one};
+use core::num::{NonZeroU32, Saturating, Wrapping};
 
 
 #[test]
@@ -33,3 +34,43 @@ fn too_much() {
     assert_eq!(transmute_one::<u32>(&bytes[..8]), Ok(0x0100_0000));
     assert_eq!(transmute_one::<u32>(&bytes[..9]), Ok(0x0100_0000));
 }
+
+#[test]
+fn long_array() {
+    let words: &[u32] = &[0x0102_0304; 48];
+    assert_eq!(transmute_one::<[u32; 48]>(transmute_to_bytes(words)), Ok([0x0102_0304; 48]));
+}
+
+#[test]
+fn num_wrappers() {
+    let words: &[u32] = &[0, 7];
+    let bytes = transmute_to_bytes(words);
+    assert_eq!(transmute_one::<Wrapping<u32>>(&bytes[4..]), Ok(Wrapping(7)));
+    assert_eq!(transmute_one::<Saturating<u32>>(&bytes[4..]), Ok(Saturating(7)));
+    assert_eq!(transmute_one::<Option<NonZeroU32>>(&bytes[..4]), Ok(None));
+    assert_eq!(transmute_one::<Option<NonZeroU32>>(&bytes[4..]), Ok(NonZeroU32::new(7)));
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn uuid() {
+    use safe_transmute::TriviallyTransmutable;
+    use uuid::Uuid;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[repr(C)]
+    struct IndexRecord {
+        id: Uuid,
+        offset: u64,
+    }
+    unsafe impl TriviallyTransmutable for IndexRecord {}
+
+    let record = IndexRecord {
+        id: Uuid::from_u128(0x67e5504410b1426f9247bb680e5fe0c8),
+        offset: 0x1000,
+    };
+    let bytes = transmute_to_bytes(core::slice::from_ref(&record));
+    assert_eq!(&bytes[..16], record.id.as_bytes());
+    assert_eq!(transmute_one::<IndexRecord>(bytes), Ok(record));
+    assert_eq!(transmute_one::<Uuid>(&bytes[..16]), Ok(record.id));
+}
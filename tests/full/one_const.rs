@@ -0,0 +1,50 @@
+use safe_transmute::{ErrorReason, GuardError, transmute_one_pedantic_const, transmute_one_const};
+
+
+const BYTES: &[u8] = &[0x01, 0x02, 0x03, 0x04, 0x05];
+const TOO_SHORT: Result<u32, GuardError> = transmute_one_const::<u32>(&[0x01, 0x02]);
+const JUST_ENOUGH: [u16; 2] = match transmute_one_pedantic_const::<[u16; 2]>(&[0x01, 0x00, 0x02, 0x00]) {
+    Ok(words) => words,
+    Err(_) => panic!(),
+};
+const TOO_MUCH: Result<u32, GuardError> = transmute_one_pedantic_const::<u32>(BYTES);
+
+
+#[test]
+fn too_short() {
+    assert_eq!(TOO_SHORT,
+               Err(GuardError {
+                   required: 32 / 8,
+                   actual: 2,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(transmute_one_pedantic_const::<u32>(&[]),
+               Err(GuardError {
+                   required: 32 / 8,
+                   actual: 0,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+}
+
+#[test]
+fn just_enough() {
+    assert_eq!(JUST_ENOUGH, [u16::from_le(0x0001), u16::from_le(0x0002)]);
+}
+
+#[test]
+fn too_much() {
+    assert_eq!(TOO_MUCH,
+               Err(GuardError {
+                   required: 32 / 8,
+                   actual: 5,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+    assert_eq!(transmute_one_const::<[u8; 4]>(BYTES), Ok([0x01, 0x02, 0x03, 0x04]));
+}
+
+#[test]
+fn unaligned() {
+    for i in 0..2 {
+        assert_eq!(transmute_one_const::<u32>(&BYTES[i..]), Ok(u32::from_le_bytes([BYTES[i], BYTES[i + 1], BYTES[i + 2], BYTES[i + 3]])));
+    }
+}
@@ -0,0 +1,33 @@
+use safe_transmute::{UnalignedError, transmute_one_ref_from_array, transmute_one_from_array, transmute_to_bytes};
+
+
+#[test]
+fn by_value() {
+    assert_eq!(transmute_one_from_array::<u32, 4>(&[0x78, 0x56, 0x34, 0x12]), u32::from_le(0x1234_5678));
+    assert_eq!(transmute_one_from_array::<[u16; 2], 4>(&[0x01, 0x00, 0x02, 0x00]), [u16::from_le(1), u16::from_le(2)]);
+    assert_eq!(transmute_one_from_array::<u8, 1>(&[0xFF]), 0xFF);
+}
+
+#[test]
+fn by_value_unaligned() {
+    let words: &[u32] = &[0x0100_0000, 0x0200_0000];
+    let bytes = transmute_to_bytes(words);
+
+    for i in 0..4 {
+        let mut array = [0u8; 4];
+        array.copy_from_slice(&bytes[i..i + 4]);
+        assert_eq!(transmute_one_from_array::<u32, 4>(&array).to_ne_bytes(), array);
+    }
+}
+
+#[test]
+fn by_ref() {
+    let words: &[u32] = &[0x0100_0000, 0x0200_0000, 0];
+    let bytes = transmute_to_bytes(words);
+
+    let aligned: &[u8; 4] = unsafe { &*(bytes[4..8].as_ptr() as *const [u8; 4]) };
+    assert_eq!(transmute_one_ref_from_array::<u32, 4>(aligned), Ok(&words[1]));
+
+    let unaligned: &[u8; 4] = unsafe { &*(bytes[5..9].as_ptr() as *const [u8; 4]) };
+    assert_eq!(transmute_one_ref_from_array::<u32, 4>(unaligned), Err(UnalignedError::new(3, &unaligned[..])));
+}
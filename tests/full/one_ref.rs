@@ -0,0 +1,98 @@
+use safe_transmute::{ErrorReason, GuardError, Error, transmute_to_bytes_mut, transmute_one_ref_mut, transmute_to_bytes, transmute_one_ref};
+
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Header {
+    magic: [u8; 4],
+    len: u32,
+}
+
+unsafe impl safe_transmute::TriviallyTransmutable for Header {}
+
+
+#[test]
+fn too_short() {
+    assert_eq!(transmute_one_ref::<u32>(transmute_to_bytes::<u32>(&[])),
+               Err(Error::Guard(GuardError {
+                   required: 32 / 8,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+    assert_eq!(transmute_one_ref::<u32>(&transmute_to_bytes::<u32>(&[0])[..3]),
+               Err(Error::Guard(GuardError {
+                   required: 32 / 8,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn unaligned() {
+    let words: &[u32] = &[0, 0];
+    let bytes = transmute_to_bytes(words);
+    match transmute_one_ref::<u32>(&bytes[1..]) {
+        Err(Error::Unaligned(e)) => assert_eq!(e.offset, 3),
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn in_place() {
+    let words: &[u32] = &[0x0100_0000, 0x0200_0000, 0x0300_0000];
+    let bytes = transmute_to_bytes(words);
+
+    let value = transmute_one_ref::<u32>(&bytes[4..]).unwrap();
+    assert_eq!(value, &words[1]);
+    assert_eq!(value as *const u32, &words[1] as *const u32);
+}
+
+#[test]
+fn fields() {
+    let headers = [Header {
+                       magic: *b"DATA",
+                       len: 12,
+                   },
+                   Header {
+                       magic: *b"ZERO",
+                       len: 0,
+                   }];
+    let bytes = transmute_to_bytes(&headers);
+
+    let header = transmute_one_ref::<Header>(bytes).unwrap();
+    assert_eq!(header, &headers[0]);
+    assert_eq!(&header.magic, b"DATA");
+    assert_eq!(transmute_one_ref::<Header>(&bytes[8..]).map(|h| &h.magic), Ok(b"ZERO"));
+}
+
+#[test]
+fn mutable() {
+    let mut headers = [Header {
+                           magic: *b"DATA",
+                           len: 0,
+                       },
+                       Header {
+                           magic: *b"ZERO",
+                           len: 0,
+                       }];
+
+    {
+        let bytes = transmute_to_bytes_mut(&mut headers);
+        transmute_one_ref_mut::<Header>(bytes).unwrap().len = 12;
+        transmute_one_ref_mut::<Header>(&mut bytes[8..]).unwrap().magic = *b"NULL";
+
+        assert_eq!(transmute_one_ref_mut::<Header>(&mut bytes[12..]).map(|h| h.len),
+                   Err(Error::Guard(GuardError {
+                       required: 8,
+                       actual: 4,
+                       reason: ErrorReason::NotEnoughBytes,
+                   })));
+        match transmute_one_ref_mut::<u32>(&mut bytes[2..]) {
+            Err(Error::Unaligned(e)) => assert_eq!(e.offset, 2),
+            other => panic!("{:?}", other),
+        }
+    }
+
+    assert_eq!(headers[0].len, 12);
+    assert_eq!(&headers[1].magic, b"NULL");
+}
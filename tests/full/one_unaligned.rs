@@ -0,0 +1,39 @@
+use safe_transmute::{ErrorReason, GuardError, Error, transmute_one_unaligned, transmute_to_bytes};
+
+
+#[test]
+fn too_short() {
+    assert_eq!(transmute_one_unaligned::<u32>(transmute_to_bytes::<u32>(&[])),
+               Err(Error::Guard(GuardError {
+                   required: 32 / 8,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+    assert_eq!(transmute_one_unaligned::<u32>(&transmute_to_bytes::<u32>(&[0])[..3]),
+               Err(Error::Guard(GuardError {
+                   required: 32 / 8,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn just_enough() {
+    let words: &[u32] = &[0x1234_5678];
+    assert_eq!(transmute_one_unaligned::<u32>(transmute_to_bytes(words)), Ok(words[0]));
+}
+
+#[test]
+fn too_much() {
+    let words: &[u32] = &[0x1234_5678, 0, 0];
+    assert_eq!(transmute_one_unaligned::<u32>(&transmute_to_bytes(words)[..5]), Ok(words[0]));
+}
+
+#[test]
+fn unaligned() {
+    let words: &[u32] = &[0x1234_5678, 0x9ABC_DEF0];
+    let bytes = transmute_to_bytes(words);
+    let mut expected = [0u8; 4];
+    expected.copy_from_slice(&bytes[1..5]);
+    assert_eq!(transmute_one_unaligned::<u32>(&bytes[1..]), Ok(u32::from_ne_bytes(expected)));
+}
@@ -0,0 +1,83 @@
+use safe_transmute::{TriviallyTransmutable, ErrorReason, GuardError, Error, transmute_to_bytes_mut, transmute_to_bytes, transmute_ref_mut, transmute_ref};
+use safe_transmute::aligned::{Aligned, A2};
+use core::num::Wrapping;
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+struct Rgb {
+    r: u16,
+    g: u16,
+    b: u16,
+}
+
+unsafe impl TriviallyTransmutable for Rgb {}
+
+
+#[test]
+fn in_place() {
+    let color = Rgb { r: 1, g: 2, b: 3 };
+
+    let channels = transmute_ref::<Rgb, [u16; 3]>(&color).unwrap();
+    assert_eq!(channels, &[1, 2, 3]);
+    assert_eq!(channels as *const [u16; 3] as usize, &color as *const Rgb as usize);
+    assert_eq!(transmute_ref::<[u16; 3], Rgb>(channels), Ok(&color));
+    assert_eq!(transmute_ref::<Wrapping<u32>, u32>(&Wrapping(7)), Ok(&7));
+}
+
+#[test]
+fn bad_size() {
+    assert_eq!(transmute_ref::<Rgb, [u16; 4]>(&Rgb { r: 1, g: 2, b: 3 }),
+               Err(Error::Guard(GuardError {
+                   required: 8,
+                   actual: 6,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+    assert_eq!(transmute_ref::<u32, u16>(&1),
+               Err(Error::Guard(GuardError {
+                   required: 2,
+                   actual: 4,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn unaligned() {
+    let words: &[u32] = &[0, 0];
+    let bytes = transmute_to_bytes(words);
+    let array: &[u8; 4] = unsafe { &*(bytes[1..5].as_ptr() as *const [u8; 4]) };
+
+    match transmute_ref::<[u8; 4], u32>(array) {
+        Err(Error::Unaligned(e)) => assert_eq!(e.offset, 3),
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn mutable() {
+    let mut header = Aligned::<A2, _>::new([0u8; 6]);
+    transmute_ref_mut::<[u8; 6], Rgb>(&mut header).unwrap().g = u16::from_le(0x0201);
+    assert_eq!(*header, [0, 0, 1, 2, 0, 0]);
+
+    let mut words = [1u16, 2, 3];
+    transmute_ref_mut::<[u16; 3], Rgb>(&mut words).unwrap().b = 4;
+    assert_eq!(words, [1, 2, 4]);
+    assert_eq!(transmute_ref_mut::<[u16; 3], [u16; 2]>(&mut words),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 6,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn mutable_unaligned() {
+    let mut words = [0u32; 2];
+    let bytes = transmute_to_bytes_mut(&mut words);
+    let array: &mut [u8; 4] = unsafe { &mut *(bytes[1..5].as_mut_ptr() as *mut [u8; 4]) };
+
+    match transmute_ref_mut::<[u8; 4], u32>(array) {
+        Err(Error::Unaligned(e)) => assert_eq!(e.offset, 3),
+        other => panic!("{:?}", other),
+    }
+}
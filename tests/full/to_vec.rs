@@ -0,0 +1,44 @@
+#![cfg(feature = "alloc")]
+
+
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, Error, transmute_to_bytes, transmute_to_vec};
+
+
+#[test]
+fn too_short() {
+    assert_eq!(transmute_to_vec::<u32, SingleManyGuard>(&[0x00, 0x01]),
+               Err(Error::Guard(GuardError {
+                   required: 32 / 8,
+                   actual: 2,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn too_much() {
+    assert_eq!(transmute_to_vec::<u16, PedanticGuard>(&[0x00, 0x01, 0x02]),
+               Err(Error::Guard(GuardError {
+                   required: 16 / 8,
+                   actual: 3,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+    assert_eq!(transmute_to_vec::<u16, SingleManyGuard>(&transmute_to_bytes(&[0x0100u16, 0x0200u16])[..3]),
+               Ok(vec![0x0100]));
+}
+
+#[test]
+fn unaligned() {
+    let words: &[u32] = &[0x0100_0000, 0x0200_0000, 0x0300_0000, 0x0400_0000];
+    let bytes = transmute_to_bytes(words);
+
+    for i in 1..4 {
+        let copied = transmute_to_vec::<u32, SingleManyGuard>(&bytes[i * 4..]).unwrap();
+        assert_eq!(copied, &words[i..]);
+        assert_eq!((copied.as_ptr() as usize) % 4, 0);
+    }
+    for i in 1..4 {
+        let copied = transmute_to_vec::<u16, SingleManyGuard>(&bytes[i..i + 4]).unwrap();
+        assert_eq!(copied.len(), 2);
+        assert_eq!((copied.as_ptr() as usize) % 2, 0);
+    }
+}
@@ -0,0 +1,17 @@
+use safe_transmute::transmute_value;
+use core::num::Wrapping;
+
+
+#[test]
+fn same_alignment() {
+    assert_eq!(transmute_value::<u32, i32>(0xFFFF_FFFF), -1);
+    assert_eq!(transmute_value::<f64, u64>(-0.0), 0x8000_0000_0000_0000);
+    assert_eq!(transmute_value::<u16, Wrapping<u16>>(7), Wrapping(7));
+}
+
+#[test]
+fn different_alignment() {
+    assert_eq!(transmute_value::<[u8; 4], u32>([0x01, 0x02, 0x03, 0x04]), u32::from_le(0x0403_0201));
+    assert_eq!(transmute_value::<u64, [u8; 8]>(u64::from_le(0x0807_0605_0403_0201)), [1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(transmute_value::<[u16; 4], [u32; 2]>([u16::from_le(1), 0, u16::from_le(2), 0]), [u32::from_le(1), u32::from_le(2)]);
+}
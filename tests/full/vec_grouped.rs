@@ -0,0 +1,56 @@
+#![cfg(feature = "alloc")]
+
+
+use safe_transmute::error::IncompatibleVecTargetError;
+use safe_transmute::{transmute_vec_grouped, Error};
+use alloc::vec::Vec;
+
+
+#[test]
+fn bad_size() {
+    assert_eq!(transmute_vec_grouped::<[u8; 2], [u8; 3]>(vec![[1, 2], [3, 4], [5, 6]]),
+               Err(Error::IncompatibleVecTarget(IncompatibleVecTargetError::new(vec![[1, 2], [3, 4], [5, 6]]))));
+    assert_eq!(transmute_vec_grouped::<u16, [u16; 2]>(vec![1, 2, 3]),
+               Err(Error::IncompatibleVecTarget(IncompatibleVecTargetError::new(vec![1, 2, 3]))));
+}
+
+#[test]
+fn bad_capacity() {
+    let mut words = Vec::with_capacity(3);
+    words.extend_from_slice(&[1u16, 2]);
+    assert_eq!(transmute_vec_grouped::<u16, [u16; 2]>(words),
+               Err(Error::IncompatibleVecTarget(IncompatibleVecTargetError::new(vec![1, 2]))));
+}
+
+#[test]
+fn bad_alignment() {
+    assert_eq!(transmute_vec_grouped::<u32, [u8; 4]>(vec![1, 2]),
+               Err(Error::IncompatibleVecTarget(IncompatibleVecTargetError::new(vec![1, 2]))));
+    assert_eq!(transmute_vec_grouped::<[u16; 2], u32>(vec![[1, 2]]),
+               Err(Error::IncompatibleVecTarget(IncompatibleVecTargetError::new(vec![[1, 2]]))));
+}
+
+#[test]
+fn grouping() {
+    assert_eq!(transmute_vec_grouped::<u16, [u16; 2]>(vec![]), Ok(vec![]));
+
+    let mut words = Vec::with_capacity(4);
+    words.extend_from_slice(&[1u16, 2, 3, 4]);
+    let groups = transmute_vec_grouped::<u16, [u16; 2]>(words).unwrap();
+    assert_eq!(groups, vec![[1, 2], [3, 4]]);
+    assert_eq!(groups.capacity(), 2);
+}
+
+#[test]
+fn splitting() {
+    let mut bytes = Vec::with_capacity(3);
+    bytes.extend_from_slice(&[[1u8, 2, 3, 4], [5, 6, 7, 8]]);
+    let bytes = transmute_vec_grouped::<[u8; 4], u8>(bytes).unwrap();
+    assert_eq!(bytes, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(bytes.capacity(), 12);
+}
+
+#[test]
+fn same_size() {
+    assert_eq!(transmute_vec_grouped::<u16, i16>(vec![0x0100u16, 0x0200u16]), Ok(vec![0x0100i16, 0x0200i16]));
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "alloc")]
+
+
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, Error, transmute_vecdeque};
+use alloc::collections::VecDeque;
+
+
+#[test]
+fn wrapped() {
+    let mut deque = VecDeque::with_capacity(4);
+    deque.extend(&[0x01, 0x02, 0x03, 0x04]);
+    deque.drain(..3);
+    deque.extend(&[0x05, 0x06, 0xFF]);
+
+    assert_eq!(transmute_vecdeque::<i8, PedanticGuard>(&mut deque), Ok(&[0x04, 0x05, 0x06, -0x01][..]));
+    assert_eq!(deque.as_slices(), (&[0x04, 0x05, 0x06, 0xFF][..], &[][..]));
+}
+
+#[test]
+fn empty() {
+    assert_eq!(transmute_vecdeque::<u8, SingleManyGuard>(&mut VecDeque::new()),
+               Err(Error::Guard(GuardError {
+                   required: 1,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn wide() {
+    let mut deque: VecDeque<u8> = (0..16).collect();
+    deque.rotate_left(5);
+
+    match transmute_vecdeque::<u32, PedanticGuard>(&mut deque) {
+        Ok(values) => assert_eq!(values.len(), 4),
+        Err(Error::Unaligned(e)) => assert!(e.offset < 4),
+        Err(e) => panic!("unexpected error {:?}", e),
+    }
+    assert_eq!(deque.as_slices().0, &[5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4][..]);
+}
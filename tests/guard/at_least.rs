@@ -0,0 +1,59 @@
+use safe_transmute::error::{ErrorReason, GuardError};
+use safe_transmute::guard::{AtLeastGuard, Guard};
+
+
+#[test]
+fn too_short() {
+    assert_eq!(AtLeastGuard::<2>::check::<u32>(&[]),
+               Err(GuardError {
+                   required: 8,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(AtLeastGuard::<2>::check::<u32>(&[0; 7]),
+               Err(GuardError {
+                   required: 8,
+                   actual: 7,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
+
+#[test]
+fn just_enough() {
+    assert_eq!(AtLeastGuard::<0>::check::<u32>(&[]), Ok(0));
+    assert_eq!(AtLeastGuard::<1>::check::<u32>(&[0; 4]), Ok(1));
+    assert_eq!(AtLeastGuard::<2>::check::<u32>(&[0; 8]), Ok(2));
+}
+
+#[test]
+fn too_much() {
+    assert_eq!(AtLeastGuard::<0>::check::<u32>(&[0]), Ok(0));
+    assert_eq!(AtLeastGuard::<2>::check::<u32>(&[0; 11]), Ok(2));
+    assert_eq!(AtLeastGuard::<2>::check::<u32>(&[0; 12]), Ok(3));
+}
+
+#[test]
+fn zero_sized() {
+    assert_eq!(AtLeastGuard::<3>::check::<()>(&[]),
+               Err(GuardError {
+                   required: 0,
+                   actual: 0,
+                   reason: ErrorReason::ZeroSizedTarget,
+               }));
+    assert_eq!(AtLeastGuard::<3>::check::<()>(&[0]),
+               Err(GuardError {
+                   required: 0,
+                   actual: 1,
+                   reason: ErrorReason::ZeroSizedTarget,
+               }));
+}
+
+#[test]
+fn overflowing_count() {
+    assert_eq!(AtLeastGuard::<{ usize::MAX / 2 }>::check::<u32>(&[0x00; 8]),
+               Err(GuardError {
+                   required: usize::MAX,
+                   actual: 8,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
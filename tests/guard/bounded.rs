@@ -0,0 +1,64 @@
+use safe_transmute::guard::{PedanticGuard, BoundedGuard, RuntimeGuard};
+use safe_transmute::error::{ErrorReason, GuardError};
+
+
+#[test]
+fn too_short() {
+    assert_eq!(BoundedGuard::new(2, 3).check_bytes::<u32>(&[]),
+               Err(GuardError {
+                   required: 8,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(BoundedGuard::new(2, 3).check_bytes::<u32>(&[0; 7]),
+               Err(GuardError {
+                   required: 8,
+                   actual: 7,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
+
+#[test]
+fn just_enough() {
+    assert_eq!(BoundedGuard::at_most(3).check_bytes::<u32>(&[]), Ok(0));
+    assert_eq!(BoundedGuard::new(2, 3).check_bytes::<u32>(&[0; 8]), Ok(2));
+    assert_eq!(BoundedGuard::new(2, 3).check_bytes::<u32>(&[0; 11]), Ok(2));
+    assert_eq!(BoundedGuard::new(2, 3).check_bytes::<u32>(&[0; 15]), Ok(3));
+}
+
+#[test]
+fn too_much() {
+    assert_eq!(BoundedGuard::at_most(0).check_bytes::<u32>(&[0; 4]),
+               Err(GuardError {
+                   required: 0,
+                   actual: 4,
+                   reason: ErrorReason::TooManyBytes,
+               }));
+    assert_eq!(BoundedGuard::new(2, 3).check_bytes::<u32>(&[0; 16]),
+               Err(GuardError {
+                   required: 12,
+                   actual: 16,
+                   reason: ErrorReason::TooManyBytes,
+               }));
+}
+
+#[test]
+fn static_guard() {
+    assert_eq!(PedanticGuard.check_bytes::<u16>(&[0; 4]), Ok(2));
+    assert_eq!(PedanticGuard.check_bytes::<u16>(&[0; 3]),
+               Err(GuardError {
+                   required: 2,
+                   actual: 3,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+}
+
+#[test]
+fn overflowing_count() {
+    assert_eq!(BoundedGuard::new(usize::MAX / 2, usize::MAX).check_bytes::<u32>(&[0x00; 8]),
+               Err(GuardError {
+                   required: usize::MAX,
+                   actual: 8,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
@@ -0,0 +1,42 @@
+use safe_transmute::{ErrorReason, GuardError, transmute_many, transmute_to_bytes};
+use safe_transmute::guard::{PermissiveGuard, Guard};
+#[cfg(feature = "alloc")]
+use safe_transmute::transmute_to_vec;
+use core::cmp;
+
+
+/// Accepts any slice, but yields at most two values.
+struct AtMostTwoGuard;
+
+unsafe impl Guard for AtMostTwoGuard {
+    fn check_len<T>(len: usize) -> Result<usize, GuardError> {
+        PermissiveGuard::check_len::<T>(len).map(|count| cmp::min(count, 2))
+    }
+}
+
+
+#[test]
+fn third_party_guard() {
+    let words: &[u16] = &[0x0102, 0x0304, 0x0506];
+    let bytes = transmute_to_bytes(words);
+
+    assert_eq!(transmute_many::<u16, AtMostTwoGuard>(bytes), Ok(&words[..2]));
+    assert_eq!(transmute_many::<u16, AtMostTwoGuard>(&bytes[..3]), Ok(&words[..1]));
+    assert_eq!(transmute_many::<u16, AtMostTwoGuard>(&bytes[..1]), Ok(&[][..]));
+    assert_eq!(AtMostTwoGuard::check::<()>(bytes),
+               Err(GuardError {
+                   required: 0,
+                   actual: 6,
+                   reason: ErrorReason::ZeroSizedTarget,
+               }));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn third_party_guard_copy() {
+    let words: &[u16] = &[0x0102, 0x0304, 0x0506, 0x0708];
+    let bytes = transmute_to_bytes(words);
+
+    assert_eq!(transmute_to_vec::<u16, AtMostTwoGuard>(bytes), Ok(words[..2].to_vec()));
+    assert_eq!(transmute_to_vec::<u16, AtMostTwoGuard>(&bytes[2..5]), Ok(words[1..2].to_vec()));
+}
@@ -0,0 +1,69 @@
+use safe_transmute::guard::{ExactCountGuard, Guard};
+use safe_transmute::error::{ErrorReason, GuardError};
+
+
+#[test]
+fn too_short() {
+    assert_eq!(ExactCountGuard::<2>::check::<u32>(&[]),
+               Err(GuardError {
+                   required: 8,
+                   actual: 0,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+    assert_eq!(ExactCountGuard::<2>::check::<u32>(&[0; 7]),
+               Err(GuardError {
+                   required: 8,
+                   actual: 7,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+}
+
+#[test]
+fn just_enough() {
+    assert_eq!(ExactCountGuard::<0>::check::<u32>(&[]), Ok(0));
+    assert_eq!(ExactCountGuard::<1>::check::<u32>(&[0; 4]), Ok(1));
+    assert_eq!(ExactCountGuard::<2>::check::<u32>(&[0; 8]), Ok(2));
+    assert_eq!(ExactCountGuard::<3>::check::<[u8; 3]>(&[0; 9]), Ok(3));
+}
+
+#[test]
+fn too_much() {
+    assert_eq!(ExactCountGuard::<0>::check::<u32>(&[0]),
+               Err(GuardError {
+                   required: 0,
+                   actual: 1,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+    assert_eq!(ExactCountGuard::<2>::check::<u32>(&[0; 12]),
+               Err(GuardError {
+                   required: 8,
+                   actual: 12,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+}
+
+#[test]
+fn zero_sized() {
+    assert_eq!(ExactCountGuard::<3>::check::<()>(&[]),
+               Err(GuardError {
+                   required: 0,
+                   actual: 0,
+                   reason: ErrorReason::ZeroSizedTarget,
+               }));
+    assert_eq!(ExactCountGuard::<3>::check::<()>(&[0]),
+               Err(GuardError {
+                   required: 0,
+                   actual: 1,
+                   reason: ErrorReason::ZeroSizedTarget,
+               }));
+}
+
+#[test]
+fn overflowing_count() {
+    assert_eq!(ExactCountGuard::<{ usize::MAX / 2 }>::check::<u32>(&[0x00; 8]),
+               Err(GuardError {
+                   required: usize::MAX,
+                   actual: 8,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+}
@@ -1 +1,5 @@
+mod exact_count;
 mod zero_sized;
+mod at_least;
+mod bounded;
+mod custom;
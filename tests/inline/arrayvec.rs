@@ -0,0 +1,30 @@
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, Error, transmute_to_arrayvec, transmute_to_bytes};
+
+
+#[test]
+fn too_short() {
+    assert_eq!(transmute_to_arrayvec::<u32, SingleManyGuard, 4>(&[0; 3]),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn just_enough() {
+    let words: &[u32] = &[1, 2, 3, 4];
+    assert_eq!(&transmute_to_arrayvec::<u32, PedanticGuard, 4>(transmute_to_bytes(words)).unwrap()[..], words);
+    assert_eq!(&transmute_to_arrayvec::<u32, SingleManyGuard, 8>(&transmute_to_bytes(words)[..15]).unwrap()[..], &words[..3]);
+}
+
+#[test]
+fn too_much() {
+    let words: &[u32] = &[1, 2, 3, 4];
+    assert_eq!(transmute_to_arrayvec::<u32, PedanticGuard, 3>(transmute_to_bytes(words)),
+               Err(Error::Guard(GuardError {
+                   required: 12,
+                   actual: 16,
+                   reason: ErrorReason::TooManyBytes,
+               })));
+}
@@ -0,0 +1,32 @@
+use safe_transmute::{SingleManyGuard, PermissiveGuard, PedanticGuard, ErrorReason, GuardError, Error, transmute_to_heapless, transmute_to_bytes};
+
+
+#[test]
+fn too_short() {
+    assert_eq!(transmute_to_heapless::<u32, SingleManyGuard, 4>(&[0; 3]),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn just_enough() {
+    let words: &[u32] = &[1, 2, 3, 4];
+    let bytes = transmute_to_bytes(words);
+    assert_eq!(&transmute_to_heapless::<u32, PedanticGuard, 4>(bytes).unwrap()[..], words);
+    assert_eq!(&transmute_to_heapless::<u32, PedanticGuard, 8>(bytes).unwrap()[..], words);
+    assert_eq!(&transmute_to_heapless::<u32, PermissiveGuard, 4>(&bytes[1..]).unwrap().len(), &3);
+}
+
+#[test]
+fn too_much() {
+    let words: &[u32] = &[1, 2, 3, 4];
+    assert_eq!(transmute_to_heapless::<u32, PedanticGuard, 3>(transmute_to_bytes(words)),
+               Err(Error::Guard(GuardError {
+                   required: 12,
+                   actual: 16,
+                   reason: ErrorReason::TooManyBytes,
+               })));
+}
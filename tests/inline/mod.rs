@@ -0,0 +1,6 @@
+#[cfg(feature = "heapless")]
+mod heapless;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
@@ -0,0 +1,28 @@
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, Error, transmute_to_smallvec, transmute_to_bytes};
+
+
+#[test]
+fn too_short() {
+    assert_eq!(transmute_to_smallvec::<u32, [u32; 4], SingleManyGuard>(&[0; 3]),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn inline() {
+    let words: &[u32] = &[1, 2, 3, 4];
+    let values = transmute_to_smallvec::<u32, [u32; 4], PedanticGuard>(transmute_to_bytes(words)).unwrap();
+    assert!(!values.spilled());
+    assert_eq!(&values[..], words);
+}
+
+#[test]
+fn spilled() {
+    let words: &[u32] = &[1, 2, 3, 4];
+    let values = transmute_to_smallvec::<u32, [u32; 2], PedanticGuard>(transmute_to_bytes(words)).unwrap();
+    assert!(values.spilled());
+    assert_eq!(&values[..], words);
+}
@@ -0,0 +1,167 @@
+use safe_transmute::layout::{FieldLayout, ScalarKind, Layout};
+use safe_transmute::{TriviallyTransmutable, transmute_to_bytes, transmute_many, PedanticGuard, ErrorReason, GuardError, Error};
+use safe_transmute::aligned::{Aligned, A4};
+use core::mem::size_of;
+
+
+#[derive(TriviallyTransmutable, Clone, Copy, Debug, PartialEq)]
+#[transmutable(layout)]
+#[repr(C)]
+struct Vertex {
+    position: [f32; 3],
+    normal: [i16; 2],
+    uv: [u16; 2],
+    colour: u32,
+}
+
+#[derive(TriviallyTransmutable, Clone, Copy, Debug, PartialEq)]
+#[transmutable(layout)]
+#[repr(C)]
+struct Instance<T>(T, [[f32; 4]; 4]);
+
+#[derive(TriviallyTransmutable, Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
+struct Index(u32);
+
+#[derive(TriviallyTransmutable, Clone, Copy, Debug, PartialEq)]
+#[transmutable(fields, layout)]
+#[repr(C)]
+struct Header<T> {
+    magic: [u8; 4],
+    count: u32,
+    extra: T,
+}
+
+#[derive(TriviallyTransmutable, Clone, Copy, Debug, PartialEq)]
+#[transmutable(align = 16, layout)]
+#[repr(C, align(16))]
+struct Descriptor {
+    address: u64,
+    len: u32,
+    flags: u32,
+}
+
+#[derive(TriviallyTransmutable, Clone, Copy, Debug, PartialEq)]
+#[transmutable(fields)]
+#[repr(C, packed)]
+struct Packed {
+    tag: u8,
+    value: u32,
+}
+
+
+#[test]
+fn named() {
+    assert_eq!(Vertex::FIELDS,
+               &[FieldLayout {
+                     name: "position",
+                     offset: 0,
+                     size: 12,
+                     kind: ScalarKind::F32,
+                     count: 3,
+                 },
+                 FieldLayout {
+                     name: "normal",
+                     offset: 12,
+                     size: 4,
+                     kind: ScalarKind::I16,
+                     count: 2,
+                 },
+                 FieldLayout {
+                     name: "uv",
+                     offset: 16,
+                     size: 4,
+                     kind: ScalarKind::U16,
+                     count: 2,
+                 },
+                 FieldLayout {
+                     name: "colour",
+                     offset: 20,
+                     size: 4,
+                     kind: ScalarKind::U32,
+                     count: 1,
+                 }]);
+    assert_eq!(size_of::<Vertex>(), 24);
+}
+
+#[test]
+fn generic_tuple() {
+    let fields = <Instance<u64>>::FIELDS;
+    assert_eq!(fields.iter().map(|f| f.name).collect::<Vec<_>>(), ["0", "1"]);
+    assert_eq!((fields[0].offset, fields[0].size, fields[0].kind, fields[0].count), (0, 8, ScalarKind::U64, 1));
+    assert_eq!((fields[1].offset, fields[1].size, fields[1].kind, fields[1].count), (8, 64, ScalarKind::F32, 16));
+}
+
+#[test]
+fn transmutable() {
+    let vertices = [Vertex {
+                        position: [1.0, 2.0, 3.0],
+                        normal: [-1, 1],
+                        uv: [0, 0xFFFF],
+                        colour: 0xFF00_00FF,
+                    }];
+    let bytes = transmute_to_bytes(&vertices);
+    assert_eq!(bytes.len(), 24);
+    assert_eq!(transmute_many::<Vertex, PedanticGuard>(bytes), Ok(&vertices[..]));
+
+    let indices = [0u32, 1, 2];
+    assert_eq!(transmute_many::<Index, PedanticGuard>(transmute_to_bytes(&indices)), Ok(&[Index(0), Index(1), Index(2)][..]));
+}
+
+#[test]
+fn fields() {
+    let headers = [Header {
+                       magic: *b"DATA",
+                       count: 3,
+                       extra: 0xFFFF_FFFF_0000_0000u64,
+                   }];
+    let bytes = transmute_to_bytes(&headers);
+
+    assert_eq!(Header::<u64>::magic_bytes(bytes), Ok(&b"DATA"[..]));
+    assert_eq!(Header::<u64>::magic_ref(bytes), Ok(b"DATA"));
+    assert_eq!(Header::<u64>::count_bytes(bytes), Ok(&3u32.to_ne_bytes()[..]));
+    assert_eq!(Header::<u64>::count_ref(bytes), Ok(&3));
+    assert_eq!(Header::<u64>::extra_ref(bytes), Ok(&0xFFFF_FFFF_0000_0000));
+    assert_eq!(Header::<u64>::FIELDS[2].offset, 8);
+}
+
+#[test]
+fn fields_too_short() {
+    let headers = [Header {
+                       magic: *b"DATA",
+                       count: 3,
+                       extra: 1u32,
+                   }];
+    let bytes = transmute_to_bytes(&headers);
+
+    assert_eq!(Header::<u32>::magic_bytes(&bytes[..8]),
+               Err(GuardError {
+                   required: 12,
+                   actual: 8,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(Header::<u32>::count_ref(&bytes[..11]),
+               Err(Error::Guard(GuardError {
+                   required: 12,
+                   actual: 11,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn fields_packed() {
+    let bytes = Aligned::<A4, _>::new([0x01, 0x02, 0x03, 0x04, 0x05, 0x00, 0x00, 0x00]);
+
+    assert_eq!(Packed::tag_ref(&*bytes), Ok(&1));
+    assert_eq!(Packed::value_bytes(&*bytes), Ok(&[0x02, 0x03, 0x04, 0x05][..]));
+    match Packed::value_ref(&*bytes) {
+        Err(Error::Unaligned(e)) => assert_eq!(e.offset, 3),
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn aligned() {
+    assert_eq!(core::mem::align_of::<Descriptor>(), 16);
+    assert_eq!(Descriptor::FIELDS.len(), 3);
+}
@@ -1,4 +1,5 @@
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
 
 
 #[cfg(feature = "std")]
@@ -8,16 +9,100 @@ extern crate core;
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
-#[cfg_attr(feature = "alloc", macro_use)]
+#[macro_use]
 extern crate safe_transmute;
+#[cfg(feature = "async")]
+extern crate futures_core;
+#[cfg(feature = "async")]
+extern crate futures_sink;
+#[cfg(feature = "async")]
+extern crate futures_io;
+#[cfg(feature = "mmap")]
+extern crate memmap2;
+#[cfg(feature = "bytes")]
+extern crate bytes;
+#[cfg(feature = "heapless")]
+extern crate heapless;
+#[cfg(feature = "smallvec")]
+extern crate smallvec;
+#[cfg(feature = "arrayvec")]
+extern crate arrayvec;
+#[cfg(feature = "zerocopy")]
+extern crate zerocopy;
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
+#[cfg(feature = "glam")]
+extern crate glam;
+#[cfg(feature = "mint")]
+extern crate mint;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+#[cfg(feature = "fixed")]
+extern crate fixed;
+#[cfg(feature = "uuid")]
+extern crate uuid;
+#[cfg(feature = "serde")]
+extern crate serde_test;
+#[cfg(feature = "defmt")]
+extern crate defmt;
+#[cfg(feature = "half")]
+extern crate half;
+#[cfg(feature = "ndarray")]
+extern crate ndarray;
+#[cfg(feature = "image")]
+extern crate image;
+#[cfg(feature = "pyo3")]
+extern crate pyo3;
 
 
+mod shared;
+mod stream;
+mod endian;
+mod transparent;
+#[cfg(feature = "derive")]
+mod layout;
+mod atomic;
+mod volatile;
+mod ffi;
+mod uninit;
+#[cfg(any(feature = "simd", feature = "portable-simd"))]
+mod simd;
+#[cfg(any(feature = "glam", feature = "mint", feature = "nalgebra", feature = "fixed"))]
+mod math;
 mod guard;
+mod align;
+mod aligned;
 mod error;
+mod owned;
 mod base;
 mod bool;
+mod checked;
+mod float;
+mod ext;
+mod text;
+mod rows;
+mod audio;
+mod framing;
 mod full;
 mod util;
+#[cfg(feature = "std")]
+mod fs;
+#[cfg(feature = "async")]
+mod async_io;
+#[cfg(feature = "mmap")]
+mod mmap;
+#[cfg(feature = "bytes")]
+mod typed_bytes;
+#[cfg(feature = "ndarray")]
+mod typed_ndarray;
+#[cfg(feature = "image")]
+mod pixels;
+#[cfg(feature = "pyo3")]
+mod py_buffer;
+#[cfg(any(feature = "heapless", feature = "smallvec", feature = "arrayvec"))]
+mod inline;
+#[cfg(any(feature = "zerocopy", feature = "bytemuck"))]
+mod compat;
 
 
 include!("test_util/le_to_native.rs");
@@ -0,0 +1,19 @@
+use safe_transmute::{SingleManyGuard, PedanticGuard, transmute_to_bytes, transmute_many, transmute_one};
+use fixed::types::extra::{U4, U15};
+use fixed::{FixedI16, FixedU8};
+
+
+#[test]
+fn samples() {
+    let samples = [FixedI16::<U15>::from_num(0.5), FixedI16::<U15>::from_num(-0.25)];
+    let bytes = transmute_to_bytes(&samples);
+    assert_eq!(bytes, transmute_to_bytes(&[0x4000i16, -0x2000]));
+    assert_eq!(transmute_many::<FixedI16<U15>, PedanticGuard>(bytes), Ok(&samples[..]));
+}
+
+#[test]
+fn bytes() {
+    let bytes: &[u8] = &[0x18, 0x01];
+    assert_eq!(transmute_one::<FixedU8<U4>>(bytes), Ok(FixedU8::from_num(1.5)));
+    assert_eq!(transmute_many::<FixedU8<U4>, SingleManyGuard>(bytes).map(|v| v.len()), Ok(2));
+}
@@ -0,0 +1,22 @@
+use safe_transmute::{PedanticGuard, transmute_one_unaligned, transmute_to_bytes, transmute_many};
+use glam::{Vec3, Mat4, UVec2};
+
+
+#[test]
+fn vertices() {
+    let vertices = [Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0)];
+    let bytes = transmute_to_bytes(&vertices);
+    assert_eq!(bytes.len(), 24);
+    assert_eq!(transmute_many::<Vec3, PedanticGuard>(bytes), Ok(&vertices[..]));
+    assert!(transmute_many::<Vec3, PedanticGuard>(&bytes[..20]).is_err());
+}
+
+#[test]
+fn uniforms() {
+    let transform = Mat4::from_scale(Vec3::new(2.0, 3.0, 4.0));
+    let bytes = transmute_to_bytes(core::slice::from_ref(&transform));
+    assert_eq!(bytes.len(), 64);
+    assert_eq!(transmute_one_unaligned::<Mat4>(bytes), Ok(transform));
+
+    assert_eq!(transmute_one_unaligned::<UVec2>(transmute_to_bytes(&[7u32, 8])), Ok(UVec2::new(7, 8)));
+}
@@ -0,0 +1,23 @@
+use safe_transmute::{PedanticGuard, transmute_one_unaligned, transmute_to_bytes, transmute_many};
+use mint::{Point3, Quaternion, Vector3, ColumnMatrix2};
+
+
+#[test]
+fn points() {
+    let points = [Point3 { x: 1.0f32, y: 2.0, z: 3.0 }, Point3 { x: 4.0, y: 5.0, z: 6.0 }];
+    let bytes = transmute_to_bytes(&points);
+    assert_eq!(bytes, transmute_to_bytes(&[1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0]));
+    assert_eq!(transmute_many::<Point3<f32>, PedanticGuard>(bytes), Ok(&points[..]));
+}
+
+#[test]
+fn rotations() {
+    let quaternion = Quaternion {
+        v: Vector3 { x: 0.0f64, y: 1.0, z: 0.0 },
+        s: 0.0,
+    };
+    assert_eq!(transmute_one_unaligned::<Quaternion<f64>>(transmute_to_bytes(&[0.0f64, 1.0, 0.0, 0.0])), Ok(quaternion));
+
+    let matrix = transmute_one_unaligned::<ColumnMatrix2<u16>>(transmute_to_bytes(&[1u16, 2, 3, 4])).unwrap();
+    assert_eq!(matrix.y, [3, 4].into());
+}
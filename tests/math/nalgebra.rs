@@ -0,0 +1,23 @@
+use safe_transmute::{PedanticGuard, transmute_one_unaligned, transmute_to_bytes, transmute_many};
+use nalgebra::{Matrix4, Point2, Quaternion, Vector3};
+
+
+#[test]
+fn matrices() {
+    let matrix = Matrix4::<f32>::new_scaling(2.0);
+    let bytes = transmute_to_bytes(core::slice::from_ref(&matrix));
+    assert_eq!(bytes.len(), 64);
+    assert_eq!(transmute_one_unaligned::<Matrix4<f32>>(bytes), Ok(matrix));
+
+    let vector = transmute_one_unaligned::<Vector3<i32>>(transmute_to_bytes(&[1i32, 2, 3])).unwrap();
+    assert_eq!(vector, Vector3::new(1, 2, 3));
+}
+
+#[test]
+fn points() {
+    let points = [Point2::new(1.0f64, 2.0), Point2::new(3.0, 4.0)];
+    assert_eq!(transmute_many::<Point2<f64>, PedanticGuard>(transmute_to_bytes(&points)), Ok(&points[..]));
+
+    let quaternion = transmute_one_unaligned::<Quaternion<f32>>(transmute_to_bytes(&[1.0f32, 2.0, 3.0, 4.0])).unwrap();
+    assert_eq!(quaternion, Quaternion::new(4.0, 1.0, 2.0, 3.0));
+}
@@ -0,0 +1,59 @@
+use safe_transmute::{ErrorReason, PedanticGuard, TypedMmapMut, GuardError, TypedMmap, Error, transmute_to_bytes};
+use std::io::{ErrorKind, Write};
+use memmap2::MmapMut;
+use std::fs::{self, File};
+use std::env;
+
+
+fn anon(bytes: &[u8]) -> MmapMut {
+    let mut map = MmapMut::map_anon(bytes.len()).unwrap();
+    map.copy_from_slice(bytes);
+    map
+}
+
+
+#[test]
+fn read_only() {
+    let words: &[u32] = &[1, 2, 3];
+    let map = anon(transmute_to_bytes(words)).make_read_only().unwrap();
+    let typed = TypedMmap::<u32>::new::<PedanticGuard>(map).unwrap();
+    assert_eq!(&*typed, words);
+    assert_eq!(typed.into_inner().len(), 12);
+}
+
+#[test]
+fn writable() {
+    let words: &[u32] = &[1, 2, 3];
+    let mut typed = TypedMmapMut::<u32>::new::<PedanticGuard>(anon(transmute_to_bytes(words))).unwrap();
+    typed[1] = 4;
+    assert_eq!(&*typed, &[1, 4, 3]);
+    assert_eq!(&typed.into_inner()[4..8], transmute_to_bytes(&[4u32]));
+}
+
+#[test]
+fn guard_failure() {
+    assert_eq!(TypedMmap::<u32>::new::<PedanticGuard>(anon(&[0; 6]).make_read_only().unwrap()).unwrap_err(),
+               Error::Guard(GuardError {
+                   required: 4,
+                   actual: 6,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+}
+
+#[test]
+fn file() {
+    let path = env::temp_dir().join(format!("safe-transmute-mmap-{}", std::process::id()));
+    let words: &[u16] = &[0x0100, 0x0200];
+    File::create(&path).unwrap().write_all(transmute_to_bytes(words)).unwrap();
+
+    {
+        let mut typed = unsafe { TypedMmapMut::<u16>::open::<PedanticGuard, _>(&path).unwrap() };
+        typed[0] = 0x0300;
+        typed.flush().unwrap();
+    }
+    assert_eq!(&*unsafe { TypedMmap::<u16>::open::<PedanticGuard, _>(&path).unwrap() }, &[0x0300, 0x0200]);
+    assert_eq!(unsafe { TypedMmap::<u32>::open::<PedanticGuard, _>(&path).unwrap() }.len(), 1);
+    assert_eq!(unsafe { TypedMmap::<u64>::open::<PedanticGuard, _>(&path) }.unwrap_err().kind(), ErrorKind::InvalidData);
+
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "alloc")]
+
+
+use safe_transmute::{SingleManyGuard, PedanticGuard, AlignedBytes, transmute_many_mut, transmute_many};
+
+
+#[test]
+fn alignment() {
+    for len in 0..20 {
+        let bytes = AlignedBytes::with_alignment_of::<u64>(len);
+        assert_eq!(bytes.len(), len);
+        assert_eq!(bytes.alignment(), 8);
+        assert_eq!((bytes.as_ptr() as usize) % 8, 0);
+        assert!(bytes.iter().all(|&b| b == 0));
+
+        let bytes = AlignedBytes::with_alignment(len, 64);
+        assert_eq!((bytes.as_ptr() as usize) % 64, 0);
+        assert_eq!((bytes.clone().as_ptr() as usize) % 64, 0);
+    }
+}
+
+#[test]
+#[should_panic]
+fn bad_alignment() {
+    AlignedBytes::with_alignment(8, 3);
+}
+
+#[test]
+fn from_slice() {
+    let bytes = AlignedBytes::from_slice_with_alignment_of::<u32>(&[0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0xFF]);
+
+    assert_eq!(&*bytes, &[0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0xFF]);
+    assert_eq!(bytes.clone(), bytes);
+    assert_eq!(transmute_many::<u32, SingleManyGuard>(&bytes), Ok(&[u32::from_le(1), u32::from_le(2)][..]));
+}
+
+#[test]
+fn mutate() {
+    let mut bytes = AlignedBytes::with_alignment_of::<u16>(4);
+    transmute_many_mut::<u16, PedanticGuard>(&mut bytes).unwrap()[1] = u16::from_le(0x0302);
+
+    assert_eq!(&*bytes, &[0x00, 0x00, 0x02, 0x03]);
+}
@@ -0,0 +1,2 @@
+mod transmuted;
+mod aligned_bytes;
@@ -0,0 +1,54 @@
+#![cfg(feature = "alloc")]
+
+
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, Error, OwnedTransmuted, transmute_to_bytes};
+use alloc::vec::Vec;
+
+
+#[test]
+fn too_short() {
+    assert_eq!(OwnedTransmuted::<u32>::new::<SingleManyGuard>(vec![0x00, 0x01]).map(|o| o.into_vec()),
+               Err(Error::Guard(GuardError {
+                   required: 32 / 8,
+                   actual: 2,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn in_place() {
+    let words: &[u32] = &[0x0100_0000, 0x0200_0000, 0x0300_0000];
+    let mut bytes: Vec<u8> = transmute_to_bytes(words).to_vec();
+    bytes.push(0xFF);
+
+    match OwnedTransmuted::<u8>::new::<PedanticGuard>(bytes.clone()) {
+        Ok(owned) => {
+            assert!(owned.is_in_place());
+            assert_eq!(&*owned, &bytes[..]);
+        }
+        Err(e) => panic!("{}", e),
+    }
+
+    let aligned = (bytes.as_ptr() as usize).is_multiple_of(4);
+    let bytes_ptr = bytes.as_ptr() as usize;
+    let mut owned = OwnedTransmuted::<u32>::new::<SingleManyGuard>(bytes).unwrap();
+    assert_eq!(owned.is_in_place(), aligned);
+    assert_eq!(&*owned, words);
+    if aligned {
+        assert_eq!(owned.as_ptr() as usize, bytes_ptr);
+    }
+
+    owned[1] = 0x0400_0000;
+    assert_eq!(owned.clone().into_vec(), vec![0x0100_0000, 0x0400_0000, 0x0300_0000]);
+}
+
+#[test]
+fn copied() {
+    let words: &[u16] = &[0x0100, 0x0200, 0x0300];
+    let mut bytes = vec![0xFF];
+    bytes.extend_from_slice(transmute_to_bytes(words));
+
+    let owned = OwnedTransmuted::<u16>::from_boxed::<PedanticGuard>(bytes[1..].to_vec().into_boxed_slice()).unwrap();
+    assert_eq!(&*owned, words);
+    assert_eq!((owned.as_ptr() as usize) % 2, 0);
+}
@@ -0,0 +1,48 @@
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, Error, transmute_pixels_endian, transmute_to_bytes_mut, transmute_pixels_mut,
+                     transmute_to_bytes, transmute_pixels};
+use safe_transmute::endian::{LittleEndian, NativeEndian, BigEndian};
+use image::{LumaA, Rgba, Luma, Rgb};
+
+
+#[test]
+fn rgba8() {
+    let texture = [0x10, 0x20, 0x30, 0xFF, 0x40, 0x50, 0x60, 0x00];
+    assert_eq!(transmute_pixels::<Rgba<u8>, PedanticGuard>(&texture),
+               Ok(&[Rgba([0x10, 0x20, 0x30, 0xFF]), Rgba([0x40, 0x50, 0x60, 0x00])][..]));
+    assert_eq!(transmute_pixels::<Rgb<u8>, SingleManyGuard>(&texture),
+               Ok(&[Rgb([0x10, 0x20, 0x30]), Rgb([0xFF, 0x40, 0x50])][..]));
+    assert_eq!(transmute_pixels::<Rgba<u8>, PedanticGuard>(&texture[..6]),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 6,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn round_trip() {
+    let pixels = [LumaA([0x0102u16, 0x0304]), LumaA([0x0506, 0x0708])];
+    assert_eq!(transmute_pixels::<LumaA<u16>, PedanticGuard>(transmute_to_bytes(&pixels)), Ok(&pixels[..]));
+}
+
+#[test]
+fn mutable() {
+    let mut texture = [0u8; 6];
+    transmute_pixels_mut::<Rgb<u8>, PedanticGuard>(&mut texture).unwrap()[1] = Rgb([1, 2, 3]);
+    assert_eq!(texture, [0, 0, 0, 1, 2, 3]);
+}
+
+#[test]
+fn endian() {
+    let mut samples = [u16::from_be(0x1234), u16::from_be(0xABCD)];
+    assert_eq!(transmute_pixels_endian::<Luma<u16>, BigEndian, PedanticGuard>(transmute_to_bytes_mut(&mut samples)),
+               Ok(&mut [Luma([0x1234]), Luma([0xABCD])][..]));
+
+    let mut samples = [u16::from_le(0x0001), u16::from_le(0x0002), u16::from_le(0x0003), u16::from_le(0x0004)];
+    assert_eq!(transmute_pixels_endian::<Rgba<u16>, LittleEndian, PedanticGuard>(transmute_to_bytes_mut(&mut samples)),
+               Ok(&mut [Rgba([1, 2, 3, 4])][..]));
+
+    let mut samples = [0x0102u16, 0x0304];
+    assert_eq!(transmute_pixels_endian::<LumaA<u16>, NativeEndian, PedanticGuard>(transmute_to_bytes_mut(&mut samples)),
+               Ok(&mut [LumaA([0x0102, 0x0304])][..]));
+}
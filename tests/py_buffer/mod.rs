@@ -0,0 +1,61 @@
+use safe_transmute::{SingleManyGuard, PedanticGuard, transmute_py_buffer_to_vec, transmute_py_buffer};
+use pyo3::exceptions::{PyBufferError, PyValueError};
+use pyo3::buffer::PyUntypedBuffer;
+use pyo3::types::PyBytes;
+use pyo3::{PyResult, Python};
+use std::ffi::CStr;
+
+
+fn with_buffer<F: FnOnce(Python, &PyUntypedBuffer) -> PyResult<()>>(code: &[u8], f: F) {
+    Python::initialize();
+    Python::attach(|py| -> PyResult<()> {
+        let obj = py.eval(CStr::from_bytes_with_nul(code).unwrap(), None, None)?;
+        let buffer = PyUntypedBuffer::get(&obj)?;
+        f(py, &buffer)
+    })
+        .unwrap();
+}
+
+
+#[test]
+fn typed_array() {
+    with_buffer(b"__import__('array').array('f', [0.5, -1.0, 2.0])\0", |py, buffer| {
+        assert_eq!(unsafe { transmute_py_buffer::<f32, PedanticGuard>(buffer)? }, &[0.5, -1.0, 2.0]);
+        assert_eq!(transmute_py_buffer_to_vec::<[f32; 3], PedanticGuard>(py, buffer)?, vec![[0.5, -1.0, 2.0]]);
+        Ok(())
+    });
+}
+
+#[test]
+fn bytes() {
+    Python::initialize();
+    Python::attach(|py| -> PyResult<()> {
+        let bytes = PyBytes::new(py, &[0x01, 0x02, 0x03]);
+        let buffer = PyUntypedBuffer::get(bytes.as_any())?;
+
+        assert_eq!(transmute_py_buffer_to_vec::<u16, SingleManyGuard>(py, &buffer)?, vec![u16::from_ne_bytes([0x01, 0x02])]);
+
+        let err = transmute_py_buffer_to_vec::<u16, PedanticGuard>(py, &buffer).unwrap_err();
+        assert!(err.is_instance_of::<PyValueError>(py));
+        Ok(())
+    })
+        .unwrap();
+}
+
+#[test]
+fn empty() {
+    with_buffer(b"b''\0", |py, buffer| {
+        assert_eq!(transmute_py_buffer_to_vec::<u32, SingleManyGuard>(py, buffer).map_err(|e| e.is_instance_of::<PyValueError>(py)),
+                   Err(true));
+        Ok(())
+    });
+}
+
+#[test]
+fn non_contiguous() {
+    with_buffer(b"memoryview(b'abcdef')[::2]\0", |py, buffer| {
+        let err = unsafe { transmute_py_buffer::<u8, PedanticGuard>(buffer) }.unwrap_err();
+        assert!(err.is_instance_of::<PyBufferError>(py));
+        Ok(())
+    });
+}
@@ -0,0 +1,51 @@
+use safe_transmute::{ErrorReason, GuardError, Error, as_chunks_exact_mut, as_chunks_exact, as_chunks_mut, as_chunks};
+
+
+#[test]
+fn chunks() {
+    let bytes = [1u8, 2, 3, 4, 5, 6, 7];
+
+    assert_eq!(as_chunks::<2>(&bytes), (&[[1, 2], [3, 4], [5, 6]][..], &[7][..]));
+    assert_eq!(as_chunks::<7>(&bytes), (&[[1, 2, 3, 4, 5, 6, 7]][..], &[][..]));
+    assert_eq!(as_chunks::<8>(&bytes), (&[][..], &bytes[..]));
+    assert_eq!(as_chunks::<3>(&[]), (&[][..], &[][..]));
+}
+
+#[test]
+fn chunks_mut() {
+    let mut bytes = [0u8; 5];
+    {
+        let (chunks, rest) = as_chunks_mut::<2>(&mut bytes);
+        chunks[1] = [1, 2];
+        rest[0] = 3;
+    }
+    assert_eq!(bytes, [0, 0, 1, 2, 3]);
+}
+
+#[test]
+fn exact() {
+    let bytes = [1u8, 2, 3, 4, 5, 6];
+
+    assert_eq!(as_chunks_exact::<3>(&bytes), Ok(&[[1, 2, 3], [4, 5, 6]][..]));
+    assert_eq!(as_chunks_exact::<3>(&bytes[..0]), Ok(&[][..]));
+    assert_eq!(as_chunks_exact::<4>(&bytes),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 6,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+    assert_eq!(as_chunks_exact::<0>(&bytes),
+               Err(Error::Guard(GuardError {
+                   required: 0,
+                   actual: 6,
+                   reason: ErrorReason::ZeroSizedTarget,
+               })));
+}
+
+#[test]
+fn exact_mut() {
+    let mut bytes = [0u8; 4];
+    as_chunks_exact_mut::<2>(&mut bytes).unwrap()[0] = [1, 2];
+    assert_eq!(bytes, [1, 2, 0, 0]);
+    assert!(as_chunks_exact_mut::<3>(&mut bytes).is_err());
+}
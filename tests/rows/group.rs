@@ -0,0 +1,63 @@
+use safe_transmute::{ErrorReason, GuardError, Error, try_group_mut, flatten_mut, try_group, flatten};
+
+
+#[test]
+fn flat() {
+    let pixels = [[1u16, 2, 3], [4, 5, 6]];
+    assert_eq!(flatten(&pixels), Ok(&[1, 2, 3, 4, 5, 6][..]));
+    assert_eq!(flatten::<u16, 3>(&[]), Ok(&[][..]));
+    assert_eq!(flatten::<u16, 0>(&[[], []]), Ok(&[][..]));
+    assert_eq!(flatten(&[[(); 4]; 3]).map(<[()]>::len), Ok(12));
+}
+
+#[test]
+fn flat_overflow() {
+    let units = [[(); 2]; usize::MAX];
+    assert_eq!(flatten(&units),
+               Err(Error::Guard(GuardError {
+                   required: 0,
+                   actual: 0,
+                   reason: ErrorReason::ZeroSizedTarget,
+               })));
+}
+
+#[test]
+fn flat_mut() {
+    let mut pixels = [[0u8; 2]; 3];
+    flatten_mut(&mut pixels).unwrap()[3] = 1;
+    assert_eq!(pixels, [[0, 0], [0, 1], [0, 0]]);
+}
+
+#[test]
+fn group() {
+    let values = [1u16, 2, 3, 4, 5, 6];
+    assert_eq!(try_group::<_, 3>(&values), Ok(&[[1, 2, 3], [4, 5, 6]][..]));
+    assert_eq!(try_group::<_, 1>(&values), Ok(&[[1], [2], [3], [4], [5], [6]][..]));
+    assert_eq!(try_group::<_, 2>(&values[..0]), Ok(&[][..]));
+    assert_eq!(try_group::<_, 2>(&values).ok().and_then(|pairs| flatten(pairs).ok()), Some(&values[..]));
+}
+
+#[test]
+fn partial_group() {
+    let values = [1u16, 2, 3, 4, 5, 6];
+    assert_eq!(try_group::<_, 4>(&values),
+               Err(Error::Guard(GuardError {
+                   required: 8,
+                   actual: 12,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+    assert_eq!(try_group::<_, 0>(&values),
+               Err(Error::Guard(GuardError {
+                   required: 0,
+                   actual: 12,
+                   reason: ErrorReason::ZeroSizedTarget,
+               })));
+}
+
+#[test]
+fn group_mut() {
+    let mut values = [0u32; 6];
+    try_group_mut::<_, 2>(&mut values).unwrap()[1] = [7, 8];
+    assert_eq!(values, [0, 0, 7, 8, 0, 0]);
+    assert!(try_group_mut::<_, 4>(&mut values).is_err());
+}
@@ -0,0 +1,51 @@
+mod strided;
+mod group;
+mod chunks;
+
+
+use safe_transmute::{ErrorReason, GuardError, Error, transmute_to_bytes_mut, transmute_to_bytes, transmute_rows_mut, transmute_rows};
+
+
+#[test]
+fn rows() {
+    let matrix = [1u16, 2, 3, 4, 5, 6];
+    let bytes = transmute_to_bytes(&matrix);
+
+    assert_eq!(transmute_rows::<u16, 3>(bytes), Ok(&[[1, 2, 3], [4, 5, 6]][..]));
+    assert_eq!(transmute_rows::<u16, 2>(bytes), Ok(&[[1, 2], [3, 4], [5, 6]][..]));
+    assert_eq!(transmute_rows::<u16, 6>(bytes), Ok(&[[1, 2, 3, 4, 5, 6]][..]));
+    assert_eq!(transmute_rows::<u16, 3>(&bytes[..0]), Ok(&[][..]));
+    assert_eq!(transmute_rows::<u16, 2>(bytes).unwrap()[2][1], 6);
+}
+
+#[test]
+fn partial_row() {
+    let matrix = [1u16, 2, 3, 4, 5, 6];
+    assert_eq!(transmute_rows::<u16, 4>(transmute_to_bytes(&matrix)),
+               Err(Error::Guard(GuardError {
+                   required: 8,
+                   actual: 12,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn empty_rows() {
+    assert_eq!(transmute_rows::<u16, 0>(&transmute_to_bytes(&[0u16; 2])[..0]),
+               Err(Error::Guard(GuardError {
+                   required: 0,
+                   actual: 0,
+                   reason: ErrorReason::ZeroSizedTarget,
+               })));
+}
+
+#[test]
+fn rows_mut() {
+    let mut matrix = [0u8; 6];
+    {
+        let rows = transmute_rows_mut::<u8, 2>(transmute_to_bytes_mut(&mut matrix)).unwrap();
+        rows[2][1] = 1;
+        rows[0][0] = 2;
+    }
+    assert_eq!(matrix, [2, 0, 0, 0, 0, 1]);
+}
@@ -0,0 +1,75 @@
+use safe_transmute::{ErrorReason, GuardError, Error, StridedView, transmute_to_bytes};
+
+
+#[test]
+fn padded() {
+    let pixels = [1u16, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9];
+    let view = StridedView::<u16>::new(transmute_to_bytes(&pixels), 3, 8).unwrap();
+
+    assert_eq!(view.width(), 3);
+    assert_eq!(view.height(), 3);
+    assert_eq!(view.stride(), 8);
+    assert_eq!(view.row(0), &[1, 2, 3]);
+    assert_eq!(view.row(2), &[7, 8, 9]);
+    assert_eq!(view.get(3), None);
+    assert!(view.rows().eq([&[1, 2, 3][..], &[4, 5, 6][..], &[7, 8, 9][..]].iter().cloned()));
+}
+
+#[test]
+fn unpadded() {
+    let pixels = [1u32, 2, 3, 4, 5];
+    let view = StridedView::<u32>::new(transmute_to_bytes(&pixels), 2, 8).unwrap();
+
+    assert_eq!(view.height(), 2);
+    assert_eq!(view.row(1), &[3, 4]);
+}
+
+#[test]
+fn too_short() {
+    let pixels = [1u32, 2];
+    let view = StridedView::<u32>::new(transmute_to_bytes(&pixels), 3, 12).unwrap();
+    assert_eq!(view.height(), 0);
+    assert_eq!(view.rows().count(), 0);
+}
+
+#[test]
+fn unaligned_rows() {
+    let pixels = [0u32; 4];
+    let bytes = transmute_to_bytes(&pixels);
+
+    match StridedView::<u32>::new(&bytes[1..], 1, 4) {
+        Err(Error::Unaligned(e)) => assert_eq!(e.offset, 3),
+        r => panic!("expected an unaligned error, got {:?}", r),
+    }
+    match StridedView::<u32>::new(bytes, 1, 6) {
+        Err(Error::Unaligned(e)) => assert_eq!(e.offset, 2),
+        r => panic!("expected an unaligned error, got {:?}", r),
+    }
+    assert_eq!(StridedView::<u32>::new(&bytes[..9], 1, 6).unwrap().height(), 1);
+}
+
+#[test]
+fn zero_sized() {
+    match StridedView::<[u32; 0]>::new(&[], 1, 1) {
+        Err(Error::Guard(e)) => {
+            assert_eq!(e,
+                       GuardError {
+                           required: 0,
+                           actual: 0,
+                           reason: ErrorReason::ZeroSizedTarget,
+                       })
+        }
+        r => panic!("expected a guard error, got {:?}", r),
+    }
+}
+
+#[test]
+fn invalid_stride() {
+    let bytes = transmute_to_bytes(&[0u16; 4]);
+    for &(width, stride, row_size) in &[(3, 4, 6), (0, 0, 0), (usize::MAX, usize::MAX, usize::MAX)] {
+        match StridedView::<u16>::new(bytes, width, stride) {
+            Err(Error::InvalidStride { stride: s, row_size: r }) => assert_eq!((s, r), (stride, row_size)),
+            r => panic!("expected an invalid stride error, got {:?}", r),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod arc;
+mod rc;
+mod slice;
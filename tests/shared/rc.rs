@@ -0,0 +1,65 @@
+#![cfg(feature = "alloc")]
+
+
+use safe_transmute::{PermissiveGuard, PedanticGuard, ErrorReason, GuardError, Error, transmute_to_bytes, transmute_rc};
+use safe_transmute::guard::AllOrNothingGuard;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+
+
+#[test]
+fn too_short() {
+    assert_eq!(transmute_rc::<u16, PedanticGuard>(Rc::from(&[][..])),
+               Err(Error::Guard(GuardError {
+                   required: 16 / 8,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+    assert_eq!(transmute_rc::<u32, AllOrNothingGuard>(Rc::from(&[0x00, 0x01][..])),
+               Err(Error::Guard(GuardError {
+                   required: 32 / 8,
+                   actual: 2,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn reuses_allocation() {
+    let pairs: &[[u8; 2]] = &[[0x01, 0x02], [0x03, 0x04], [0x05, 0x06]];
+    let bytes: Rc<[u8]> = Rc::from(transmute_to_bytes(pairs));
+    let bytes_ptr = bytes.as_ptr() as usize;
+
+    let transmuted = transmute_rc::<[u8; 2], PedanticGuard>(bytes).unwrap();
+    assert_eq!(&*transmuted, pairs);
+    assert_eq!(transmuted.as_ptr() as usize, bytes_ptr);
+}
+
+#[test]
+fn copies_wider_alignment() {
+    let words: &[u32] = &[0x0100_0000, 0x0200_0000, 0x0300_0000];
+    let bytes: Rc<[u8]> = Rc::from(transmute_to_bytes(words));
+    let bytes_ptr = bytes.as_ptr() as usize;
+
+    let transmuted = transmute_rc::<u32, PedanticGuard>(bytes).unwrap();
+    assert_eq!(&*transmuted, words);
+    assert!(transmuted.as_ptr() as usize != bytes_ptr);
+}
+
+#[test]
+fn copies_trailing() {
+    let words: &[u16] = &[0x0100, 0x0200, 0x0300, 0x0400];
+    let bytes: Rc<[u8]> = Rc::from(&transmute_to_bytes(words)[..7]);
+
+    assert_eq!(&*transmute_rc::<u16, PermissiveGuard>(bytes).unwrap(), &words[..3]);
+}
+
+#[test]
+fn copies_unaligned() {
+    let words: &[u16] = &[0x0100, 0x0200, 0x0300, 0x0400];
+    let bytes: Rc<[u8]> = Rc::from(transmute_to_bytes(words));
+    let mut unaligned: Vec<u8> = vec![0xFF];
+    unaligned.extend_from_slice(&bytes);
+    let unaligned: Rc<[u8]> = Rc::from(&unaligned[1..]);
+
+    assert_eq!(&*transmute_rc::<u16, PedanticGuard>(unaligned).unwrap(), words);
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "alloc")]
+
+
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, Error, SharedSlice, transmute_to_bytes};
+use core::ops::Bound;
+use alloc::sync::Arc;
+
+
+fn words() -> Arc<[u8]> {
+    Arc::from(transmute_to_bytes(&[1u32, 2, 3, 4, 5]))
+}
+
+
+#[test]
+fn new() {
+    let words = SharedSlice::<u32>::new::<PedanticGuard>(words()).unwrap();
+    assert_eq!(&*words, &[1, 2, 3, 4, 5]);
+    assert_eq!(words.as_bytes().len(), 20);
+
+    let bytes = words.as_bytes().clone();
+    assert_eq!(&*SharedSlice::<u16>::new::<SingleManyGuard>(Arc::from(&bytes[..5])).unwrap(),
+               &[u16::from_ne_bytes([bytes[0], bytes[1]]), u16::from_ne_bytes([bytes[2], bytes[3]])]);
+}
+
+#[test]
+fn guarded() {
+    match SharedSlice::<u32>::new::<PedanticGuard>(Arc::from(&words()[..6])) {
+        Err(Error::Guard(e)) => {
+            assert_eq!(e,
+                       GuardError {
+                           required: 4,
+                           actual: 6,
+                           reason: ErrorReason::InexactByteCount,
+                       })
+        }
+        r => panic!("expected a guard error, got {:?}", r),
+    }
+}
+
+#[test]
+fn slice() {
+    let words = SharedSlice::<u32>::new::<PedanticGuard>(words()).unwrap();
+
+    assert_eq!(&*words.slice(..), &[1, 2, 3, 4, 5]);
+    assert_eq!(&*words.slice(1..3), &[2, 3]);
+    assert_eq!(&*words.slice(1..=3), &[2, 3, 4]);
+    assert_eq!(&*words.slice(..2), &[1, 2]);
+    assert_eq!(&*words.slice(5..), &[] as &[u32]);
+    assert_eq!(&*words.slice(2..).slice(1..).slice(..1), &[4]);
+
+    let window = words.slice(3..);
+    drop(words);
+    assert_eq!(&*window.clone(), &[4, 5]);
+    assert_eq!(Arc::strong_count(window.as_bytes()), 1);
+}
+
+#[test]
+#[should_panic]
+fn slice_out_of_bounds() {
+    SharedSlice::<u32>::new::<PedanticGuard>(words()).unwrap().slice(1..).slice(..5);
+}
+
+#[test]
+fn get() {
+    let words = SharedSlice::<u32>::new::<PedanticGuard>(words()).unwrap();
+
+    assert_eq!(words.get(1..3).as_deref(), Some(&[2, 3][..]));
+    assert_eq!(words.get(5..).as_deref(), Some(&[][..]));
+    assert!(words.get(..6).is_none());
+    assert!(words.get((Bound::Included(3), Bound::Excluded(2))).is_none());
+    assert!(words.get(..=usize::MAX).is_none());
+    assert!(words.get((Bound::Excluded(usize::MAX), Bound::Unbounded)).is_none());
+    assert!(words.slice(1..).get(..5).is_none());
+}
+
+#[test]
+fn send() {
+    fn assert_send_sync<S: Send + Sync>(_: &S) {}
+    assert_send_sync(&SharedSlice::<u32>::new::<PedanticGuard>(words()).unwrap());
+}
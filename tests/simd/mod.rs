@@ -0,0 +1,4 @@
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod x86_64;
+#[cfg(feature = "portable-simd")]
+mod portable;
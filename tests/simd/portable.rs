@@ -0,0 +1,11 @@
+use safe_transmute::{PedanticGuard, transmute_to_bytes, transmute_many};
+use core::simd::Simd;
+
+
+#[test]
+fn vectors() {
+    let vectors = [Simd::<f32, 8>::splat(1.5), Simd::splat(-2.0)];
+    let values = transmute_many::<Simd<f32, 8>, PedanticGuard>(transmute_to_bytes(&vectors)).unwrap();
+    assert_eq!(values, &vectors);
+    assert_eq!(transmute_many::<f32, PedanticGuard>(transmute_to_bytes(&values[1..])).unwrap(), &[-2.0; 8]);
+}
@@ -0,0 +1,15 @@
+use safe_transmute::{PedanticGuard, transmute_one_unaligned, transmute_to_bytes, transmute_many};
+use core::arch::x86_64::{__m128i, __m256};
+
+
+#[test]
+fn vectors() {
+    let lanes: [u8; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+    let vector = transmute_one_unaligned::<__m128i>(&lanes).unwrap();
+    assert_eq!(transmute_to_bytes(&[vector]), &lanes);
+
+    let vectors = [transmute_one_unaligned::<__m256>(transmute_to_bytes(&[1.0f32; 8])).unwrap(); 2];
+    let bytes = transmute_to_bytes(&vectors);
+    assert_eq!(transmute_many::<__m256, PedanticGuard>(bytes).map(|v| v.len()).ok(), Some(2));
+    assert!(transmute_many::<__m256, PedanticGuard>(&bytes[4..36]).is_err());
+}
@@ -0,0 +1,5 @@
+mod unaligned_iter;
+mod transmuter;
+mod reader;
+mod writer;
+mod read_exact;
@@ -0,0 +1,35 @@
+#![cfg(feature = "std")]
+
+
+use safe_transmute::{transmute_to_bytes, read_exact_typed};
+use std::io::{ErrorKind, Cursor};
+
+
+#[test]
+fn exact() {
+    let mut reader = Cursor::new(transmute_to_bytes(&[1u32, 2, 3]));
+    let mut values = [0u32; 2];
+
+    read_exact_typed(&mut reader, &mut values).unwrap();
+    assert_eq!(values, [1, 2]);
+    read_exact_typed(&mut reader, &mut values[..1]).unwrap();
+    assert_eq!(values, [3, 2]);
+    read_exact_typed(&mut reader, &mut [] as &mut [u32]).unwrap();
+}
+
+#[test]
+fn eof() {
+    let mut reader = Cursor::new(transmute_to_bytes(&[1u16, 2, 3]));
+    let mut values = [[0u16; 2]; 2];
+
+    assert_eq!(read_exact_typed(&mut reader, &mut values).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn dyn_reader() {
+    let mut reader: Box<dyn std::io::Read> = Box::new(Cursor::new(transmute_to_bytes(&[7u64])));
+    let mut values = [0u64];
+
+    read_exact_typed(&mut *reader, &mut values).unwrap();
+    assert_eq!(values, [7]);
+}
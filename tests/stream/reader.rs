@@ -0,0 +1,86 @@
+#![cfg(feature = "std")]
+
+
+use safe_transmute::{TransmuteReader, transmute_to_bytes};
+use std::io::{self, ErrorKind, Cursor, Read};
+
+
+/// A reader returning at most `chunk` bytes at a time, interrupted before every read.
+struct Trickle<'a> {
+    data: &'a [u8],
+    chunk: usize,
+    interrupt: bool,
+}
+
+impl<'a> Read for Trickle<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.interrupt = !self.interrupt;
+        if self.interrupt {
+            return Err(io::Error::new(ErrorKind::Interrupted, "interrupted"));
+        }
+
+        let len = self.chunk.min(buf.len()).min(self.data.len());
+        buf[..len].copy_from_slice(&self.data[..len]);
+        self.data = &self.data[len..];
+        Ok(len)
+    }
+}
+
+
+#[test]
+fn empty() {
+    assert!(TransmuteReader::<_, u32>::new(Cursor::new(vec![])).next().is_none());
+}
+
+#[test]
+fn whole() {
+    let words: &[u64] = &[0x0100, 0x0200, 0x0300];
+    let reader = TransmuteReader::<_, u64>::new(Cursor::new(transmute_to_bytes(words).to_vec()));
+    assert_eq!(reader.collect::<io::Result<Vec<_>>>().unwrap(), words);
+}
+
+#[test]
+fn small_buffer() {
+    let words: &[u32] = &[1, 2, 3, 4, 5, 6, 7];
+    let reader = TransmuteReader::<_, u32>::with_capacity(2,
+                                                          Trickle {
+                                                              data: transmute_to_bytes(words),
+                                                              chunk: 3,
+                                                              interrupt: false,
+                                                          });
+    assert_eq!(reader.collect::<io::Result<Vec<_>>>().unwrap(), words);
+}
+
+#[test]
+fn unexpected_eof() {
+    let words: &[u32] = &[1, 2];
+    let mut reader = TransmuteReader::<_, u32>::new(Cursor::new(transmute_to_bytes(words)[..7].to_vec()));
+    assert_eq!(reader.next().unwrap().unwrap(), 1);
+    assert_eq!(reader.next().unwrap().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    assert!(reader.next().is_none());
+}
+
+/// A reader filling the whole buffer with ones, but claiming to have read more.
+struct Overreporting {
+    reads: usize,
+}
+
+impl Read for Overreporting {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.reads == 0 {
+            return Ok(0);
+        }
+
+        self.reads -= 1;
+        for b in buf.iter_mut() {
+            *b = 0x01;
+        }
+        Ok(buf.len() + 5)
+    }
+}
+
+#[test]
+fn overreporting_reader() {
+    let reader = TransmuteReader::<_, u32>::with_capacity(2, Overreporting { reads: 1 });
+    assert_eq!(reader.collect::<io::Result<Vec<_>>>().unwrap(), vec![0x01010101, 0x01010101]);
+}
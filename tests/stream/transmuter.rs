@@ -0,0 +1,57 @@
+use safe_transmute::{ErrorReason, GuardError, StreamingTransmuter, transmute_to_bytes};
+
+
+#[test]
+fn empty() {
+    let mut decoder = StreamingTransmuter::<u32>::new();
+    assert_eq!(decoder.push(&[]).next(), None);
+    assert_eq!(decoder.pending(), &[0u8; 0]);
+    assert_eq!(decoder.finish(), Ok(()));
+}
+
+#[test]
+fn whole_chunks() {
+    let words: &[u32] = &[0x0403_0201, 0x0807_0605];
+    let mut decoder = StreamingTransmuter::<u32>::new();
+    let mut values = decoder.push(transmute_to_bytes(words));
+    assert_eq!(values.len(), 2);
+    assert_eq!(values.next(), Some(0x0403_0201));
+    assert_eq!(values.next(), Some(0x0807_0605));
+    assert_eq!(values.next(), None);
+    assert_eq!(decoder.finish(), Ok(()));
+}
+
+#[test]
+fn split_chunks() {
+    let words: &[u32] = &[0x0403_0201, 0x0807_0605, 0x0C0B_0A09];
+    let bytes = transmute_to_bytes(words);
+    let mut decoder = StreamingTransmuter::<u32>::new();
+
+    assert_eq!(decoder.push(&bytes[..1]).next(), None);
+    assert_eq!(decoder.push(&bytes[1..3]).next(), None);
+    assert_eq!(decoder.pending(), &bytes[..3]);
+
+    let mut values = decoder.push(&bytes[3..9]);
+    assert_eq!(values.len(), 2);
+    assert_eq!(values.next(), Some(0x0403_0201));
+    assert_eq!(values.next(), Some(0x0807_0605));
+    assert_eq!(values.next(), None);
+    assert_eq!(decoder.pending(), &bytes[8..9]);
+
+    let mut values = decoder.push(&bytes[9..]);
+    assert_eq!(values.next(), Some(0x0C0B_0A09));
+    assert_eq!(values.next(), None);
+    assert_eq!(decoder.finish(), Ok(()));
+}
+
+#[test]
+fn unfinished() {
+    let mut decoder = StreamingTransmuter::<u32>::new();
+    assert_eq!(decoder.push(&[0x01, 0x02]).next(), None);
+    assert_eq!(decoder.finish(),
+               Err(GuardError {
+                   required: 4,
+                   actual: 2,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
@@ -0,0 +1,44 @@
+use safe_transmute::{transmute_to_bytes, unaligned_iter};
+
+
+#[test]
+fn empty() {
+    assert_eq!(unaligned_iter::<u16>(&[]).next(), None);
+    assert_eq!(unaligned_iter::<u16>(&[0x00]).next(), None);
+    assert_eq!(unaligned_iter::<u16>(&[0x00]).remainder(), &[0x00]);
+}
+
+#[test]
+fn aligned() {
+    let words: &[u16] = &[0x0100, 0x0200, 0x0300];
+    let mut iter = unaligned_iter::<u16>(transmute_to_bytes(words));
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next(), Some(0x0100));
+    assert_eq!(iter.next(), Some(0x0200));
+    assert_eq!(iter.next(), Some(0x0300));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.remainder(), &[0u8; 0]);
+}
+
+#[test]
+fn unaligned() {
+    let words: &[u32] = &[0x0403_0201, 0x0807_0605, 0x0C0B_0A09];
+    let bytes = &transmute_to_bytes(words)[1..];
+    let mut iter = unaligned_iter::<u32>(bytes);
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next(), Some(u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])));
+    assert_eq!(iter.next(), Some(u32::from_ne_bytes([bytes[4], bytes[5], bytes[6], bytes[7]])));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.remainder(), &bytes[8..]);
+}
+
+#[test]
+fn double_ended() {
+    let words: &[u16] = &[0x0100, 0x0200, 0x0300];
+    let bytes = &transmute_to_bytes(words)[..5];
+    let mut iter = unaligned_iter::<u16>(bytes);
+    assert_eq!(iter.next_back(), Some(0x0200));
+    assert_eq!(iter.next(), Some(0x0100));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "std")]
+
+
+use safe_transmute::{TransmuteReader, TransmuteWriter, transmute_to_bytes, write_transmuted};
+use std::io::{self, Cursor};
+
+
+#[test]
+fn free_function() {
+    let words: &[u32] = &[1, 2, 3];
+    let mut out = Vec::new();
+    write_transmuted(&mut out, words).unwrap();
+    assert_eq!(out, transmute_to_bytes(words));
+}
+
+#[test]
+fn mixed() {
+    let words: &[u16] = &[1, 2, 3, 4, 5];
+    let mut writer = TransmuteWriter::<_, u16>::new(Vec::new());
+    writer.write_value(words[0]).unwrap();
+    writer.write_values(&words[1..3]).unwrap();
+    writer.write_iter(words[3..].iter().cloned()).unwrap();
+    writer.flush().unwrap();
+    assert_eq!(writer.get_ref().len(), 10);
+    assert_eq!(writer.into_inner(), transmute_to_bytes(words));
+}
+
+#[test]
+fn round_trip() {
+    let words: &[u64] = &[0x0102_0304_0506_0708, 0x1112_1314_1516_1718];
+    let mut writer = TransmuteWriter::new(Vec::new());
+    writer.write_values(words).unwrap();
+
+    let reader = TransmuteReader::<_, u64>::new(Cursor::new(writer.into_inner()));
+    assert_eq!(reader.collect::<io::Result<Vec<_>>>().unwrap(), words);
+}
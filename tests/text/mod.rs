@@ -0,0 +1,2 @@
+mod utf8;
+mod utf16;
@@ -0,0 +1,57 @@
+use safe_transmute::{ErrorReason, GuardError, Error, transmute_to_utf16, transmute_to_bytes};
+#[cfg(feature = "alloc")]
+use safe_transmute::endian::{LittleEndian, NativeEndian, BigEndian};
+#[cfg(feature = "alloc")]
+use safe_transmute::decode_utf16;
+#[cfg(feature = "alloc")]
+use alloc::string::ToString;
+
+
+#[test]
+fn view() {
+    let wide: &[u16] = &[0x0052, 0x0065, 0x0067, 0xD800];
+    let bytes = transmute_to_bytes(wide);
+
+    assert_eq!(transmute_to_utf16(bytes), Ok(wide));
+    assert_eq!(transmute_to_utf16(&bytes[..0]), Ok(&[][..]));
+    assert_eq!(transmute_to_utf16(&bytes[..3]),
+               Err(Error::Guard(GuardError {
+                   required: 2,
+                   actual: 3,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decode_without_bom() {
+    assert_eq!(decode_utf16::<LittleEndian>(&[]), Ok("".to_string()));
+    assert_eq!(decode_utf16::<LittleEndian>(&[0x7C, 0x01, 0x3D, 0xD8, 0x00, 0xDE]), Ok("ż😀".to_string()));
+    assert_eq!(decode_utf16::<BigEndian>(&[0x01, 0x7C, 0xD8, 0x3D, 0xDE, 0x00]), Ok("ż😀".to_string()));
+
+    let wide: &[u16] = &[0x0052, 0x0065, 0x0067];
+    assert_eq!(decode_utf16::<NativeEndian>(transmute_to_bytes(wide)), Ok("Reg".to_string()));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decode_with_bom() {
+    assert_eq!(decode_utf16::<BigEndian>(&[0xFF, 0xFE, 0x7C, 0x01]), Ok("ż".to_string()));
+    assert_eq!(decode_utf16::<LittleEndian>(&[0xFE, 0xFF, 0x01, 0x7C]), Ok("ż".to_string()));
+    assert_eq!(decode_utf16::<LittleEndian>(&[0xFF, 0xFE]), Ok("".to_string()));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decode_invalid() {
+    assert_eq!(decode_utf16::<LittleEndian>(&[0x52, 0x00, 0x65]),
+               Err(Error::Guard(GuardError {
+                   required: 2,
+                   actual: 3,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+    assert_eq!(decode_utf16::<LittleEndian>(&[0x3D, 0xD8, 0x00, 0xDE, 0x3D, 0xD8, 0x52, 0x00]),
+               Err(Error::InvalidValueAt { index: 2, bytes: &[0x3D, 0xD8] }));
+    assert_eq!(decode_utf16::<LittleEndian>(&[0xFF, 0xFE, 0x52, 0x00, 0x00, 0xDE]),
+               Err(Error::InvalidValueAt { index: 2, bytes: &[0x00, 0xDE] }));
+}
@@ -0,0 +1,44 @@
+use safe_transmute::{Error, transmute_bytes_to_str};
+#[cfg(feature = "alloc")]
+use safe_transmute::transmute_vec_to_string;
+#[cfg(feature = "alloc")]
+use alloc::string::ToString;
+
+
+#[test]
+fn valid() {
+    assert_eq!(transmute_bytes_to_str(&[]), Ok(""));
+    assert_eq!(transmute_bytes_to_str(b"HKEY_LOCAL_MACHINE"), Ok("HKEY_LOCAL_MACHINE"));
+    assert_eq!(transmute_bytes_to_str("żółw".as_bytes()), Ok("żółw"));
+}
+
+#[test]
+fn invalid() {
+    assert_eq!(transmute_bytes_to_str(&[0xFF]), Err(Error::InvalidValueAt { index: 0, bytes: &[0xFF] }));
+    assert_eq!(transmute_bytes_to_str(&[0x61, 0x62, 0xC5]), Err(Error::InvalidValueAt { index: 2, bytes: &[0xC5] }));
+    assert_eq!(transmute_bytes_to_str(&[0x61, 0xC5, 0xBC, 0xED, 0xA0, 0x80]),
+               Err(Error::InvalidValueAt { index: 3, bytes: &[0xED] }));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec() {
+    assert_eq!(transmute_vec_to_string(vec![]), Ok("".to_string()));
+    assert_eq!(transmute_vec_to_string("żółw".as_bytes().to_vec()), Ok("żółw".to_string()));
+
+    let err = transmute_vec_to_string(vec![0x61, 0x62, 0x80, 0x63]).unwrap_err();
+    assert_eq!(err.index(), 2);
+    assert_eq!(err.invalid_bytes(), &[0x80]);
+    assert_eq!(err.error(), Error::InvalidValueAt { index: 2, bytes: &[0x80] });
+    assert_eq!(err.to_string(), "Invalid target value for `u8` at index 2: [80]");
+    assert_eq!(err.vec, vec![0x61, 0x62, 0x80, 0x63]);
+
+    let err = transmute_vec_to_string(vec![0x61, 0xC5]).unwrap_err();
+    assert_eq!(err.invalid_bytes(), &[0xC5]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn display() {
+    assert_eq!(transmute_bytes_to_str(&[0x61, 0xFF]).unwrap_err().to_string(), "Invalid target value for `u8` at index 1: [FF]");
+}
@@ -0,0 +1,41 @@
+use safe_transmute::TransparentWrapper;
+use core::marker::PhantomData;
+
+
+#[derive(TransparentWrapper, Debug, PartialEq)]
+#[repr(transparent)]
+struct Meters(f64);
+
+#[derive(TransparentWrapper, Debug, PartialEq)]
+#[repr(transparent)]
+struct Named {
+    id: u32,
+}
+
+#[derive(TransparentWrapper, Debug, PartialEq)]
+#[repr(transparent)]
+#[transparent(T)]
+struct Tagged<T, Tag> {
+    value: T,
+    tag: PhantomData<Tag>,
+}
+
+
+#[test]
+fn tuple() {
+    assert_eq!(Meters::wrap_slice(&[1.0, 2.0]), &[Meters(1.0), Meters(2.0)]);
+    assert_eq!(Meters(3.0).peel_ref(), &3.0);
+}
+
+#[test]
+fn named() {
+    assert_eq!(Named::wrap_ref(&7), &Named { id: 7 });
+}
+
+#[test]
+fn generic() {
+    enum Seconds {}
+    let values: &[Tagged<u16, Seconds>] = Tagged::wrap_slice(&[1u16, 2]);
+    assert_eq!(values[1].value, 2);
+    assert_eq!(Tagged::peel_slice(values), &[1, 2]);
+}
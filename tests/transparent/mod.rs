@@ -0,0 +1,3 @@
+#[cfg(feature = "derive")]
+mod derive;
+mod wrapper;
@@ -0,0 +1,52 @@
+use safe_transmute::TransparentWrapper;
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(transparent)]
+struct Celsius(f32);
+
+unsafe impl TransparentWrapper<f32> for Celsius {}
+
+
+#[test]
+fn reference() {
+    let mut value = 21.5;
+    assert_eq!(Celsius::wrap_ref(&value), &Celsius(21.5));
+
+    Celsius::wrap_mut(&mut value).0 = 22.0;
+    assert_eq!(value, 22.0);
+
+    let mut temperature = Celsius(-4.0);
+    assert_eq!(temperature.peel_ref(), &-4.0);
+    *temperature.peel_mut() = 4.0;
+    assert_eq!(temperature, Celsius(4.0));
+}
+
+#[test]
+fn slice() {
+    let mut values = [1.0, 2.0, 3.0];
+    assert_eq!(Celsius::wrap_slice(&values), &[Celsius(1.0), Celsius(2.0), Celsius(3.0)]);
+
+    Celsius::wrap_slice_mut(&mut values)[1].0 = 5.0;
+    assert_eq!(values, [1.0, 5.0, 3.0]);
+
+    let mut temperatures = [Celsius(1.0), Celsius(2.0)];
+    assert_eq!(Celsius::peel_slice(&temperatures), &[1.0, 2.0]);
+    Celsius::peel_slice_mut(&mut temperatures)[0] = 0.0;
+    assert_eq!(temperatures, [Celsius(0.0), Celsius(2.0)]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vec() {
+    let values = vec![1.0, 2.0, 3.0];
+    let ptr = values.as_ptr() as usize;
+
+    let temperatures = Celsius::wrap_vec(values);
+    assert_eq!(temperatures, vec![Celsius(1.0), Celsius(2.0), Celsius(3.0)]);
+    assert_eq!(temperatures.as_ptr() as usize, ptr);
+
+    let values = Celsius::peel_vec(temperatures);
+    assert_eq!(values, vec![1.0, 2.0, 3.0]);
+    assert_eq!(values.as_ptr() as usize, ptr);
+}
@@ -0,0 +1,25 @@
+use safe_transmute::{ErrorReason, GuardError, PedanticGuard, Error, transmute_bytes_mut};
+use core::mem::align_of;
+use bytes::BytesMut;
+
+
+#[test]
+fn modify() {
+    let mut buffer = BytesMut::from(&[0u8; 8 + 16][..]);
+    let offset = (align_of::<u64>() - buffer.as_ptr() as usize % align_of::<u64>()) % align_of::<u64>();
+    let mut buffer = buffer.split_off(offset);
+    buffer.truncate(16);
+
+    transmute_bytes_mut::<u64, PedanticGuard>(&mut buffer).unwrap()[1] = 1;
+    assert_eq!(transmute_bytes_mut::<u64, PedanticGuard>(&mut buffer), Ok(&mut [0, 1][..]));
+}
+
+#[test]
+fn too_short() {
+    assert_eq!(transmute_bytes_mut::<u64, PedanticGuard>(&mut BytesMut::from(&[0u8; 3][..])),
+               Err(Error::Guard(GuardError {
+                   required: 8,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
@@ -0,0 +1,2 @@
+mod bytes_mut;
+mod typed;
@@ -0,0 +1,45 @@
+use safe_transmute::{ErrorReason, PedanticGuard, GuardError, TypedBytes, Error, transmute_to_bytes};
+use bytes::Bytes;
+
+
+fn aligned(words: &[u32]) -> Bytes {
+    // `Bytes` created from a `Vec<u8>` have no alignment guarantees, so go through a `u32` allocation
+    let leaked: &'static [u32] = Box::leak(words.to_vec().into_boxed_slice());
+    Bytes::from_static(transmute_to_bytes(leaked))
+}
+
+
+#[test]
+fn just_enough() {
+    let words: &[u32] = &[1, 2, 3];
+    let typed = TypedBytes::<u32>::new::<PedanticGuard>(aligned(words)).unwrap();
+    assert_eq!(&*typed, words);
+    assert_eq!(&*typed.clone(), words);
+    assert_eq!(typed.as_bytes().len(), 12);
+    assert_eq!(typed.into_bytes().len(), 12);
+}
+
+#[test]
+fn sliced() {
+    let words: &[u32] = &[1, 2, 3];
+    let typed = TypedBytes::<u32>::new::<PedanticGuard>(aligned(words).slice(4..)).unwrap();
+    assert_eq!(&*typed, &words[1..]);
+}
+
+#[test]
+fn too_much() {
+    assert_eq!(TypedBytes::<u32>::new::<PedanticGuard>(aligned(&[1, 2]).slice(..6)).unwrap_err(),
+               Error::Guard(GuardError {
+                   required: 4,
+                   actual: 6,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+}
+
+#[test]
+fn unaligned() {
+    match TypedBytes::<u32>::new::<PedanticGuard>(aligned(&[1, 2]).slice(1..5)) {
+        Err(Error::Unaligned(e)) => assert_eq!(e.offset, 3),
+        other => panic!("unexpected {:?}", other),
+    }
+}
@@ -0,0 +1,73 @@
+use safe_transmute::{ErrorReason, GuardError, Error, transmute_to_array_view_mut, transmute_to_array_view, transmute_to_bytes_mut, transmute_to_bytes,
+                     transmute_to_array};
+use ndarray::{arr2, arr3, Ix1};
+
+
+#[test]
+fn view() {
+    let data = [1u32, 2, 3, 4, 5, 6];
+    let bytes = transmute_to_bytes(&data);
+
+    assert_eq!(transmute_to_array_view::<u32, _>(bytes, (2, 3)).unwrap(), arr2(&[[1, 2, 3], [4, 5, 6]]));
+    assert_eq!(transmute_to_array_view::<u32, _>(bytes, (3, 2)).unwrap(), arr2(&[[1, 2], [3, 4], [5, 6]]));
+    assert_eq!(transmute_to_array_view::<u32, _>(bytes, Ix1(6)).unwrap().len(), 6);
+    assert_eq!(transmute_to_array_view::<u32, _>(&bytes[..0], (0, 3)).unwrap().len(), 0);
+}
+
+#[test]
+fn view_mut() {
+    let mut data = [0u16; 4];
+    transmute_to_array_view_mut::<u16, _>(transmute_to_bytes_mut(&mut data), (2, 2)).unwrap()[[1, 0]] = 9;
+    assert_eq!(data, [0, 0, 9, 0]);
+}
+
+#[test]
+fn owned() {
+    let data = [1u16, 2, 3, 4, 5, 6, 7, 8];
+    let bytes = transmute_to_bytes(&data);
+
+    assert_eq!(transmute_to_array::<u16, _>(bytes.to_vec(), (2, 2, 2)).unwrap(),
+               arr3(&[[[1, 2], [3, 4]], [[5, 6], [7, 8]]]));
+
+    let mut unaligned = vec![0];
+    unaligned.extend_from_slice(bytes);
+    assert_eq!(transmute_to_array::<u16, _>(unaligned[1..].to_vec(), (4, 2)).unwrap()[[3, 1]], 8);
+}
+
+#[test]
+fn wrong_shape() {
+    let data = [1u32, 2, 3, 4, 5, 6];
+    let bytes = transmute_to_bytes(&data);
+
+    match transmute_to_array_view::<u32, _>(bytes, (4, 2)) {
+        Err(e) => {
+            assert_eq!(e,
+                       Error::Guard(GuardError {
+                           required: 32,
+                           actual: 24,
+                           reason: ErrorReason::InexactByteCount,
+                       }))
+        }
+        Ok(_) => panic!("expected a guard error"),
+    }
+    match transmute_to_array::<u32, _>(bytes[..20].to_vec(), (6,)) {
+        Err(e) => {
+            assert_eq!(e,
+                       Error::Guard(GuardError {
+                           required: 24,
+                           actual: 20,
+                           reason: ErrorReason::InexactByteCount,
+                       }))
+        }
+        Ok(_) => panic!("expected a guard error"),
+    }
+}
+
+#[test]
+fn unaligned() {
+    let data = [0u32; 3];
+    match transmute_to_array_view::<u32, _>(&transmute_to_bytes(&data)[1..9], (2,)) {
+        Err(Error::Unaligned(e)) => assert_eq!(e.offset, 3),
+        _ => panic!("expected an unaligned error"),
+    }
+}
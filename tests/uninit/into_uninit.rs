@@ -0,0 +1,41 @@
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, Error, transmute_many_into_uninit, transmute_to_bytes};
+use core::mem::MaybeUninit;
+
+
+#[test]
+fn too_short() {
+    let mut storage = [MaybeUninit::<u32>::uninit(); 4];
+    assert_eq!(transmute_many_into_uninit::<u32, SingleManyGuard>(&[0x00; 3], &mut storage),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn too_much() {
+    let mut storage = [MaybeUninit::<u32>::uninit(); 2];
+    assert_eq!(transmute_many_into_uninit::<u32, PedanticGuard>(&[0x00; 12], &mut storage),
+               Err(Error::Guard(GuardError {
+                   required: 8,
+                   actual: 12,
+                   reason: ErrorReason::TooManyBytes,
+               })));
+}
+
+#[test]
+fn unaligned() {
+    let words: &[u32] = &[1, 2, 3];
+    let mut storage = [MaybeUninit::<u32>::uninit(); 4];
+    assert_eq!(transmute_many_into_uninit::<u32, SingleManyGuard>(&transmute_to_bytes(words)[4..], &mut storage).unwrap(),
+               &[2, 3]);
+}
+
+#[test]
+fn exact() {
+    let words: &[u16] = &[7, 8];
+    let mut storage = [MaybeUninit::<u16>::uninit(); 2];
+    assert_eq!(transmute_many_into_uninit::<u16, PedanticGuard>(transmute_to_bytes(words), &mut storage).unwrap(), words);
+    assert_eq!(unsafe { storage[1].assume_init() }, 8);
+}
@@ -0,0 +1,37 @@
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, Error, transmute_many_uninit, transmute_to_bytes};
+
+
+#[test]
+fn too_short() {
+    let words: &[u32] = &[1];
+    assert_eq!(transmute_many_uninit::<u32, SingleManyGuard>(&transmute_to_bytes(words)[..3]).map(|v| v.len()),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn unaligned() {
+    let words: &[u32] = &[1, 2];
+    match transmute_many_uninit::<u32, SingleManyGuard>(&transmute_to_bytes(words)[1..]) {
+        Err(Error::Unaligned(e)) => assert_eq!(e.offset, 3),
+        _ => panic!("expected an unaligned error"),
+    }
+}
+
+#[test]
+fn just_enough() {
+    let words: &[u32] = &[1, 2, 3];
+    let values = transmute_many_uninit::<u32, PedanticGuard>(transmute_to_bytes(words)).unwrap();
+    assert_eq!(values.len(), 3);
+    assert_eq!(unsafe { values[2].assume_init() }, 3);
+}
+
+#[test]
+fn not_trivially_transmutable() {
+    let words: &[u32] = &[0, 1];
+    let values = transmute_many_uninit::<char, PedanticGuard>(transmute_to_bytes(words)).unwrap();
+    assert_eq!(values.len(), 2);
+}
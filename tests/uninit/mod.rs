@@ -0,0 +1,2 @@
+mod into_uninit;
+mod many;
@@ -1,5 +1,6 @@
 #[cfg(feature = "alloc")]
 use self::super::{dealloc_aligned_vec, aligned_vec};
+use safe_transmute::{transmute_one_to_bytes, SequenceError, ErrorReason, GuardError, transmute_one};
 use safe_transmute::align::check_alignment;
 use safe_transmute::util;
 use core::mem::align_of;
@@ -19,7 +20,48 @@ fn designalise_f32() {
 fn designalise_f64() {
     assert_eq!(util::designalise_f64(12.34125121), 12.34125121);
     assert!(util::designalise_f64(f64::NAN).is_nan());
-    // I'm not quite sure how to make an sNaN to test this, either
+    assert_eq!(util::designalise_f64(f64::from_bits(0x7FF0_0000_0000_0001)).to_bits(), 0x7FF8_0000_0000_0001);
+    assert_eq!(util::from_bits_f64_designalised(0xFFF4_0000_0000_0000).to_bits(), 0xFFFC_0000_0000_0000);
+}
+
+#[test]
+fn designalise_trait() {
+    use safe_transmute::util::Designalise;
+
+    fn scrub<F: Designalise>(values: &mut [F]) {
+        for v in values {
+            *v = v.designalise();
+        }
+    }
+
+    let mut singles = [f32::from_bits(0x7F80_0001), f32::from_bits(0xFFA0_0000), f32::INFINITY, -2.5];
+    scrub(&mut singles);
+    assert_eq!(singles.map(f32::to_bits), [0x7FC0_0001, 0xFFE0_0000, f32::INFINITY.to_bits(), (-2.5f32).to_bits()]);
+
+    let mut doubles = [f64::from_bits(0x7FF0_0000_0000_0001), f64::NEG_INFINITY, 0.5];
+    scrub(&mut doubles);
+    assert_eq!(doubles[0].to_bits(), 0x7FF8_0000_0000_0001);
+    assert_eq!(doubles[1], f64::NEG_INFINITY);
+    assert_eq!(doubles[2], 0.5);
+
+    assert_eq!(f32::from_bits_designalised(0x7F80_0002).to_bits(), 0x7FC0_0002);
+    assert_eq!(f64::from_bits_designalised(0x7FF0_0000_0000_0002).to_bits(), 0x7FF8_0000_0000_0002);
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn designalise_half() {
+    use safe_transmute::util::Designalise;
+    use half::{bf16, f16};
+
+    assert_eq!(f16::from_bits(0x7C01).designalise().to_bits(), 0x7E01);
+    assert_eq!(f16::from_bits(0x7C00).designalise().to_bits(), 0x7C00);
+    assert_eq!(f16::from_bits_designalised(0xFC10).to_bits(), 0xFE10);
+    assert_eq!(f16::from_f32(1.5).designalise(), f16::from_f32(1.5));
+
+    assert_eq!(bf16::from_bits(0x7F81).designalise().to_bits(), 0x7FC1);
+    assert_eq!(bf16::from_bits(0x7F80).designalise().to_bits(), 0x7F80);
+    assert_eq!(bf16::from_bits_designalised(0xFF90).to_bits(), 0xFFD0);
 }
 
 #[test]
@@ -70,3 +112,67 @@ fn check_aligned_vec_with<T>(bytes: &[u8]) {
         dealloc_aligned_vec::<T>(vec);
     }
 }
+
+#[test]
+fn include_bytes_aligned() {
+    let bytes: &'static [u8] = include_bytes_aligned!(u64, "mod.rs");
+    assert_eq!(bytes, &include_bytes!("mod.rs")[..]);
+    assert_eq!((bytes.as_ptr() as usize) % align_of::<u64>(), 0);
+
+    let bytes: &'static [u8] = include_bytes_aligned!([u32; 4], "mod.rs");
+    assert_eq!((bytes.as_ptr() as usize) % align_of::<u32>(), 0);
+
+    let bytes: &'static [u8] = include_bytes_aligned!(safe_transmute::aligned::A64, "mod.rs");
+    assert_eq!((bytes.as_ptr() as usize) % 64, 0);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+struct Rgba {
+    channels: [u8; 4],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+struct Extent {
+    width: u16,
+    height: u16,
+}
+
+unsafe_trivially_transmutable!(Rgba, Extent { size: 4, align: 2 });
+
+#[test]
+fn unsafe_trivially_transmutable() {
+    assert_eq!(transmute_one::<Rgba>(&[1, 2, 3, 4]), Ok(Rgba { channels: [1, 2, 3, 4] }));
+    assert_eq!(transmute_one_to_bytes(&Extent { width: 0x0101, height: 0x0202 }), &[1, 1, 2, 2]);
+}
+
+assert_trivially_transmutable!(Extent {
+    width: u16,
+    height: u16,
+});
+assert_trivially_transmutable!(Rgba { channels: [u8; 4] });
+
+#[test]
+fn transmute_sequence() {
+    let bytes = [0xFF, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B];
+
+    assert_eq!(transmute_sequence!(bytes[1..] => head: Rgba, size: Extent, tail: u8),
+               Ok((Rgba { channels: [1, 2, 3, 4] }, Extent { width: u16::from_le(0x0605), height: u16::from_le(0x0807) }, 9)));
+    assert_eq!(transmute_sequence!(&bytes => tag: u8, len: u32,), Ok((0xFF, u32::from_le(0x0403_0201))));
+    assert_eq!(transmute_sequence!(&bytes[..1] => tag: u8), Ok((0xFF,)));
+}
+
+#[test]
+fn transmute_sequence_too_short() {
+    assert_eq!(transmute_sequence!([0x01u8, 0x02, 0x03] => tag: u8, len: u32, tail: u8),
+               Err(SequenceError {
+                   field: "len",
+                   offset: 1,
+                   error: GuardError {
+                       required: 4,
+                       actual: 2,
+                       reason: ErrorReason::NotEnoughBytes,
+                   },
+               }));
+}
@@ -0,0 +1,57 @@
+use safe_transmute::{ErrorReason, GuardError, Error, transmute_to_bytes_mut, transmute_to_bytes, write_volatile_one, read_volatile_one};
+
+
+#[test]
+fn read() {
+    let registers = [0x0102u16, 0x0304, 0x0506];
+    let bytes = transmute_to_bytes(&registers);
+
+    assert_eq!(read_volatile_one::<u16>(bytes), Ok(0x0102));
+    assert_eq!(read_volatile_one::<u16>(&bytes[4..]), Ok(0x0506));
+
+    let registers = [0x0102_0304u32, 0x0506_0708];
+    assert_eq!(read_volatile_one::<u16>(&transmute_to_bytes(&registers)[4..]),
+               Ok(u16::from_ne_bytes([transmute_to_bytes(&registers)[4], transmute_to_bytes(&registers)[5]])));
+}
+
+#[test]
+fn write() {
+    let mut registers = [0u16; 3];
+    write_volatile_one::<u16>(&mut transmute_to_bytes_mut(&mut registers)[2..], 0xABCD).unwrap();
+    write_volatile_one::<u8>(&mut transmute_to_bytes_mut(&mut registers)[5..], 0xFF).unwrap();
+
+    assert_eq!(registers[0], 0);
+    assert_eq!(registers[1], 0xABCD);
+    assert_eq!(transmute_to_bytes(&registers[2..])[1], 0xFF);
+}
+
+#[test]
+fn too_short() {
+    let mut registers = [0u16; 2];
+    assert_eq!(read_volatile_one::<u32>(&transmute_to_bytes(&registers)[..2]),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 2,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+    assert_eq!(write_volatile_one::<u16>(&mut transmute_to_bytes_mut(&mut registers)[..1], 1),
+               Err(Error::Guard(GuardError {
+                   required: 2,
+                   actual: 1,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn unaligned() {
+    let mut registers = [0u32; 2];
+    match read_volatile_one::<u32>(&transmute_to_bytes(&registers)[1..]) {
+        Err(Error::Unaligned(e)) => assert_eq!(e.offset, 3),
+        r => panic!("expected an unaligned error, got {:?}", r),
+    }
+    match write_volatile_one::<u32>(&mut transmute_to_bytes_mut(&mut registers)[2..], 1) {
+        Err(Error::Unaligned(e)) => assert_eq!(e.offset, 2),
+        r => panic!("expected an unaligned error, got {:?}", r),
+    }
+    assert_eq!(registers, [0, 0]);
+}